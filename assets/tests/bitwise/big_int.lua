@@ -0,0 +1,10 @@
+local a = BigInt.new(12)
+local b = BigInt.new(10)
+
+assert((a & b):to_integer() == 8, "Bitwise and did not work")
+assert((a | b):to_integer() == 14, "Bitwise or did not work")
+assert((a ~ b):to_integer() == 6, "Bitwise xor did not work")
+assert((a << 2):to_integer() == 48, "Shift left did not work")
+assert((a >> 2):to_integer() == 3, "Shift right did not work")
+assert((~a):to_integer() == -13, "Bitwise not did not work")
+assert((BigInt.new(-7) // BigInt.new(2)):to_integer() == -4, "Floor division did not work")
@@ -0,0 +1,24 @@
+local res_type = world.get_type_by_name("TestResourceWithVariousFields")
+local res = world.get_resource(res_type)
+local map = res.ordered_map
+
+local keys = map:keys()
+assert(#keys == 3, "Expected 3 keys, got " .. #keys)
+assert(keys[1] == 1 and keys[2] == 2 and keys[3] == 3, "Keys were not in order")
+
+map:insert(0, "zero")
+assert(map:contains(0) == true, "Expected map to contain 0 after insert")
+assert(map:keys()[1] == 0, "Expected the inserted key to come first")
+
+local removed = map:remove(2)
+assert(removed == "two", "Expected to remove 'two', got " .. tostring(removed))
+assert(map:contains(2) == false, "Expected map to not contain 2 after remove")
+
+local iterated_vals = {}
+for v in pairs(map) do
+    iterated_vals[#iterated_vals + 1] = v
+end
+assert(#iterated_vals == 3, "Expected 3 values, got " .. #iterated_vals)
+assert(iterated_vals[1] == "zero", "First value is not 'zero'")
+assert(iterated_vals[2] == "one", "Second value is not 'one'")
+assert(iterated_vals[3] == "three", "Third value is not 'three'")
@@ -0,0 +1,10 @@
+local small = BigInt.new(1)
+local large = BigInt.new(2)
+
+assert((small < large) == true, "Less than did not work")
+assert((small <= large) == true, "Less than or equal did not work")
+assert((small <= small) == true, "Less than or equal did not work")
+assert((large <= small) == false, "Less than or equal did not work")
+assert((large > small) == true, "Greater than did not work")
+assert((large >= small) == true, "Greater than or equal did not work")
+assert((small > large) == false, "Greater than did not work")
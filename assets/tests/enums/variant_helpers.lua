@@ -0,0 +1,28 @@
+-- Struct Variant
+local constructed = SimpleEnum.Struct({ foo = 123 })
+
+assert(constructed:variant_name() == "Struct", "Expected Struct but got " .. constructed:variant_name())
+assert(constructed:is_variant("Struct"), "Expected is_variant to match Struct")
+assert(not constructed:is_variant("Unit"), "Expected is_variant not to match Unit")
+
+local fields = constructed:variant_fields()
+assert(fields.foo == 123, "Expected fields.foo to be 123 but got " .. tostring(fields.foo))
+
+-- TupleStruct Variant
+constructed = SimpleEnum.TupleStruct({ 456 })
+
+assert(constructed:is_variant("TupleStruct"), "Expected TupleStruct but got " .. constructed:variant_name())
+fields = constructed:variant_fields()
+assert(#fields == 1, "Expected 1 field but got " .. #fields)
+assert(fields[1] == 456, "Expected fields[1] to be 456 but got " .. tostring(fields[1]))
+
+-- Unit Variant
+constructed = SimpleEnum.Unit()
+
+assert(constructed:is_variant("Unit"), "Expected Unit but got " .. constructed:variant_name())
+assert(#constructed:variant_fields() == 0, "Expected no fields for a unit variant")
+
+-- Non enums
+local vec = Vec3.new(1.0, 2.0, 3.0)
+assert(not vec:is_variant("Unit"), "Expected is_variant to be false for a non enum")
+assert(vec:variant_fields() == nil, "Expected no variant fields for a non enum")
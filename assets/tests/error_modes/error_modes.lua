@@ -0,0 +1,27 @@
+-- thrown errors
+assert(_checked_div(4, 2) == 2, "Expected 4 / 2 to be 2")
+local ok, err = pcall(_checked_div, 1, 0)
+assert(not ok, "Expected division by zero to throw")
+assert(string.find(tostring(err), "division by zero"), "Unexpected error: " .. tostring(err))
+
+-- nil and message
+local value, message = _checked_div_nil_and_message(4, 2)
+assert(value == 2, "Expected 4 / 2 to be 2")
+assert(message == nil, "Expected no error message")
+value, message = _checked_div_nil_and_message(1, 0)
+assert(value == nil, "Expected nil on error")
+assert(string.find(message, "division by zero"), "Unexpected error: " .. tostring(message))
+
+-- references
+local result = _checked_div_reference(4, 2)
+assert(result:is_ok(), "Expected an ok result")
+assert(not result:is_err(), "Expected an ok result")
+assert(result:unwrap() == 2, "Expected 4 / 2 to be 2")
+assert(result:err() == nil, "Expected no error")
+
+result = _checked_div_reference(1, 0)
+assert(result:is_err(), "Expected an error result")
+assert(result:unwrap_or(-1) == -1, "Expected the default value")
+assert(string.find(result:err(), "division by zero"), "Unexpected error: " .. tostring(result:err()))
+ok = pcall(function() return result:unwrap() end)
+assert(not ok, "Expected unwrapping an error to throw")
@@ -0,0 +1,6 @@
+local reversed = _reverse_bytes("\0\1\2\255")
+assert(type(reversed) == "string", "Expected bytes to be returned as a string but got: " .. type(reversed))
+assert(reversed == "\255\2\1\0", "Expected the bytes to be reversed")
+
+assert(_reverse_bytes(_reverse_bytes("abc")) == "abc", "Expected the bytes to survive a round trip")
+assert(_reverse_bytes({ 1, 2, 3 }) == "\3\2\1", "Expected a list of integers to be accepted as bytes")
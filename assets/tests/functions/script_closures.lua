@@ -0,0 +1,9 @@
+local sorted = _sort_with({ 3, 1, 2 }, function(a, b) return a > b end)
+assert(sorted[1] == 3 and sorted[2] == 2 and sorted[3] == 1, "Expected the list to be sorted in descending order")
+
+local offset = 10
+_store_callable(function(x) return x + offset end)
+assert(_call_stored_callable({ 1 }) == 11, "Expected the stored closure to be called")
+
+local ok = pcall(_sort_with, { 1, 2 }, function(a, b) error("comparator failed") end)
+assert(not ok, "Expected errors in the closure to be propagated")
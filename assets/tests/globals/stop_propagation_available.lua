@@ -0,0 +1,2 @@
+assert(STOP_PROPAGATION ~= nil, "STOP_PROPAGATION global is not available")
+assert(STOP_PROPAGATION:display() ~= nil, "STOP_PROPAGATION should be a reference")
@@ -0,0 +1,29 @@
+local res_type = world.get_type_by_name("TestResourceWithVariousFields")
+local res = world.get_resource(res_type)
+local set = res.string_set
+
+assert(set:contains("foo") == true, "Expected set to contain 'foo'")
+assert(set:contains("missing") == false, "Expected set to not contain 'missing'")
+
+set:insert("new")
+assert(set:contains("new") == true, "Expected set to contain 'new' after insert")
+assert(set:len() == 5, "Expected 5 elements, got " .. set:len())
+
+set:remove("new")
+assert(set:contains("new") == false, "Expected set to not contain 'new' after remove")
+assert(set:len() == 4, "Expected 4 elements, got " .. set:len())
+
+local union = set:union({ "foo", "other" })
+assert(union:len() == 5, "Expected union of 5 elements, got " .. union:len())
+assert(union:contains("other") == true, "Expected union to contain 'other'")
+assert(set:len() == 4, "Union modified the original set")
+
+local intersection = set:intersection({ "foo", "bar", "other" })
+assert(intersection:len() == 2, "Expected intersection of 2 elements, got " .. intersection:len())
+assert(intersection:contains("foo") == true, "Expected intersection to contain 'foo'")
+assert(intersection:contains("other") == false, "Expected intersection to not contain 'other'")
+
+local difference = set:difference(intersection)
+assert(difference:len() == 2, "Expected difference of 2 elements, got " .. difference:len())
+assert(difference:contains("zoo") == true, "Expected difference to contain 'zoo'")
+assert(difference:contains("foo") == false, "Expected difference to not contain 'foo'")
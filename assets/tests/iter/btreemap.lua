@@ -0,0 +1,11 @@
+local res_type = world.get_type_by_name("TestResourceWithVariousFields")
+local res = world.get_resource(res_type)
+
+local iterated_vals = {}
+for v in pairs(res.ordered_map) do
+    iterated_vals[#iterated_vals + 1] = v
+end
+assert(#iterated_vals == 3, "Length is not 3")
+assert(iterated_vals[1] == "one", "First value is not 'one'")
+assert(iterated_vals[2] == "two", "Second value is not 'two'")
+assert(iterated_vals[3] == "three", "Third value is not 'three'")
@@ -0,0 +1,15 @@
+local res_type = world.get_type_by_name("TestResourceWithVariousFields")
+local res = world.get_resource(res_type)
+
+local iterated_vals = {}
+for v in pairs(res.string_map) do
+    iterated_vals[v] = true
+end
+assert(iterated_vals["bar"] == true, "Value 'bar' was not visited")
+assert(iterated_vals["zed"] == true, "Value 'zed' was not visited")
+
+local count = 0
+for _ in pairs(res.string_map) do
+    count = count + 1
+end
+assert(count == 2, "Expected 2 values, got " .. count)
@@ -0,0 +1,14 @@
+local res_type = world.get_type_by_name("TestResourceWithVariousFields")
+local res = world.get_resource(res_type)
+
+local iterated_vals = {}
+local count = 0
+for v in pairs(res.string_set) do
+    iterated_vals[v] = true
+    count = count + 1
+end
+assert(count == 4, "Expected 4 elements, got " .. count)
+assert(iterated_vals["foo"], "Element 'foo' was not visited")
+assert(iterated_vals["bar"], "Element 'bar' was not visited")
+assert(iterated_vals["zoo"], "Element 'zoo' was not visited")
+assert(iterated_vals["zed"], "Element 'zed' was not visited")
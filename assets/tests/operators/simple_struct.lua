@@ -0,0 +1,15 @@
+local SimpleStruct = world.get_type_by_name("SimpleStruct")
+local small = construct(SimpleStruct, { foo = 1 })
+local large = construct(SimpleStruct, { foo = 2 })
+
+assert((small .. "!") == "SimpleStruct(1)!", "Concatenation did not work, got: " .. (small .. "!"))
+assert(("?" .. large) == "?SimpleStruct(2)", "Concatenation did not work, got: " .. ("?" .. large))
+
+assert(small(10) == 11, "Call did not work")
+assert(large(0) == 2, "Call did not work")
+
+assert((small <= large) == true, "Less than or equal did not work")
+assert((small <= small) == true, "Less than or equal did not work")
+assert((large <= small) == false, "Less than or equal did not work")
+assert((large >= small) == true, "Greater than or equal did not work")
+assert((small >= large) == false, "Greater than or equal did not work")
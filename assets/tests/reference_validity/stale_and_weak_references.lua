@@ -0,0 +1,15 @@
+local component = world.get_type_by_name("TestComponent")
+local entity = world._get_entity_with_test_component("TestComponent")
+local retrieved = world.get_component(entity, component)
+assert(retrieved:is_valid(), "Expected the component reference to be valid")
+
+local weak = retrieved:downgrade()
+assert(weak:is_valid(), "Expected the weak reference to be valid")
+assert(weak:upgrade().strings[1] == "Initial", "Expected the upgraded reference to point to the component")
+
+world.despawn(entity)
+assert(not retrieved:is_valid(), "Expected the component reference to be stale after despawning")
+assert(not weak:is_valid(), "Expected the weak reference to be stale after despawning")
+assert_throws(function()
+    return retrieved.strings
+end, "was despawned")
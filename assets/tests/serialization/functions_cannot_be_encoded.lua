@@ -0,0 +1,3 @@
+assert(not pcall(function()
+    json.encode(function() end)
+end), "Encoding a function should fail")
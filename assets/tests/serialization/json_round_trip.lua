@@ -0,0 +1,6 @@
+local encoded = json.encode({ name = "player", score = 10 })
+assert(encoded == '{"name":"player","score":10}', "Unexpected json: " .. encoded)
+
+local decoded = json.decode(encoded)
+assert(decoded.name == "player", "decoded.name was: " .. tostring(decoded.name))
+assert(decoded.score == 10, "decoded.score was: " .. tostring(decoded.score))
@@ -0,0 +1,10 @@
+local component = world.get_type_by_name("TestComponent")
+local entity = world._get_entity_with_test_component("TestComponent")
+local retrieved = world.get_component(entity, component)
+
+local encoded = ron.encode(retrieved)
+assert(string.find(encoded, "TestComponent", 1, true) ~= nil, "Encoded value does not contain the type path: " .. encoded)
+
+local decoded = ron.decode(encoded)
+assert(decoded.strings[1] == "Initial", "decoded.strings[1] was: " .. decoded.strings[1])
+assert(decoded.strings[2] == "Value", "decoded.strings[2] was: " .. decoded.strings[2])
@@ -0,0 +1,20 @@
+local component = world.get_type_by_name("TestComponent")
+local entity = world._get_entity_with_test_component("TestComponent")
+local retrieved = world.get_component(entity, component)
+
+local tbl = retrieved:to_table()
+assert(type(tbl) == "table", "Expected a table but got: " .. type(tbl))
+assert(#tbl.strings == 2, "Expected 2 strings but got: " .. #tbl.strings)
+assert(tbl.strings[1] == "Initial", "tbl.strings[1] was: " .. tbl.strings[1])
+assert(tbl.strings[2] == "Value", "tbl.strings[2] was: " .. tbl.strings[2])
+
+-- the table is a snapshot and does not change the component
+tbl.strings[1] = "Changed"
+assert(retrieved.strings[1] == "Initial", "Component was modified through the table")
+
+local rebuilt = component:from_table(tbl)
+assert(rebuilt.strings[1] == "Changed", "rebuilt.strings[1] was: " .. rebuilt.strings[1])
+assert(rebuilt.strings[2] == "Value", "rebuilt.strings[2] was: " .. rebuilt.strings[2])
+
+local static_rebuilt = TestComponent.from_table({ strings = { "a" } })
+assert(static_rebuilt.strings[1] == "a", "static_rebuilt.strings[1] was: " .. static_rebuilt.strings[1])
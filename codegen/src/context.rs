@@ -81,7 +81,7 @@ pub(crate) const DEF_PATHS_GET_TYPE_REGISTRATION: [&str; 2] = [
 /// others are later used for quick lookup of the type "does this type implement Display" etc.
 pub(crate) const STD_ONLY_TRAITS: [&str; 1] = ["std::string::ToString"];
 
-pub(crate) const STD_OR_CORE_TRAITS: [&str; 13] = [
+pub(crate) const STD_OR_CORE_TRAITS: [&str; 19] = [
     // PRINTING
     "fmt::Debug",
     "fmt::Display",
@@ -94,6 +94,12 @@ pub(crate) const STD_OR_CORE_TRAITS: [&str; 13] = [
     "ops::Sub",
     "ops::Div",
     "ops::Rem",
+    "ops::BitAnd",
+    "ops::BitOr",
+    "ops::BitXor",
+    "ops::Shl",
+    "ops::Shr",
+    "ops::Not",
     "cmp::Eq",
     "cmp::PartialEq",
     "cmp::Ord", // we don't use these fully cuz of the output types not being lua primitives, but keeping it for the future
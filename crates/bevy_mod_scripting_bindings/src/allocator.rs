@@ -19,7 +19,7 @@ use std::{
     cmp::Ordering,
     fmt::{Display, Formatter},
     hash::Hasher,
-    sync::{Arc, atomic::AtomicUsize},
+    sync::{Arc, Weak, atomic::AtomicUsize},
 };
 
 /// The path used for the total number of allocations diagnostic
@@ -66,6 +66,41 @@ impl ReflectAllocationId {
     pub fn strong_count(&self) -> usize {
         Arc::strong_count(&self.0)
     }
+
+    /// Creates a [`WeakReflectAllocationId`] which does not keep the allocation alive
+    pub fn downgrade(&self) -> WeakReflectAllocationId {
+        WeakReflectAllocationId {
+            id: self.id(),
+            inner: Arc::downgrade(&self.0),
+        }
+    }
+}
+
+/// A weak version of [`ReflectAllocationId`], which does not prevent the allocation from being garbage collected
+#[derive(Clone, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+pub struct WeakReflectAllocationId {
+    id: usize,
+    #[debug_with_type_info(skip)]
+    inner: Weak<usize>,
+}
+
+impl WeakReflectAllocationId {
+    /// Returns the id of the allocation
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns a strong id if the allocation was not yet garbage collected
+    pub fn upgrade(&self) -> Option<ReflectAllocationId> {
+        self.inner.upgrade().map(ReflectAllocationId)
+    }
+}
+
+impl PartialEq for WeakReflectAllocationId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
 }
 
 impl std::hash::Hash for ReflectAllocationId {
@@ -325,6 +360,18 @@ mod test {
         assert!(ref_.reflect_partial_eq(&"string").unwrap());
     }
 
+    #[test]
+    fn test_weak_allocation_id_does_not_keep_allocation_alive() {
+        let mut allocator = ReflectAllocator::default();
+        let id = allocator.allocate(0);
+        let weak = id.downgrade();
+        assert_eq!(weak.upgrade(), Some(id.clone()));
+        drop(id);
+        allocator.clean_garbage_allocations();
+        assert_eq!(allocator.allocations.len(), 0);
+        assert!(weak.upgrade().is_none());
+    }
+
     #[test]
     fn test_reflect_allocator_garbage_clean_no_garbage() {
         let mut allocator = ReflectAllocator::default();
@@ -0,0 +1,431 @@
+//! Deep conversions between reflected values and plain script values.
+//!
+//! Unlike [`crate::IntoScriptRef`], which hands out references to anything which is not a primitive, these conversions
+//! copy the whole value into maps and lists, so scripts can read it without crossing the language boundary on every access.
+//! The opposite direction builds concrete values from such maps and lists, in the same format accepted by [`WorldExtensions::construct`].
+
+use std::{any::TypeId, collections::VecDeque, sync::Arc};
+
+use bevy_mod_scripting_world::WorldGuard;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{
+    PartialReflect, ReflectRef, TypeInfo,
+    enums::{DynamicEnum, DynamicVariant},
+    list::DynamicList,
+    map::{DynamicMap, Map},
+    set::{DynamicSet, Set},
+    tuple::DynamicTuple,
+};
+
+use crate::{
+    InteropError, Primitive, ReflectReference, ScriptTypeRegistration, ScriptValue,
+    WorldExtensions,
+    function::from_ref::FromScriptRef,
+    integer_into_script,
+    reflection_extensions::{PartialReflectExt, TypeInfoExtensions},
+};
+
+/// Converts a reflected value into a plain [`ScriptValue`], recursively converting all of its fields.
+///
+/// - Primitives are converted into their script equivalents
+/// - Structs and maps are converted into [`ScriptValue::Map`]s, map keys must be strings or primitives
+/// - Tuples, tuple structs, lists, arrays and sets are converted into [`ScriptValue::List`]s
+/// - Enums are converted into maps with a `variant` key holding the variant name, and the fields of the variant,
+///   this is the same format accepted by [`WorldExtensions::construct`]
+/// - Options are converted into their inner value or [`ScriptValue::Unit`]
+/// - Any other opaque values are copied into a new allocation and returned as references
+///
+/// `one_indexed` decides the keys of tuple variant fields, and should be set for languages with one-indexed collections.
+pub fn reflect_into_script_value(
+    value: &dyn PartialReflect,
+    one_indexed: bool,
+    world: WorldGuard,
+) -> Result<ScriptValue, InteropError> {
+    match Primitive::from(value) {
+        Primitive::I(i) => return integer_into_script(i, world),
+        Primitive::U(u) => return integer_into_script(u, world),
+        Primitive::F(f) => return Ok(ScriptValue::Float(f)),
+        Primitive::B(b) => return Ok(ScriptValue::Bool(b)),
+        Primitive::S(s) => return Ok(ScriptValue::String(s.into_owned().into())),
+        Primitive::Unit => return Ok(ScriptValue::Unit),
+        Primitive::O(_) => {}
+    }
+
+    if let Some(script_value) = value.try_downcast_ref::<ScriptValue>() {
+        return Ok(script_value.clone());
+    }
+
+    if let Some(bytes) = value.try_downcast_ref::<Vec<u8>>() {
+        return Ok(ScriptValue::Bytes(bytes.clone()));
+    }
+
+    if let Ok(option) = value.as_option() {
+        return match option {
+            Some(inner) => reflect_into_script_value(inner, one_indexed, world),
+            None => Ok(ScriptValue::Unit),
+        };
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => {
+            let mut map = HashMap::default();
+            for (name, field) in s.iter_fields() {
+                map.insert(
+                    name.to_owned(),
+                    reflect_into_script_value(field, one_indexed, world.clone())?,
+                );
+            }
+            Ok(ScriptValue::Map(map))
+        }
+        ReflectRef::TupleStruct(s) => list_into_script_value(s.iter_fields(), one_indexed, world),
+        ReflectRef::Tuple(t) => list_into_script_value(t.iter_fields(), one_indexed, world),
+        ReflectRef::List(l) => list_into_script_value(l.iter(), one_indexed, world),
+        ReflectRef::Array(a) => list_into_script_value(a.iter(), one_indexed, world),
+        ReflectRef::Set(s) => list_into_script_value(s.iter(), one_indexed, world),
+        ReflectRef::Map(m) => {
+            let mut map = HashMap::default();
+            for (key, value) in m.iter() {
+                let key = match reflect_into_script_value(key, one_indexed, world.clone())? {
+                    ScriptValue::String(s) => s.into_owned(),
+                    ScriptValue::Integer(i) => i.to_string(),
+                    ScriptValue::Float(f) => f.to_string(),
+                    ScriptValue::Bool(b) => b.to_string(),
+                    key => {
+                        return Err(InteropError::value_mismatch(
+                            std::any::TypeId::of::<String>(),
+                            key,
+                        ));
+                    }
+                };
+                map.insert(
+                    key,
+                    reflect_into_script_value(value, one_indexed, world.clone())?,
+                );
+            }
+            Ok(ScriptValue::Map(map))
+        }
+        ReflectRef::Enum(e) => {
+            let mut map = HashMap::default();
+            map.insert(
+                "variant".to_owned(),
+                ScriptValue::String(e.variant_name().to_owned().into()),
+            );
+            for (idx, field) in e.iter_fields().enumerate() {
+                let key = match field.name() {
+                    Some(name) => name.to_owned(),
+                    None => (idx + usize::from(one_indexed)).to_string(),
+                };
+                map.insert(
+                    key,
+                    reflect_into_script_value(field.value(), one_indexed, world.clone())?,
+                );
+            }
+            Ok(ScriptValue::Map(map))
+        }
+        _ => {
+            let owned = <dyn PartialReflect>::from_reflect_or_clone(value, world.clone())?;
+            let allocator = world.allocator();
+            let mut allocator = allocator.write();
+            ReflectReference::new_allocated_boxed_parial_reflect(owned, &mut allocator)
+                .map(ScriptValue::Reference)
+        }
+    }
+}
+
+fn list_into_script_value<'a>(
+    items: impl Iterator<Item = &'a dyn PartialReflect>,
+    one_indexed: bool,
+    world: WorldGuard,
+) -> Result<ScriptValue, InteropError> {
+    items
+        .map(|item| reflect_into_script_value(item, one_indexed, world.clone()))
+        .collect::<Result<VecDeque<_>, _>>()
+        .map(ScriptValue::List)
+}
+
+/// Converts a plain [`ScriptValue`] into a reflected value of the `target` type, recursively constructing all of its fields.
+///
+/// This is the inverse of [`reflect_into_script_value`]:
+/// - Maps are constructed into structs, tuple structs, tuples and enums via [`WorldExtensions::construct`]
+/// - Lists are constructed into tuples and tuple structs positionally
+/// - Strings are constructed into unit enum variants of the same name
+/// - Lists, sets and maps of nested values are constructed element by element
+/// - Anything else, including references, is converted via [`FromScriptRef`]
+pub fn script_value_into_reflect(
+    value: ScriptValue,
+    target: TypeId,
+    one_indexed: bool,
+    world: WorldGuard,
+) -> Result<Box<dyn PartialReflect>, InteropError> {
+    let type_info = world.type_registry().read().get_type_info(target);
+    let Some(type_info) = type_info.filter(|info| !matches!(info, TypeInfo::Opaque(_))) else {
+        return <Box<dyn PartialReflect>>::from_script_ref(target, value, world);
+    };
+
+    let construct = |payload: HashMap<String, ScriptValue>| {
+        let registration = world
+            .type_registry()
+            .read()
+            .get(target)
+            .cloned()
+            .ok_or_else(|| {
+                InteropError::missing_type_data(
+                    target,
+                    "Type was not registered, could not construct it.".to_owned(),
+                )
+            })?;
+        world.construct(
+            ScriptTypeRegistration::new(Arc::new(registration)),
+            payload,
+            one_indexed,
+        )
+    };
+
+    match (type_info, value) {
+        (_, value @ (ScriptValue::Reference(_) | ScriptValue::Unit)) => {
+            <Box<dyn PartialReflect>>::from_script_ref(target, value, world)
+        }
+        (type_info, value) if type_info.option_inner_type().is_some() => {
+            let inner_type = type_info.option_inner_type().unwrap_or(target);
+            let inner = script_value_into_reflect(value, inner_type, one_indexed, world)?;
+            let mut option = DynamicEnum::new(
+                "Some",
+                DynamicVariant::Tuple(DynamicTuple::from_iter(vec![inner])),
+            );
+            option.set_represented_type(Some(type_info));
+            Ok(Box::new(option))
+        }
+        (
+            TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) | TypeInfo::Tuple(_) | TypeInfo::Enum(_),
+            ScriptValue::Map(payload),
+        ) => construct(payload),
+        (TypeInfo::TupleStruct(_) | TypeInfo::Tuple(_), ScriptValue::List(fields)) => {
+            let offset = usize::from(one_indexed);
+            construct(
+                fields
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, field)| ((idx + offset).to_string(), field))
+                    .collect(),
+            )
+        }
+        (TypeInfo::Enum(_), ScriptValue::String(variant)) => construct(
+            [("variant".to_owned(), ScriptValue::String(variant))]
+                .into_iter()
+                .collect(),
+        ),
+        (TypeInfo::List(info), ScriptValue::List(items)) => {
+            let mut list = DynamicList::default();
+            for item in items {
+                list.push_box(script_value_into_reflect(
+                    item,
+                    info.item_ty().id(),
+                    one_indexed,
+                    world.clone(),
+                )?);
+            }
+            list.set_represented_type(Some(type_info));
+            Ok(Box::new(list))
+        }
+        (TypeInfo::Set(info), ScriptValue::List(items)) => {
+            let mut set = DynamicSet::default();
+            for item in items {
+                set.insert_boxed(script_value_into_reflect(
+                    item,
+                    info.value_ty().id(),
+                    one_indexed,
+                    world.clone(),
+                )?);
+            }
+            set.set_represented_type(Some(type_info));
+            Ok(Box::new(set))
+        }
+        (TypeInfo::Map(info), ScriptValue::Map(entries)) => {
+            let mut map = DynamicMap::default();
+            for (key, value) in entries {
+                // keys are always strings in scripts, but might be integers in rust
+                let key_type = info.key_ty().id();
+                let key = <Box<dyn PartialReflect>>::from_script_ref(
+                    key_type,
+                    ScriptValue::String(key.clone().into()),
+                    world.clone(),
+                )
+                .or_else(|err| match key.parse::<i64>() {
+                    Ok(integer) => <Box<dyn PartialReflect>>::from_script_ref(
+                        key_type,
+                        ScriptValue::Integer(integer),
+                        world.clone(),
+                    ),
+                    Err(_) => Err(err),
+                })?;
+                let value = script_value_into_reflect(
+                    value,
+                    info.value_ty().id(),
+                    one_indexed,
+                    world.clone(),
+                )?;
+                map.insert_boxed(key, value);
+            }
+            map.set_represented_type(Some(type_info));
+            Ok(Box::new(map))
+        }
+        (_, value) => <Box<dyn PartialReflect>>::from_script_ref(target, value, world),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy_ecs::{reflect::AppTypeRegistry, world::World};
+    use bevy_mod_scripting_world::WorldAccessGuard;
+    use bevy_reflect::Reflect;
+
+    use super::*;
+    use crate::{AppReflectAllocator, CurrentScriptAttachment};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Inner(f32, String);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Empty,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Outer {
+        inner: Inner,
+        shapes: Vec<Shape>,
+        tags: std::collections::BTreeMap<u32, bool>,
+        parent: Option<u8>,
+    }
+
+    fn with_world(f: impl FnOnce(WorldGuard)) {
+        let mut world = World::default();
+        world.init_resource::<AppReflectAllocator>();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Outer>();
+        world.insert_resource(registry);
+        let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+        f(WorldAccessGuard::new_exclusive(&mut world, cache));
+    }
+
+    fn map(entries: impl IntoIterator<Item = (&'static str, ScriptValue)>) -> ScriptValue {
+        ScriptValue::Map(
+            entries
+                .into_iter()
+                .map(|(k, v)| (k.to_owned(), v))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn nested_values_are_converted_deeply() {
+        with_world(|world| {
+            let value = Outer {
+                inner: Inner(1.5, "hello".to_owned()),
+                shapes: vec![
+                    Shape::Circle { radius: 2.0 },
+                    Shape::Rect(1.0, 3.0),
+                    Shape::Empty,
+                ],
+                tags: [(7, true)].into_iter().collect(),
+                parent: None,
+            };
+
+            let converted = reflect_into_script_value(&value, true, world).unwrap();
+
+            let expected = map([
+                (
+                    "inner",
+                    ScriptValue::List(
+                        vec![ScriptValue::Float(1.5), ScriptValue::String("hello".into())].into(),
+                    ),
+                ),
+                (
+                    "shapes",
+                    ScriptValue::List(
+                        vec![
+                            map([
+                                ("variant", ScriptValue::String("Circle".into())),
+                                ("radius", ScriptValue::Float(2.0)),
+                            ]),
+                            map([
+                                ("variant", ScriptValue::String("Rect".into())),
+                                ("1", ScriptValue::Float(1.0)),
+                                ("2", ScriptValue::Float(3.0)),
+                            ]),
+                            map([("variant", ScriptValue::String("Empty".into()))]),
+                        ]
+                        .into(),
+                    ),
+                ),
+                ("tags", map([("7", ScriptValue::Bool(true))])),
+                ("parent", ScriptValue::Unit),
+            ]);
+            pretty_assertions::assert_eq!(converted, expected);
+        });
+    }
+
+    #[test]
+    fn tuple_variant_keys_respect_indexing() {
+        with_world(|world| {
+            let converted =
+                reflect_into_script_value(&Shape::Rect(1.0, 3.0), false, world).unwrap();
+            let ScriptValue::Map(map) = converted else {
+                panic!("expected a map, got: {converted:?}");
+            };
+            assert_eq!(map.get("0"), Some(&ScriptValue::Float(1.0)));
+            assert_eq!(map.get("1"), Some(&ScriptValue::Float(3.0)));
+        });
+    }
+
+    #[test]
+    fn script_values_are_constructed_deeply() {
+        with_world(|world| {
+            let value = Outer {
+                inner: Inner(1.5, "hello".to_owned()),
+                shapes: vec![
+                    Shape::Circle { radius: 2.0 },
+                    Shape::Rect(1.0, 3.0),
+                    Shape::Empty,
+                ],
+                tags: [(7, true)].into_iter().collect(),
+                parent: Some(3),
+            };
+
+            for one_indexed in [true, false] {
+                let converted =
+                    reflect_into_script_value(&value, one_indexed, world.clone()).unwrap();
+                let constructed = script_value_into_reflect(
+                    converted,
+                    TypeId::of::<Outer>(),
+                    one_indexed,
+                    world.clone(),
+                )
+                .unwrap();
+                let constructed =
+                    <Outer as bevy_reflect::FromReflect>::from_reflect(constructed.as_ref())
+                        .unwrap();
+                pretty_assertions::assert_eq!(constructed, value);
+            }
+        });
+    }
+
+    #[test]
+    fn unit_variants_can_be_constructed_from_strings() {
+        with_world(|world| {
+            let constructed = script_value_into_reflect(
+                ScriptValue::String("Empty".into()),
+                TypeId::of::<Shape>(),
+                true,
+                world,
+            )
+            .unwrap();
+            assert_eq!(
+                <Shape as bevy_reflect::FromReflect>::from_reflect(constructed.as_ref()),
+                Some(Shape::Empty)
+            );
+        });
+    }
+}
@@ -0,0 +1,320 @@
+//! Lossless conversions of integers wider than the script integer type.
+//!
+//! Scripts represent integers as 64-bit signed integers, the [`IntegerOverflowPolicy`] decides what happens to values which do not fit,
+//! either when passed to a script or when passed back into a narrower rust integer.
+
+use std::{any::TypeId, fmt::Display};
+
+use bevy_ecs::resource::Resource;
+use bevy_mod_scripting_derive::{ArgMeta, GetTypeDependencies, TypedThrough};
+use bevy_mod_scripting_world::{CachedRegistry, WorldGuard};
+use bevy_reflect::{Reflect, TypeRegistry, Typed};
+
+use crate::{
+    FromScript, InteropError, IntoScript, ScriptValue, WorldExtensions, function::from::V,
+};
+
+/// Decides what happens to integers which do not fit into the integer type they are converted into.
+///
+/// Insert it as a resource to change the policy, by default overflowing integers produce an [`InteropError`].
+#[derive(Resource, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IntegerOverflowPolicy {
+    /// Overflowing integers produce an [`InteropError::IntegerOverflow`] error
+    #[default]
+    Error,
+    /// Overflowing integers are clamped to the closest value representable by the target type
+    Saturate,
+    /// Integers which do not fit into a script integer are passed to scripts as a [`BigInt`] reference.
+    ///
+    /// Converting a script value into a narrower rust integer still produces an error on overflow.
+    Boxed,
+}
+
+impl CachedRegistry for IntegerOverflowPolicy {
+    const SLOT: usize = 5;
+}
+
+/// An integer which does not fit into a script integer, passed to scripts as a reference.
+///
+/// Produced when the [`IntegerOverflowPolicy::Boxed`] policy is active, supports arithmetic and comparisons
+/// with other big integers and script integers, and can be passed back anywhere an integer is expected.
+#[derive(
+    Reflect,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    TypedThrough,
+    GetTypeDependencies,
+    ArgMeta,
+)]
+#[reflect(opaque, Clone, Debug, PartialEq, Hash)]
+#[typed_through(bms_bindings_path = "crate")]
+#[get_type_dependencies(bms_bindings_path = "crate")]
+#[arg_meta(bms_bindings_path = "crate")]
+pub struct BigInt(pub i128);
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[profiling::all_functions]
+impl FromScript for BigInt {
+    type This<'w> = Self;
+    fn from_script(value: ScriptValue, world: WorldGuard) -> Result<Self, InteropError> {
+        match value {
+            ScriptValue::Integer(i) => Ok(BigInt(i as i128)),
+            ScriptValue::String(s) => s
+                .trim()
+                .parse()
+                .map(BigInt)
+                .map_err(|_| InteropError::value_mismatch(TypeId::of::<BigInt>(), s.into())),
+            ScriptValue::Reference(r) => r.downcast::<Self>(world),
+            _ => Err(InteropError::value_mismatch(TypeId::of::<BigInt>(), value)),
+        }
+    }
+}
+
+#[profiling::all_functions]
+impl IntoScript for BigInt {
+    fn into_script(self, world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        V(self).into_script(world)
+    }
+}
+
+/// A rust integer type which can be converted into and from script integers according to the [`IntegerOverflowPolicy`].
+pub trait ScriptInteger: Copy + Display + 'static {
+    /// The smallest value of this type
+    const MIN: Self;
+    /// The largest value of this type
+    const MAX: Self;
+
+    /// Converts the integer into an `i64` if it fits
+    fn to_i64(self) -> Option<i64>;
+
+    /// Converts the integer into an `i128` if it fits
+    fn to_i128(self) -> Option<i128>;
+
+    /// Converts an `i128` into this integer type if it fits
+    fn from_i128(value: i128) -> Option<Self>;
+
+    /// Converts a float into this integer type if its integer part fits, the fractional part is discarded
+    fn from_f64(value: f64) -> Option<Self>;
+}
+
+macro_rules! impl_script_integer {
+    ($($ty:ty),*) => {
+        $(
+            #[allow(
+                clippy::useless_conversion,
+                reason = "the conversions are generated for every integer type"
+            )]
+            impl ScriptInteger for $ty {
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+
+                fn to_i64(self) -> Option<i64> {
+                    i64::try_from(self).ok()
+                }
+
+                fn to_i128(self) -> Option<i128> {
+                    i128::try_from(self).ok()
+                }
+
+                fn from_i128(value: i128) -> Option<Self> {
+                    <$ty>::try_from(value).ok()
+                }
+
+                fn from_f64(value: f64) -> Option<Self> {
+                    // both bounds are zero or powers of two, which floats represent exactly
+                    let signed = <$ty>::MIN != 0;
+                    let lower = <$ty>::MIN as f64;
+                    let upper = 2f64.powi(<$ty>::BITS as i32 - signed as i32);
+                    let value = value.trunc();
+                    (lower..upper).contains(&value).then_some(value as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_script_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Converts a rust integer into a script value, applying the [`IntegerOverflowPolicy`] of the world if it does not fit into a script integer.
+pub fn integer_into_script<T: ScriptInteger>(
+    value: T,
+    world: WorldGuard,
+) -> Result<ScriptValue, InteropError> {
+    if let Some(value) = value.to_i64() {
+        return Ok(ScriptValue::Integer(value));
+    }
+
+    match world.integer_overflow_policy() {
+        IntegerOverflowPolicy::Error => {
+            Err(InteropError::integer_overflow(value, TypeId::of::<i64>()))
+        }
+        IntegerOverflowPolicy::Saturate => Ok(ScriptValue::Integer(match value.to_i128() {
+            Some(value) if value < 0 => i64::MIN,
+            _ => i64::MAX,
+        })),
+        IntegerOverflowPolicy::Boxed => match value.to_i128() {
+            Some(value) => BigInt(value).into_script(world),
+            None => Err(InteropError::integer_overflow(
+                value,
+                TypeId::of::<BigInt>(),
+            )),
+        },
+    }
+}
+
+/// Converts an integer into the rust integer type `T`, applying the [`IntegerOverflowPolicy`] of the world if it does not fit.
+pub fn integer_from_script<T: ScriptInteger>(
+    value: i128,
+    world: &WorldGuard,
+) -> Result<T, InteropError> {
+    T::from_i128(value)
+        .or_else(|| saturate(value < 0, world.integer_overflow_policy()))
+        .ok_or_else(|| InteropError::integer_overflow(value, TypeId::of::<T>()))
+}
+
+/// Converts a float into the rust integer type `T`, discarding the fractional part and applying the [`IntegerOverflowPolicy`] of the world if it does not fit.
+///
+/// `NaN` never fits.
+pub fn integer_from_script_float<T: ScriptInteger>(
+    value: f64,
+    world: &WorldGuard,
+) -> Result<T, InteropError> {
+    T::from_f64(value)
+        .or_else(|| {
+            (!value.is_nan())
+                .then(|| saturate(value < 0.0, world.integer_overflow_policy()))
+                .flatten()
+        })
+        .ok_or_else(|| InteropError::integer_overflow(value, TypeId::of::<T>()))
+}
+
+/// The value an overflowing integer is replaced with under the given policy, if any.
+pub fn saturate<T: ScriptInteger>(negative: bool, policy: IntegerOverflowPolicy) -> Option<T> {
+    match policy {
+        IntegerOverflowPolicy::Saturate if negative => Some(T::MIN),
+        IntegerOverflowPolicy::Saturate => Some(T::MAX),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy_ecs::world::World;
+    use bevy_mod_scripting_world::WorldAccessGuard;
+
+    use super::*;
+    use crate::{AppReflectAllocator, CurrentScriptAttachment};
+
+    fn with_policy(policy: IntegerOverflowPolicy, f: impl FnOnce(WorldGuard)) {
+        let mut world = World::default();
+        world.init_resource::<AppReflectAllocator>();
+        world.insert_resource(policy);
+        let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+        f(WorldAccessGuard::new_exclusive(&mut world, cache));
+    }
+
+    #[test]
+    fn integers_in_range_are_converted_losslessly() {
+        with_policy(IntegerOverflowPolicy::Error, |world| {
+            assert_eq!(
+                integer_into_script(i64::MAX as u64, world.clone()).unwrap(),
+                ScriptValue::Integer(i64::MAX)
+            );
+            assert_eq!(integer_from_script::<u8>(255, &world).unwrap(), 255);
+        });
+    }
+
+    #[test]
+    fn overflow_produces_error_by_default() {
+        with_policy(IntegerOverflowPolicy::Error, |world| {
+            assert!(matches!(
+                integer_into_script(u64::MAX, world.clone()),
+                Err(InteropError::IntegerOverflow { .. })
+            ));
+            assert!(matches!(
+                integer_from_script::<u8>(256, &world),
+                Err(InteropError::IntegerOverflow { .. })
+            ));
+            assert!(matches!(
+                integer_from_script::<u64>(-1, &world),
+                Err(InteropError::IntegerOverflow { .. })
+            ));
+        });
+    }
+
+    #[test]
+    fn overflow_saturates_with_saturate_policy() {
+        with_policy(IntegerOverflowPolicy::Saturate, |world| {
+            assert_eq!(
+                integer_into_script(u128::MAX, world.clone()).unwrap(),
+                ScriptValue::Integer(i64::MAX)
+            );
+            assert_eq!(
+                integer_into_script(i128::MIN, world.clone()).unwrap(),
+                ScriptValue::Integer(i64::MIN)
+            );
+            assert_eq!(integer_from_script::<u8>(-5, &world).unwrap(), 0);
+            assert_eq!(integer_from_script::<i8>(300, &world).unwrap(), i8::MAX);
+            assert_eq!(integer_from_script_float::<u8>(-5.5, &world).unwrap(), 0);
+            assert_eq!(
+                integer_from_script_float::<i64>(1e30, &world).unwrap(),
+                i64::MAX
+            );
+            assert!(integer_from_script_float::<i64>(f64::NAN, &world).is_err());
+        });
+    }
+
+    #[test]
+    fn floats_are_range_checked() {
+        with_policy(IntegerOverflowPolicy::Error, |world| {
+            assert_eq!(integer_from_script_float::<u8>(255.9, &world).unwrap(), 255);
+            assert_eq!(
+                integer_from_script_float::<i8>(-128.0, &world).unwrap(),
+                -128
+            );
+            assert_eq!(
+                integer_from_script_float::<u64>(u64::MAX as f64 / 2.0, &world).unwrap(),
+                1 << 63
+            );
+            for value in [256.0, -1.0, f64::INFINITY, f64::NAN] {
+                assert!(matches!(
+                    integer_from_script_float::<u8>(value, &world),
+                    Err(InteropError::IntegerOverflow { .. })
+                ));
+            }
+            assert!(integer_from_script_float::<u64>(u64::MAX as f64, &world).is_err());
+            assert!(integer_from_script_float::<i128>(2f64.powi(127), &world).is_err());
+        });
+    }
+
+    #[test]
+    fn overflow_is_boxed_with_boxed_policy() {
+        with_policy(IntegerOverflowPolicy::Boxed, |world| {
+            let value = integer_into_script(u64::MAX, world.clone()).unwrap();
+            assert!(matches!(value, ScriptValue::Reference(_)));
+            assert_eq!(
+                BigInt::from_script(value.clone(), world.clone()).unwrap(),
+                BigInt(u64::MAX as i128)
+            );
+            assert_eq!(u64::from_script(value, world.clone()).unwrap(), u64::MAX);
+
+            assert!(matches!(
+                integer_into_script(u128::MAX, world.clone()),
+                Err(InteropError::IntegerOverflow { .. })
+            ));
+        });
+    }
+}
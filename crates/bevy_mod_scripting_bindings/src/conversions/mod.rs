@@ -1,5 +1,9 @@
 //! Conversions from untyped reflected values to concrete values
 
+mod deep;
+mod integer;
 mod primitive;
 
+pub use deep::*;
+pub use integer::*;
 pub use primitive::*;
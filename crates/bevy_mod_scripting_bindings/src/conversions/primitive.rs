@@ -3,6 +3,8 @@ use std::{any::TypeId, borrow::Cow, ffi::OsString, path::PathBuf};
 use bevy_mod_scripting_display::OrFakeId;
 use bevy_reflect::PartialReflect;
 
+use crate::{IntegerOverflowPolicy, ScriptInteger, saturate};
+
 /// Attempts to convert the value given by `value` into a suitable value of the target type.
 ///
 /// This will match up primitive types against "similar" or "appropriate" types.
@@ -12,19 +14,25 @@ use bevy_reflect::PartialReflect;
 ///
 /// This operation is mostly intended to be used when primitives will generally be expected and flexibility is required.
 ///
+/// Integers and floats which do not fit into the target integer type are handled according to the given [`IntegerOverflowPolicy`].
+///
 /// Note: string ref types cannot be effectively converted into their static reference versions without leaking, so they are not supported here.
-pub fn convert(value: &dyn PartialReflect, target: TypeId) -> Option<Box<dyn PartialReflect>> {
+pub fn convert(
+    value: &dyn PartialReflect,
+    target: TypeId,
+    policy: IntegerOverflowPolicy,
+) -> Option<Box<dyn PartialReflect>> {
     let primitive = Primitive::from(value);
-    primitive.convert(target)
+    primitive.convert(target, policy)
 }
 
 /// A coercion primitive used for intermediate normalizations.
 #[derive(Debug)]
 pub enum Primitive<'a> {
     /// Integer family
-    I(i64),
+    I(i128),
     /// Unsized Integer family
-    U(u64),
+    U(u128),
     /// Floating point family
     F(f64),
     /// Boolean family
@@ -37,80 +45,72 @@ pub enum Primitive<'a> {
     Unit,
 }
 
+/// Converts an integer into the integer or float type given by `target`.
+///
+/// Integers which do not fit are saturated if the policy says so, otherwise they are not converted.
+macro_rules! convert_integer {
+    ($value:expr, $target:expr, $policy:expr) => {{
+        let value = $value;
+        let target = $target;
+        if target == TypeId::of::<f32>() {
+            Some(Box::new(value as f32) as Box<dyn PartialReflect>)
+        } else if target == TypeId::of::<f64>() {
+            Some(Box::new(value as f64))
+        } else {
+            convert_integer!(value, target, $policy, [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize])
+        }
+    }};
+    ($value:expr, $target:expr, $policy:expr, [$($ty:ty),*]) => {
+        $(
+            if $target == TypeId::of::<$ty>() {
+                <$ty>::try_from($value)
+                    .ok()
+                    .or_else(|| saturate::<$ty>($value.to_i128().is_some_and(|v| v < 0), $policy))
+                    .map(|v| Box::new(v) as Box<dyn PartialReflect>)
+            } else
+        )* {
+            None
+        }
+    };
+}
+
+/// Converts a float into the integer type given by `target`, discarding the fractional part.
+///
+/// Floats which do not fit are saturated if the policy says so, otherwise they are not converted.
+macro_rules! convert_float {
+    ($value:expr, $target:expr, $policy:expr, [$($ty:ty),*]) => {
+        $(
+            if $target == TypeId::of::<$ty>() {
+                <$ty>::from_f64($value)
+                    .or_else(|| {
+                        (!$value.is_nan())
+                            .then(|| saturate::<$ty>($value < 0.0, $policy))
+                            .flatten()
+                    })
+                    .map(|v| Box::new(v) as Box<dyn PartialReflect>)
+            } else
+        )* {
+            None
+        }
+    };
+}
+
 impl<'a> Primitive<'a> {
     /// Converts the primitive into the target TypeId
-    pub fn convert(self, target: TypeId) -> Option<Box<dyn PartialReflect>> {
+    #[allow(
+        clippy::useless_conversion,
+        reason = "integer conversions are generated for every pair of integer types"
+    )]
+    pub fn convert(
+        self,
+        target: TypeId,
+        policy: IntegerOverflowPolicy,
+    ) -> Option<Box<dyn PartialReflect>> {
         use Primitive::*;
         match self {
-            // Integer conversions
-            I(i) => {
-                if target == TypeId::of::<i8>() {
-                    Some(Box::new(i as i8))
-                } else if target == TypeId::of::<i16>() {
-                    Some(Box::new(i as i16))
-                } else if target == TypeId::of::<i32>() {
-                    Some(Box::new(i as i32))
-                } else if target == TypeId::of::<i64>() {
-                    Some(Box::new(i))
-                } else if target == TypeId::of::<i128>() {
-                    Some(Box::new(i as i128))
-                } else if target == TypeId::of::<isize>() {
-                    Some(Box::new(i as isize))
-                } else if target == TypeId::of::<u8>() {
-                    Some(Box::new(i as u8))
-                } else if target == TypeId::of::<u16>() {
-                    Some(Box::new(i as u16))
-                } else if target == TypeId::of::<u32>() {
-                    Some(Box::new(i as u32))
-                } else if target == TypeId::of::<u64>() {
-                    Some(Box::new(i as u64))
-                } else if target == TypeId::of::<u128>() {
-                    Some(Box::new(i as u128))
-                } else if target == TypeId::of::<usize>() {
-                    Some(Box::new(i as usize))
-                } else if target == TypeId::of::<f32>() {
-                    Some(Box::new(i as f32))
-                } else if target == TypeId::of::<f64>() {
-                    Some(Box::new(i as f64))
-                } else {
-                    None
-                }
-            }
-
-            // Unsigned integers
-            U(u) => {
-                if target == TypeId::of::<i8>() {
-                    Some(Box::new(u as i8))
-                } else if target == TypeId::of::<i16>() {
-                    Some(Box::new(u as i16))
-                } else if target == TypeId::of::<i32>() {
-                    Some(Box::new(u as i32))
-                } else if target == TypeId::of::<i64>() {
-                    Some(Box::new(u as i64))
-                } else if target == TypeId::of::<i128>() {
-                    Some(Box::new(u as i128))
-                } else if target == TypeId::of::<isize>() {
-                    Some(Box::new(u as isize))
-                } else if target == TypeId::of::<u8>() {
-                    Some(Box::new(u as u8))
-                } else if target == TypeId::of::<u16>() {
-                    Some(Box::new(u as u16))
-                } else if target == TypeId::of::<u32>() {
-                    Some(Box::new(u as u32))
-                } else if target == TypeId::of::<u64>() {
-                    Some(Box::new(u))
-                } else if target == TypeId::of::<u128>() {
-                    Some(Box::new(u as u128))
-                } else if target == TypeId::of::<usize>() {
-                    Some(Box::new(u as usize))
-                } else if target == TypeId::of::<f32>() {
-                    Some(Box::new(u as f32))
-                } else if target == TypeId::of::<f64>() {
-                    Some(Box::new(u as f64))
-                } else {
-                    None
-                }
-            }
+            // Integer conversions, integers which do not fit into the target are only converted when saturating
+            I(i) => convert_integer!(i, target, policy),
+            U(u) => convert_integer!(u, target, policy),
 
             // Floating point conversions
             F(f) => {
@@ -118,32 +118,15 @@ impl<'a> Primitive<'a> {
                     Some(Box::new(f as f32))
                 } else if target == TypeId::of::<f64>() {
                     Some(Box::new(f))
-                } else if target == TypeId::of::<i8>() {
-                    Some(Box::new(f as i8))
-                } else if target == TypeId::of::<i16>() {
-                    Some(Box::new(f as i16))
-                } else if target == TypeId::of::<i32>() {
-                    Some(Box::new(f as i32))
-                } else if target == TypeId::of::<i64>() {
-                    Some(Box::new(f as i64))
-                } else if target == TypeId::of::<i128>() {
-                    Some(Box::new(f as i128))
-                } else if target == TypeId::of::<isize>() {
-                    Some(Box::new(f as isize))
-                } else if target == TypeId::of::<u8>() {
-                    Some(Box::new(f as u8))
-                } else if target == TypeId::of::<u16>() {
-                    Some(Box::new(f as u16))
-                } else if target == TypeId::of::<u32>() {
-                    Some(Box::new(f as u32))
-                } else if target == TypeId::of::<u64>() {
-                    Some(Box::new(f as u64))
-                } else if target == TypeId::of::<u128>() {
-                    Some(Box::new(f as u128))
-                } else if target == TypeId::of::<usize>() {
-                    Some(Box::new(f as usize))
                 } else {
-                    None
+                    convert_float!(
+                        f,
+                        target,
+                        policy,
+                        [
+                            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+                        ]
+                    )
                 }
             }
 
@@ -210,11 +193,11 @@ impl<'a> From<&'a dyn PartialReflect> for Primitive<'a> {
         if t == TypeId::of::<usize>()
             && let Some(v) = v.try_downcast_ref::<usize>()
         {
-            Primitive::U(*v as u64)
+            Primitive::U(*v as u128)
         } else if t == TypeId::of::<isize>()
             && let Some(v) = v.try_downcast_ref::<isize>()
         {
-            Primitive::I(*v as i64)
+            Primitive::I(*v as i128)
         } else if t == TypeId::of::<bool>()
             && let Some(v) = v.try_downcast_ref::<bool>()
         {
@@ -222,43 +205,43 @@ impl<'a> From<&'a dyn PartialReflect> for Primitive<'a> {
         } else if t == TypeId::of::<u8>()
             && let Some(v) = v.try_downcast_ref::<u8>()
         {
-            Primitive::U(*v as u64)
+            Primitive::U(*v as u128)
         } else if t == TypeId::of::<u16>()
             && let Some(v) = v.try_downcast_ref::<u16>()
         {
-            Primitive::U(*v as u64)
+            Primitive::U(*v as u128)
         } else if t == TypeId::of::<u32>()
             && let Some(v) = v.try_downcast_ref::<u32>()
         {
-            Primitive::U(*v as u64)
+            Primitive::U(*v as u128)
         } else if t == TypeId::of::<u64>()
             && let Some(v) = v.try_downcast_ref::<u64>()
         {
-            Primitive::U(*v)
+            Primitive::U(*v as u128)
         } else if t == TypeId::of::<u128>()
             && let Some(v) = v.try_downcast_ref::<u128>()
         {
-            Primitive::U(*v as u64)
+            Primitive::U(*v)
         } else if t == TypeId::of::<i8>()
             && let Some(v) = v.try_downcast_ref::<i8>()
         {
-            Primitive::I(*v as i64)
+            Primitive::I(*v as i128)
         } else if t == TypeId::of::<i16>()
             && let Some(v) = v.try_downcast_ref::<i16>()
         {
-            Primitive::I(*v as i64)
+            Primitive::I(*v as i128)
         } else if t == TypeId::of::<i32>()
             && let Some(v) = v.try_downcast_ref::<i32>()
         {
-            Primitive::I(*v as i64)
+            Primitive::I(*v as i128)
         } else if t == TypeId::of::<i64>()
             && let Some(v) = v.try_downcast_ref::<i64>()
         {
-            Primitive::I(*v)
+            Primitive::I(*v as i128)
         } else if t == TypeId::of::<i128>()
             && let Some(v) = v.try_downcast_ref::<i128>()
         {
-            Primitive::I(*v as i64)
+            Primitive::I(*v)
         } else if t == TypeId::of::<f32>()
             && let Some(v) = v.try_downcast_ref::<f32>()
         {
@@ -325,7 +308,7 @@ mod tests {
         ];
 
         for (ty, expected) in targets {
-            let boxed = convert(value, *ty).unwrap();
+            let boxed = convert(value, *ty, IntegerOverflowPolicy::Error).unwrap();
             let downcasted = match *ty {
                 t if t == TypeId::of::<i8>() => *boxed.try_downcast_ref::<i8>().unwrap() as i64,
                 t if t == TypeId::of::<i16>() => *boxed.try_downcast_ref::<i16>().unwrap() as i64,
@@ -349,6 +332,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wide_integer_conversions_are_lossless() {
+        let big = u64::MAX;
+        let value: &dyn PartialReflect = &big;
+
+        let boxed = convert(value, TypeId::of::<u128>(), IntegerOverflowPolicy::Error).unwrap();
+        assert_eq!(*boxed.try_downcast_ref::<u128>().unwrap(), u64::MAX as u128);
+        let boxed = convert(value, TypeId::of::<u64>(), IntegerOverflowPolicy::Error).unwrap();
+        assert_eq!(*boxed.try_downcast_ref::<u64>().unwrap(), u64::MAX);
+
+        let huge = i128::MIN;
+        let value: &dyn PartialReflect = &huge;
+        let boxed = convert(value, TypeId::of::<i128>(), IntegerOverflowPolicy::Error).unwrap();
+        assert_eq!(*boxed.try_downcast_ref::<i128>().unwrap(), i128::MIN);
+    }
+
+    #[test]
+    fn test_overflowing_integer_conversions_fail() {
+        let big = u64::MAX;
+        let value: &dyn PartialReflect = &big;
+        assert!(convert(value, TypeId::of::<i64>(), IntegerOverflowPolicy::Error).is_none());
+        assert!(convert(value, TypeId::of::<u32>(), IntegerOverflowPolicy::Error).is_none());
+
+        let negative = -1i32;
+        let value: &dyn PartialReflect = &negative;
+        assert!(convert(value, TypeId::of::<u8>(), IntegerOverflowPolicy::Error).is_none());
+        assert!(convert(value, TypeId::of::<usize>(), IntegerOverflowPolicy::Error).is_none());
+
+        let huge = u128::MAX;
+        let value: &dyn PartialReflect = &huge;
+        assert!(convert(value, TypeId::of::<i128>(), IntegerOverflowPolicy::Error).is_none());
+    }
+
+    #[test]
+    fn test_overflowing_conversions_saturate_with_saturate_policy() {
+        let saturate = IntegerOverflowPolicy::Saturate;
+        let big = u64::MAX;
+        let value: &dyn PartialReflect = &big;
+        let boxed = convert(value, TypeId::of::<i64>(), saturate).unwrap();
+        assert_eq!(*boxed.try_downcast_ref::<i64>().unwrap(), i64::MAX);
+
+        let negative = -1i32;
+        let value: &dyn PartialReflect = &negative;
+        let boxed = convert(value, TypeId::of::<u8>(), saturate).unwrap();
+        assert_eq!(*boxed.try_downcast_ref::<u8>().unwrap(), 0);
+
+        let float = -1e30f64;
+        let value: &dyn PartialReflect = &float;
+        assert!(convert(value, TypeId::of::<i32>(), IntegerOverflowPolicy::Error).is_none());
+        let boxed = convert(value, TypeId::of::<i32>(), saturate).unwrap();
+        assert_eq!(*boxed.try_downcast_ref::<i32>().unwrap(), i32::MIN);
+
+        let nan = f64::NAN;
+        let value: &dyn PartialReflect = &nan;
+        assert!(convert(value, TypeId::of::<i32>(), saturate).is_none());
+    }
+
     #[test]
     fn test_float_conversions() {
         let f: f64 = 3.5;
@@ -364,7 +404,7 @@ mod tests {
         ];
 
         for (ty, expected) in targets {
-            let boxed = convert(value, *ty).unwrap();
+            let boxed = convert(value, *ty, IntegerOverflowPolicy::Error).unwrap();
             let val = if *ty == TypeId::of::<f32>() {
                 *boxed.try_downcast_ref::<f32>().unwrap() as f64
             } else if *ty == TypeId::of::<f64>() {
@@ -392,7 +432,7 @@ mod tests {
         let targets: &[(TypeId, bool)] = &[(TypeId::of::<bool>(), true)];
 
         for (ty, expected) in targets {
-            let boxed = convert(value, *ty).unwrap();
+            let boxed = convert(value, *ty, IntegerOverflowPolicy::Error).unwrap();
             let val = *boxed.try_downcast_ref::<bool>().unwrap();
             assert_eq!(val, *expected);
         }
@@ -412,7 +452,7 @@ mod tests {
         ];
 
         for (ty, expected) in targets {
-            let boxed = convert(value, *ty);
+            let boxed = convert(value, *ty, IntegerOverflowPolicy::Error);
             if *ty == TypeId::of::<String>() {
                 assert_eq!(
                     boxed.unwrap().try_downcast_ref::<String>().unwrap(),
@@ -445,7 +485,7 @@ mod tests {
     #[test]
     fn test_unit_conversion() {
         let unit: &dyn PartialReflect = &();
-        let boxed = convert(unit, TypeId::of::<()>()).unwrap();
+        let boxed = convert(unit, TypeId::of::<()>(), IntegerOverflowPolicy::Error).unwrap();
         assert!(boxed.try_downcast_ref::<()>().is_some());
     }
 
@@ -458,11 +498,16 @@ mod tests {
         let value: &dyn PartialReflect = &s;
 
         // Exact type match works
-        let boxed = convert(value, TypeId::of::<MyStruct>()).unwrap();
+        let boxed = convert(
+            value,
+            TypeId::of::<MyStruct>(),
+            IntegerOverflowPolicy::Error,
+        )
+        .unwrap();
         let val = boxed.try_downcast_ref::<MyStruct>().unwrap();
         assert_eq!(val.0, 10);
 
         // Mismatched type fails
-        assert!(convert(value, TypeId::of::<i32>()).is_none());
+        assert!(convert(value, TypeId::of::<i32>(), IntegerOverflowPolicy::Error).is_none());
     }
 }
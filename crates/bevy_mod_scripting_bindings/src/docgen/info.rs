@@ -1,14 +1,16 @@
 //! Information about functions and their arguments.
 
 use crate::function::arg_meta::ArgMeta;
+use crate::function::error_mode::ErrorReturnMode;
 use crate::function::namespace::Namespace;
+use bevy_mod_scripting_bindings_domain::deprecation_message;
 use bevy_mod_scripting_derive::DebugWithTypeInfo;
 use bevy_mod_scripting_display::{DisplayWithTypeInfo, WithTypeInfo};
 use bevy_mod_scripting_world::WorldGuard;
 use bevy_reflect::Reflect;
 use std::{any::TypeId, borrow::Cow};
 
-use super::typed_through::{ThroughTypeInfo, TypedThrough};
+use super::typed_through::{ThroughTypeInfo, TypedThrough, TypedWrapperKind};
 
 /// for things you can call and provide some introspection capability.
 #[diagnostic::on_unimplemented(
@@ -34,6 +36,10 @@ pub struct FunctionInfo {
     pub return_info: FunctionReturnInfo,
     /// Documentation for the function.
     pub docs: Option<Cow<'static, str>>,
+    /// Usage examples of the function, possibly in multiple scripting languages.
+    pub examples: Vec<FunctionExample>,
+    /// If set, the function is deprecated and should not be used in new scripts.
+    pub deprecation: Option<FunctionDeprecation>,
 }
 
 impl Default for FunctionInfo {
@@ -52,6 +58,8 @@ impl FunctionInfo {
             arg_info: Vec::new(),
             return_info: FunctionReturnInfo::default(),
             docs: None,
+            examples: Vec::new(),
+            deprecation: None,
         }
     }
 
@@ -63,6 +71,8 @@ impl FunctionInfo {
             arg_info: Vec::new(),
             return_info: FunctionReturnInfo::default(),
             docs: None,
+            examples: Vec::new(),
+            deprecation: None,
         }
     }
 
@@ -94,12 +104,40 @@ impl FunctionInfo {
         self
     }
 
+    /// Overrides how errors returned from this function are surfaced to scripts, see [`ErrorReturnMode`].
+    pub fn with_error_mode(mut self, mode: ErrorReturnMode) -> Self {
+        self.return_info.error_mode = Some(mode);
+        self
+    }
+
     /// Add documentation to the function info.
     pub fn with_docs(mut self, docs: impl Into<Cow<'static, str>>) -> Self {
         self.docs = Some(docs.into());
         self
     }
 
+    /// Add a usage example to the function info.
+    ///
+    /// The language should be the name of the scripting language the example is written in, i.e. `lua` or `rhai`,
+    /// documentation generators use it to highlight the code and to group examples written in different languages.
+    pub fn with_example(
+        mut self,
+        language: impl Into<Cow<'static, str>>,
+        code: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.examples.push(FunctionExample {
+            language: language.into(),
+            code: code.into(),
+        });
+        self
+    }
+
+    /// Marks the function as deprecated.
+    pub fn with_deprecation(mut self, deprecation: FunctionDeprecation) -> Self {
+        self.deprecation = Some(deprecation);
+        self
+    }
+
     /// Add argument names to the function info.
     ///
     /// If the number of argument names is less than the number of arguments, the remaining arguments will be unnamed.
@@ -115,6 +153,45 @@ impl FunctionInfo {
     }
 }
 
+#[derive(Clone, Reflect, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+/// A usage example of a function.
+pub struct FunctionExample {
+    /// The scripting language the example is written in.
+    pub language: Cow<'static, str>,
+    /// The code of the example.
+    pub code: Cow<'static, str>,
+}
+
+#[derive(Clone, Default, Reflect, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+/// Information about the deprecation of a function.
+pub struct FunctionDeprecation {
+    /// The version the function was deprecated in.
+    pub since: Option<Cow<'static, str>>,
+    /// The name of the function which should be used instead.
+    pub replacement: Option<Cow<'static, str>>,
+}
+
+impl FunctionDeprecation {
+    /// Set the version the function was deprecated in.
+    pub fn with_since(mut self, since: impl Into<Cow<'static, str>>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// Set the name of the function which should be used instead.
+    pub fn with_replacement(mut self, replacement: impl Into<Cow<'static, str>>) -> Self {
+        self.replacement = Some(replacement.into());
+        self
+    }
+
+    /// Describes the deprecation in a human readable way, i.e. `deprecated since 0.15, use `new_name` instead`.
+    pub fn message(&self) -> String {
+        deprecation_message(self.since.as_deref(), self.replacement.as_deref())
+    }
+}
+
 #[derive(Clone, Reflect, DebugWithTypeInfo)]
 #[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
 /// Information about a function argument.
@@ -177,6 +254,10 @@ pub struct FunctionReturnInfo {
     /// The type information of the return value.
     #[reflect(ignore)]
     pub type_info: Option<ThroughTypeInfo>,
+    /// How errors are surfaced to scripts if the function is fallible.
+    ///
+    /// If `None`, the [`ErrorReturnMode`] resource decides.
+    pub error_mode: Option<ErrorReturnMode>,
 }
 
 impl Default for FunctionReturnInfo {
@@ -205,8 +286,19 @@ impl FunctionReturnInfo {
         Self {
             type_id: TypeId::of::<T>(),
             type_info: Some(T::through_type_info()),
+            error_mode: None,
         }
     }
+
+    /// Returns true if the function returns a `Result`, meaning its errors are surfaced according to an [`ErrorReturnMode`].
+    pub fn is_fallible(&self) -> bool {
+        matches!(
+            self.type_info,
+            Some(ThroughTypeInfo::TypedWrapper(
+                TypedWrapperKind::InteropResult(_)
+            ))
+        )
+    }
 }
 
 macro_rules! impl_documentable {
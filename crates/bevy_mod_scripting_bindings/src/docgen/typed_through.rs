@@ -1,11 +1,12 @@
 //! Defines a set of traits which destruture [`bevy_reflect::TypeInfo`] and implement a light weight wrapper around it, to allow types
 //! which normally can't implement [`bevy_reflect::Typed`] to be used in a reflection context.
 
-use std::{any::TypeId, collections::VecDeque, ffi::OsString, path::PathBuf};
+use std::{any::TypeId, borrow::Cow, collections::VecDeque, ffi::OsString, path::PathBuf};
 
 use crate::{
     ReflectReference, VariadicTuple,
     function::{
+        callable::ScriptCallable,
         from::{M, R, Union, V},
         script_function::{DynamicScriptFunction, DynamicScriptFunctionMut, FunctionCallContext},
     },
@@ -83,7 +84,10 @@ pub enum TypedWrapperKind {
 /// A dynamic version of [`TypedThrough`], which can be used to convert a [`TypeInfo`] into a [`ThroughTypeInfo`].
 pub fn into_through_type_info(type_info: &'static TypeInfo) -> ThroughTypeInfo {
     let option = (|| {
-        if let Ok(array) = type_info.as_array() {
+        // byte buffers are lists, but are passed as a primitive
+        if type_info.type_id() == TypeId::of::<Vec<u8>>() {
+            return Some(ThroughTypeInfo::Primitive(ReflectionPrimitiveKind::Bytes));
+        } else if let Ok(array) = type_info.as_array() {
             let len = array.capacity();
             let inner = array.item_info()?;
             return Some(ThroughTypeInfo::TypedWrapper(TypedWrapperKind::Array(
@@ -189,6 +193,8 @@ pub fn as_reflect_primitive(type_info: &'static TypeInfo) -> Option<ReflectionPr
         ReflectionPrimitiveKind::OsString
     } else if type_id == TypeId::of::<PathBuf>() {
         ReflectionPrimitiveKind::PathBuf
+    } else if type_id == TypeId::of::<Vec<u8>>() {
+        ReflectionPrimitiveKind::Bytes
     } else if type_id == TypeId::of::<FunctionCallContext>() {
         ReflectionPrimitiveKind::FunctionCallContext
     } else if type_id == TypeId::of::<DynamicScriptFunction>() {
@@ -246,7 +252,25 @@ impl<T: Typed> TypedThrough for V<T> {
 
 impl<T: TypedThrough> TypedThrough for Vec<T> {
     fn through_type_info() -> ThroughTypeInfo {
-        ThroughTypeInfo::TypedWrapper(TypedWrapperKind::Vec(Box::new(T::through_type_info())))
+        match T::through_type_info() {
+            // `Vec<u8>` is converted into a byte buffer
+            ThroughTypeInfo::Primitive(ReflectionPrimitiveKind::U8) => {
+                ThroughTypeInfo::Primitive(ReflectionPrimitiveKind::Bytes)
+            }
+            inner => ThroughTypeInfo::TypedWrapper(TypedWrapperKind::Vec(Box::new(inner))),
+        }
+    }
+}
+
+impl TypedThrough for Box<[u8]> {
+    fn through_type_info() -> ThroughTypeInfo {
+        ThroughTypeInfo::Primitive(ReflectionPrimitiveKind::Bytes)
+    }
+}
+
+impl TypedThrough for Cow<'static, [u8]> {
+    fn through_type_info() -> ThroughTypeInfo {
+        ThroughTypeInfo::Primitive(ReflectionPrimitiveKind::Bytes)
     }
 }
 
@@ -317,6 +341,7 @@ impl_through_typed!(
     ReflectReference => ReflectReference,
     DynamicScriptFunctionMut => DynamicFunctionMut,
     DynamicScriptFunction => DynamicFunction,
+    ScriptCallable => DynamicFunction,
     ScriptValue => ScriptValue,
     bool => Bool,
     i8 => I8,
@@ -404,6 +429,7 @@ mod test {
             ReflectionPrimitiveKind::ReflectReference,
         );
         assert_type_info_is_primitive::<&'static str>(ReflectionPrimitiveKind::Str);
+        assert_type_info_is_primitive::<Vec<u8>>(ReflectionPrimitiveKind::Bytes);
     }
 
     #[test]
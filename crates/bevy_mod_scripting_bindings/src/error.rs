@@ -163,6 +163,13 @@ pub enum InteropError {
         /// The actual length
         got: usize,
     },
+    /// An integer did not fit into the target integer type
+    IntegerOverflow {
+        /// The integer which overflowed
+        value: Box<String>,
+        /// The type the integer was converted into
+        target: Box<TypeId>,
+    },
     /// Something failed when converting from a reflect
     FailedFromReflect {
         /// The type id of the reflect
@@ -252,6 +259,13 @@ pub enum InteropError {
         /// The reference to the allocation
         reference: Box<ReflectReference>,
     },
+    /// A reference to a value which no longer exists was used, i.e. a component of a despawned entity
+    StaleReference {
+        /// The stale reference
+        reference: Box<ReflectReference>,
+        /// Why the reference is stale
+        reason: Box<String>,
+    },
     /// An unregistered reflect base was used
     UnregisteredReflectBase {
         /// The base that was unregistered
@@ -285,6 +299,11 @@ pub enum InteropError {
     },
     /// The world was missing
     MissingWorld,
+    /// A script function was called after the script context it belongs to was unloaded
+    ScriptContextUnloaded {
+        /// The language of the unloaded context
+        language: Box<Language>,
+    },
     /// An external error occurred
     External(ExternalError),
     /// an error enriched with some contextual information
@@ -350,6 +369,14 @@ impl InteropError {
         Self::LengthMismatch { expected, got }
     }
 
+    /// Creates a new integer overflow error.
+    pub fn integer_overflow(value: impl Display, target: TypeId) -> Self {
+        Self::IntegerOverflow {
+            value: Box::new(value.to_string()),
+            target: Box::new(target),
+        }
+    }
+
     /// Creates a new failed from reflect error.
     pub fn failed_from_reflect(type_id: Option<TypeId>, reason: impl Display) -> Self {
         Self::FailedFromReflect {
@@ -470,6 +497,14 @@ impl InteropError {
         }
     }
 
+    /// Creates a new stale reference error.
+    pub fn stale_reference(reference: ReflectReference, reason: impl Into<String>) -> Self {
+        Self::StaleReference {
+            reference: Box::new(reference),
+            reason: Box::new(reason.into()),
+        }
+    }
+
     /// Creates a new unregistered base error.
     pub fn unregistered_base(base: ReflectBaseType) -> Self {
         Self::UnregisteredReflectBase {
@@ -511,6 +546,13 @@ impl InteropError {
         Self::MissingWorld
     }
 
+    /// Creates a new script context unloaded error.
+    pub fn script_context_unloaded(language: Language) -> Self {
+        Self::ScriptContextUnloaded {
+            language: Box::new(language),
+        }
+    }
+
     /// Creates a new missing function error.
     pub fn missing_function(
         function_name: impl Display,
@@ -555,6 +597,13 @@ impl DisplayWithTypeInfo for InteropError {
             InteropError::LengthMismatch { expected, got } => {
                 write!(f, "Length mismatch: expected {expected}, got {got}")
             }
+            InteropError::IntegerOverflow { value, target } => {
+                write!(
+                    f,
+                    "Integer overflow: {value} does not fit into {}",
+                    WithTypeInfo::new_with_opt_info(target, type_info_provider)
+                )
+            }
             InteropError::FailedFromReflect { type_id, reason } => {
                 write!(
                     f,
@@ -676,6 +725,14 @@ impl DisplayWithTypeInfo for InteropError {
                     WithTypeInfo::new_with_opt_info(reference, type_info_provider)
                 )
             }
+            InteropError::StaleReference { reference, reason } => {
+                write!(
+                    f,
+                    "Stale reference to {}: {}",
+                    WithTypeInfo::new_with_opt_info(&reference.base, type_info_provider),
+                    reason
+                )
+            }
             InteropError::UnregisteredReflectBase { base } => {
                 write!(
                     f,
@@ -720,6 +777,12 @@ impl DisplayWithTypeInfo for InteropError {
             InteropError::MissingWorld => {
                 write!(f, "Missing world")
             }
+            InteropError::ScriptContextUnloaded { language } => {
+                write!(
+                    f,
+                    "Cannot call {language} function, the script context it belongs to was unloaded"
+                )
+            }
             InteropError::External(external_error) => {
                 write!(
                     f,
@@ -9,6 +9,7 @@ use crate::{
 };
 
 use super::{
+    callable::ScriptCallable,
     from::{FromScript, M, R, Union, V},
     into::IntoScript,
     script_function::{DynamicScriptFunction, DynamicScriptFunctionMut, FunctionCallContext},
@@ -103,13 +104,19 @@ impl<T> ArgMeta for Option<T> {
 
 impl<T> ArgMeta for Vec<T> {}
 impl<T> ArgMeta for VecDeque<T> {}
+impl ArgMeta for Box<[u8]> {}
+impl ArgMeta for std::borrow::Cow<'static, [u8]> {}
 
 impl<T, const N: usize> ArgMeta for [T; N] {}
 
 impl<K, V> ArgMeta for HashMap<K, V> {}
 impl<K, V> ArgMeta for std::collections::HashMap<K, V> {}
 
-impl_arg_info!(DynamicScriptFunction, DynamicScriptFunctionMut);
+impl_arg_info!(
+    DynamicScriptFunction,
+    DynamicScriptFunctionMut,
+    ScriptCallable
+);
 
 impl ArgMeta for () {
     fn default_value() -> Option<ScriptValue> {
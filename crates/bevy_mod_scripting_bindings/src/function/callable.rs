@@ -0,0 +1,75 @@
+//! Functions passed into rust from scripts, which can be called back later.
+
+use std::borrow::Cow;
+
+use bevy_mod_scripting_derive::DebugWithTypeInfo;
+use bevy_reflect::Reflect;
+
+use super::{
+    from::FromScript,
+    script_function::{DynamicScriptFunction, DynamicScriptFunctionMut, FunctionCallContext},
+};
+use crate::{ScriptValue, error::InteropError};
+
+/// A function passed into rust as an argument, for example a comparator or a callback.
+///
+/// Accepts script closures as well as rust functions which were previously handed to the script.
+/// Script closures only stay callable while the script context they were created in is loaded, after which calls fail with [`InteropError::ScriptContextUnloaded`].
+#[derive(Clone, Reflect, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+#[reflect(opaque)]
+pub enum ScriptCallable {
+    /// A function without state
+    Function(DynamicScriptFunction),
+    /// A function possibly storing state
+    FunctionMut(DynamicScriptFunctionMut),
+}
+
+#[profiling::all_functions]
+impl ScriptCallable {
+    /// Get the name of the function
+    pub fn name(&self) -> &Cow<'static, str> {
+        match self {
+            ScriptCallable::Function(f) => f.name(),
+            ScriptCallable::FunctionMut(f) => f.name(),
+        }
+    }
+
+    /// Call the function with the given arguments and caller context.
+    ///
+    /// Fails if the function returns an error, or if the script context it belongs to was unloaded.
+    pub fn call<I: IntoIterator<Item = ScriptValue>>(
+        &self,
+        args: I,
+        context: FunctionCallContext,
+    ) -> Result<ScriptValue, InteropError> {
+        match self {
+            ScriptCallable::Function(f) => f.call(args, context),
+            ScriptCallable::FunctionMut(f) => f.call(args, context),
+        }
+    }
+
+    /// Call the function and convert its return value into the given type.
+    pub fn call_as<O, I>(&self, args: I, context: FunctionCallContext) -> Result<O, InteropError>
+    where
+        O: FromScript,
+        for<'w> O::This<'w>: Into<O>,
+        I: IntoIterator<Item = ScriptValue>,
+    {
+        let world = context.world()?;
+        let out = self.call(args, context)?;
+        O::from_script(out, world).map(Into::into)
+    }
+}
+
+impl From<DynamicScriptFunction> for ScriptCallable {
+    fn from(value: DynamicScriptFunction) -> Self {
+        ScriptCallable::Function(value)
+    }
+}
+
+impl From<DynamicScriptFunctionMut> for ScriptCallable {
+    fn from(value: DynamicScriptFunctionMut) -> Self {
+        ScriptCallable::FunctionMut(value)
+    }
+}
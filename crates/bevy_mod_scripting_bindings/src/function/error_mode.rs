@@ -0,0 +1,91 @@
+//! Controls how errors returned from fallible functions are surfaced to scripts.
+
+use std::collections::BTreeMap;
+
+use bevy_ecs::resource::Resource;
+use bevy_mod_scripting_asset::Language;
+use bevy_mod_scripting_derive::DebugWithTypeInfo;
+use bevy_mod_scripting_world::{CachedRegistry, WorldGuard};
+use bevy_reflect::Reflect;
+
+use super::{IntoScript, V};
+use crate::{ScriptValue, VariadicTuple, error::InteropError};
+
+/// Decides how functions returning a `Result` surface errors to scripts.
+///
+/// The mode used for each language is decided by the [`ErrorReturnModes`] resource, individual functions can override it via [`super::FunctionReturnInfo::error_mode`].
+/// By default errors are thrown.
+#[derive(Reflect, Clone, Copy, Default, PartialEq, Eq, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+pub enum ErrorReturnMode {
+    /// Errors are thrown as script errors, which can be caught with `pcall` in Lua or `try`/`catch` in Rhai.
+    #[default]
+    Throw,
+    /// Successful values are returned as is, errors are returned as two values, `nil` and the error message.
+    ///
+    /// Languages without multiple return values receive both values as a list.
+    NilAndMessage,
+    /// Both successful values and errors are returned as a [`ScriptResult`] reference, with `is_ok`, `unwrap` and `err` methods.
+    Reference,
+}
+
+/// The [`ErrorReturnMode`]s used for functions called from scripts, unless overridden per function.
+#[derive(Resource, Clone, Default, PartialEq, Eq, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+pub struct ErrorReturnModes {
+    /// The mode used for languages without their own mode.
+    pub default: ErrorReturnMode,
+    /// The modes set by individual language plugins.
+    pub per_language: BTreeMap<Language, ErrorReturnMode>,
+}
+
+impl ErrorReturnModes {
+    /// Returns the mode used for functions called from the given language.
+    pub fn for_language(&self, language: &Language) -> ErrorReturnMode {
+        self.per_language
+            .get(language)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+impl CachedRegistry for ErrorReturnModes {
+    const SLOT: usize = 6;
+}
+
+/// The outcome of a fallible function, passed to scripts as a reference when the [`ErrorReturnMode::Reference`] mode is active.
+#[derive(Clone, Reflect, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+#[reflect(opaque)]
+pub struct ScriptResult(pub Result<ScriptValue, InteropError>);
+
+impl IntoScript for ScriptResult {
+    fn into_script(self, world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        V(self).into_script(world)
+    }
+}
+
+/// The result of calling a fallible function, converted into a script value according to the given [`ErrorReturnMode`].
+pub struct FallibleReturn {
+    /// The result of the call
+    pub result: Result<ScriptValue, InteropError>,
+    /// The mode to convert the result with
+    pub mode: ErrorReturnMode,
+}
+
+impl IntoScript for FallibleReturn {
+    fn into_script(self, world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        match (self.mode, self.result) {
+            (ErrorReturnMode::Throw, result) => result,
+            (ErrorReturnMode::NilAndMessage, Ok(value)) => Ok(value),
+            (ErrorReturnMode::NilAndMessage, Err(error)) => Ok(ScriptValue::Tuple(VariadicTuple(
+                [
+                    ScriptValue::Unit,
+                    ScriptValue::String(error.to_string().into()),
+                ]
+                .into(),
+            ))),
+            (ErrorReturnMode::Reference, result) => ScriptResult(result).into_script(world),
+        }
+    }
+}
@@ -1,14 +1,21 @@
 //! This module contains the [`FromScript`] trait and its implemenations.
 
-use super::script_function::{DynamicScriptFunction, DynamicScriptFunctionMut};
-use crate::{ReflectReference, ScriptValue, error::InteropError, script_value::VariadicTuple};
+use super::{
+    callable::ScriptCallable,
+    script_function::{DynamicScriptFunction, DynamicScriptFunctionMut},
+};
+use crate::{
+    BigInt, ReflectReference, ScriptValue, error::InteropError, integer_from_script,
+    integer_from_script_float, script_value::VariadicTuple,
+};
 use bevy_mod_scripting_world::WorldAccessRange;
 use bevy_mod_scripting_world::WorldGuard;
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::{FromReflect, Reflect};
 use nonmax::NonMaxU32;
 use std::{
-    any::TypeId,
+    any::{Any, TypeId},
+    borrow::Cow,
     collections::VecDeque,
     ffi::OsString,
     ops::{Deref, DerefMut},
@@ -91,8 +98,36 @@ macro_rules! impl_from_with_downcast {
     };
 }
 
-impl_from_with_downcast!(
-    i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64, usize, isize
+impl_from_with_downcast!(f32, f64);
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            #[profiling::all_functions]
+            impl FromScript for $ty {
+                type This<'w> = Self;
+                #[profiling::function]
+                fn from_script(value: ScriptValue, world: WorldGuard) -> Result<Self, InteropError> {
+                    match value {
+                        ScriptValue::Integer(i) => integer_from_script(i as i128, &world),
+                        ScriptValue::Float(f) => integer_from_script_float(f, &world),
+                        ScriptValue::Reference(r) => r.downcast::<Self>(world.clone()).or_else(|err| {
+                            match r.downcast::<BigInt>(world.clone()) {
+                                Ok(big) => integer_from_script(big.0, &world),
+                                Err(_) => Err(err),
+                            }
+                        }),
+                        ScriptValue::Bool(b) => Ok(b as usize as $ty),
+                        _ => Err(InteropError::value_mismatch(std::any::TypeId::of::<Self>(), value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(
+    i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize, isize
 );
 
 macro_rules! impl_from_stringlike {
@@ -121,13 +156,17 @@ impl FromScript for NonMaxU32 {
 
     fn from_script(
         value: ScriptValue,
-        _world: WorldGuard<'_>,
+        world: WorldGuard<'_>,
     ) -> Result<Self::This<'_>, InteropError>
     where
         Self: Sized,
     {
         match value {
-            ScriptValue::Integer(i) if i != 0 => Ok(unsafe { NonMaxU32::new_unchecked(i as u32) }),
+            ScriptValue::Integer(i) if i != 0 => {
+                NonMaxU32::new(integer_from_script(i as i128, &world)?).ok_or_else(|| {
+                    InteropError::integer_overflow(i, std::any::TypeId::of::<Self>())
+                })
+            }
             ScriptValue::Float(f) if f != 0.0 => Ok(unsafe { NonMaxU32::new_unchecked(f as u32) }),
             // ScriptValue::Reference(r) => r.downcast::<Self>(world),
             _ => Err(InteropError::value_mismatch(
@@ -384,6 +423,45 @@ where
     }
 }
 
+/// Converts a byte buffer into a `Vec<T>`, moving the buffer without copying if `T` is `u8`
+fn vec_from_bytes<T: FromScript + 'static>(
+    bytes: Vec<u8>,
+    world: WorldGuard,
+) -> Result<Vec<T>, InteropError>
+where
+    for<'w> T::This<'w>: Into<T>,
+{
+    let mut bytes = Some(bytes);
+    if let Some(vec) = (&mut bytes as &mut dyn Any).downcast_mut::<Option<Vec<T>>>() {
+        return Ok(vec.take().unwrap_or_default());
+    }
+    bytes
+        .into_iter()
+        .flatten()
+        .map(|byte| {
+            T::from_script(ScriptValue::Integer(byte as i64), world.clone()).map(Into::into)
+        })
+        .collect()
+}
+
+#[profiling::all_functions]
+impl FromScript for Box<[u8]> {
+    type This<'w> = Self;
+    #[profiling::function]
+    fn from_script(value: ScriptValue, world: WorldGuard) -> Result<Self, InteropError> {
+        Vec::<u8>::from_script(value, world).map(Vec::into_boxed_slice)
+    }
+}
+
+#[profiling::all_functions]
+impl FromScript for Cow<'static, [u8]> {
+    type This<'w> = Self;
+    #[profiling::function]
+    fn from_script(value: ScriptValue, world: WorldGuard) -> Result<Self, InteropError> {
+        Vec::<u8>::from_script(value, world).map(Cow::Owned)
+    }
+}
+
 #[profiling::all_functions]
 impl<T: FromScript + 'static> FromScript for Vec<T>
 where
@@ -400,6 +478,10 @@ where
                 }
                 Ok(vec)
             }
+            ScriptValue::Bytes(bytes) => vec_from_bytes(bytes, world),
+            ScriptValue::String(s) if TypeId::of::<T>() == TypeId::of::<u8>() => {
+                vec_from_bytes(s.into_owned().into_bytes(), world)
+            }
             _ => Err(InteropError::value_mismatch(
                 std::any::TypeId::of::<Vec<T>>(),
                 value,
@@ -424,6 +506,7 @@ where
                 }
                 Ok(vec)
             }
+            ScriptValue::Bytes(bytes) => vec_from_bytes(bytes, world).map(VecDeque::from),
             _ => Err(InteropError::value_mismatch(
                 std::any::TypeId::of::<VecDeque<T>>(),
                 value,
@@ -450,6 +533,9 @@ where
                     .map_err(|list: Vec<T>| InteropError::length_mismatch(N, list.len()))?;
                 Ok(converted_list)
             }
+            ScriptValue::Bytes(bytes) if bytes.len() == N => vec_from_bytes::<T>(bytes, world)?
+                .try_into()
+                .map_err(|list: Vec<T>| InteropError::length_mismatch(N, list.len())),
             _ => Err(InteropError::value_mismatch(
                 std::any::TypeId::of::<[T; N]>(),
                 value,
@@ -494,6 +580,25 @@ impl FromScript for DynamicScriptFunction {
     }
 }
 
+#[profiling::all_functions]
+impl FromScript for ScriptCallable {
+    type This<'w> = Self;
+    #[profiling::function]
+    fn from_script(value: ScriptValue, _: WorldGuard<'_>) -> Result<Self::This<'_>, InteropError>
+    where
+        Self: Sized,
+    {
+        match value {
+            ScriptValue::Function(f) => Ok(ScriptCallable::Function(f)),
+            ScriptValue::FunctionMut(f) => Ok(ScriptCallable::FunctionMut(f)),
+            _ => Err(InteropError::value_mismatch(
+                std::any::TypeId::of::<Self>(),
+                value,
+            )),
+        }
+    }
+}
+
 macro_rules! impl_from_script_hashmap {
     ($hashmap_type:path) => {
         #[profiling::all_functions]
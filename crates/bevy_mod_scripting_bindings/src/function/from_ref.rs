@@ -60,6 +60,7 @@ impl FromScriptRef for Box<dyn PartialReflect> {
                 tq : String  => return <String>::from_script(value, world).map(|a| Box::new(a) as _),
                 tr : PathBuf => return <PathBuf>::from_script(value, world).map(|a| Box::new(a) as _),
                 ts : OsString=> return <OsString>::from_script(value, world).map(|a| Box::new(a) as _),
+                tvb: Vec<u8> => return <Vec<u8>>::from_script(value, world).map(|a| Box::new(a) as _),
                 tsv: ScriptValue => return <ScriptValue>::from_script(value, world).map(|a| Box::new(a) as _),
                 tn : ()      => return <()>::from_script(value, world).map(|a| Box::new(a) as _)
             }
@@ -1,7 +1,10 @@
 //! Implementations of the [`IntoScript`] trait for various types.
 
-use super::{DynamicScriptFunction, DynamicScriptFunctionMut, Union, V};
-use crate::{ReflectReference, ScriptValue, VariadicTuple, WorldExtensions, error::InteropError};
+use super::{DynamicScriptFunction, DynamicScriptFunctionMut, ScriptCallable, Union, V};
+use crate::{
+    ReflectReference, ScriptValue, VariadicTuple, WorldExtensions, error::InteropError,
+    integer_into_script,
+};
 use bevy_mod_scripting_world::WorldGuard;
 use bevy_platform::collections::HashMap;
 use bevy_reflect::Reflect;
@@ -19,6 +22,20 @@ pub trait IntoScript {
     {
         self.into_script(world).unwrap_or_else(ScriptValue::Error)
     }
+
+    /// Convert a list of these values into a [`ScriptValue`], by default a [`ScriptValue::List`].
+    ///
+    /// Overridden for `u8`, so byte buffers are moved into a single [`ScriptValue::Bytes`] without copying.
+    fn vec_into_script(values: Vec<Self>, world: WorldGuard) -> Result<ScriptValue, InteropError>
+    where
+        Self: Sized,
+    {
+        let mut list = VecDeque::with_capacity(values.len());
+        for val in values {
+            list.push_back(val.into_script(world.clone())?);
+        }
+        Ok(ScriptValue::List(list))
+    }
 }
 
 impl IntoScript for ScriptValue {
@@ -48,6 +65,16 @@ impl IntoScript for DynamicScriptFunction {
     }
 }
 
+#[profiling::all_functions]
+impl IntoScript for ScriptCallable {
+    fn into_script(self, _world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        Ok(match self {
+            ScriptCallable::Function(f) => ScriptValue::Function(f),
+            ScriptCallable::FunctionMut(f) => ScriptValue::FunctionMut(f),
+        })
+    }
+}
+
 #[profiling::all_functions]
 impl IntoScript for bool {
     fn into_script(self, _world: WorldGuard) -> Result<ScriptValue, InteropError> {
@@ -69,9 +96,36 @@ macro_rules! impl_into_with_downcast {
 
 }
 
-impl_into_with_downcast!(Integer as i64 [i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize, isize]);
+impl_into_with_downcast!(Integer as i64 [i8, i16, i32, i64, u16, u32]);
+
+#[profiling::all_functions]
+impl IntoScript for u8 {
+    fn into_script(self, _world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        Ok(ScriptValue::Integer(self as i64))
+    }
+
+    fn vec_into_script(values: Vec<Self>, _world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        Ok(ScriptValue::Bytes(values))
+    }
+}
+
 impl_into_with_downcast!(Float as f64 [f32, f64]);
 
+macro_rules! impl_into_wide_integer {
+    ($($ty:ty),*) => {
+        $(
+            #[profiling::all_functions]
+            impl IntoScript for $ty {
+                fn into_script(self, world: WorldGuard) -> Result<ScriptValue, InteropError> {
+                    integer_into_script(self, world)
+                }
+            }
+        )*
+    }
+}
+
+impl_into_wide_integer!(i128, u64, u128, usize, isize);
+
 macro_rules! impl_into_stringlike {
     ($id:ident,[ $(($ty:ty => $conversion:expr)),*]) => {
         $(
@@ -137,11 +191,21 @@ impl<T: IntoScript> IntoScript for Option<T> {
 #[profiling::all_functions]
 impl<T: IntoScript> IntoScript for Vec<T> {
     fn into_script(self, world: WorldGuard) -> Result<ScriptValue, InteropError> {
-        let mut values = VecDeque::with_capacity(self.len());
-        for val in self {
-            values.push_back(val.into_script(world.clone())?);
-        }
-        Ok(ScriptValue::List(values))
+        T::vec_into_script(self, world)
+    }
+}
+
+#[profiling::all_functions]
+impl IntoScript for Box<[u8]> {
+    fn into_script(self, _world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        Ok(ScriptValue::Bytes(self.into_vec()))
+    }
+}
+
+#[profiling::all_functions]
+impl IntoScript for Cow<'static, [u8]> {
+    fn into_script(self, _world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        Ok(ScriptValue::Bytes(self.into_owned()))
     }
 }
 
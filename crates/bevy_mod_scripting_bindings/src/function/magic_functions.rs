@@ -1,6 +1,8 @@
 //! All the switchable special functions used by language implementors
-use super::{FromScriptRef, FunctionCallContext, IntoScriptRef};
-use crate::{ReferencePart, ReflectReference, ScriptValue, error::InteropError};
+use super::{
+    FromScriptRef, FunctionCallContext, IntoScriptRef, script_function::into_script_return,
+};
+use crate::{ReferencePart, ReflectReference, ScriptValue, WorldExtensions, error::InteropError};
 use bevy_mod_scripting_derive::DebugWithTypeInfo;
 use bevy_mod_scripting_display::OrFakeId;
 use bevy_reflect::PartialReflect;
@@ -28,16 +30,24 @@ pub struct MagicFunctions {
 
 impl MagicFunctions {
     /// Calls the currently set `get` function with the given arguments.
+    ///
+    /// Errors are surfaced according to the [`super::ErrorReturnMode`] of the caller's language,
+    /// successful lookups always return the value itself so that field access stays transparent.
     pub fn get(
         &self,
         ctxt: FunctionCallContext,
         reference: ReflectReference,
         key: ScriptValue,
     ) -> Result<ScriptValue, InteropError> {
-        (self.get)(ctxt, reference, key)
+        match (self.get)(ctxt.clone(), reference, key) {
+            Ok(value) => Ok(value),
+            Err(error) => into_script_return(None, true, Err(error), &ctxt),
+        }
     }
 
     /// Calls the currently set `set` function with the given arguments.
+    ///
+    /// Assignments produce no value to return errors in, so errors are always thrown.
     pub fn set(
         &self,
         ctxt: FunctionCallContext,
@@ -77,6 +87,9 @@ impl MagicFunctions {
         reference
             .reflect_path
             .set_is_one_indexed(ctxt.convert_to_0_indexed());
+        reference
+            .reflect_path
+            .set_integer_overflow_policy(world.integer_overflow_policy());
         reference.push_path(path);
         ReflectReference::into_script_ref(reference, world)
     }
@@ -108,6 +121,9 @@ impl MagicFunctions {
         reference
             .reflect_path
             .set_is_one_indexed(ctxt.convert_to_0_indexed());
+        reference
+            .reflect_path
+            .set_integer_overflow_policy(world.integer_overflow_policy());
         reference.push_path(path);
         reference.with_reflect_mut(world.clone(), |r| {
             let target_type_id = r
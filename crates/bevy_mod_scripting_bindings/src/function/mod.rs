@@ -1,6 +1,8 @@
 //! Abstractions to do with dynamic script functions
 
 pub mod arg_meta;
+pub mod callable;
+pub mod error_mode;
 pub mod from;
 pub mod from_ref;
 pub mod into;
@@ -11,6 +13,8 @@ pub mod script_function;
 pub mod type_dependencies;
 
 pub use arg_meta::*;
+pub use callable::*;
+pub use error_mode::*;
 pub use from::*;
 pub use from_ref::*;
 pub use into::*;
@@ -117,6 +121,13 @@ mod test {
         test_is_valid_return::<&'static str>();
     }
 
+    #[test]
+    fn byte_buffers_are_valid_args() {
+        test_is_valid_arg_and_return::<Vec<u8>>();
+        test_is_valid_arg_and_return::<Box<[u8]>>();
+        test_is_valid_arg_and_return::<std::borrow::Cow<'static, [u8]>>();
+    }
+
     #[test]
     fn composites_are_valid_args() {
         test_is_valid_arg::<Union<usize, usize>>();
@@ -209,5 +220,6 @@ mod test {
         test_is_valid_arg_and_return::<crate::function::script_function::DynamicScriptFunction>();
         test_is_valid_arg_and_return::<crate::function::script_function::DynamicScriptFunctionMut>(
         );
+        test_is_valid_arg_and_return::<crate::function::callable::ScriptCallable>();
     }
 }
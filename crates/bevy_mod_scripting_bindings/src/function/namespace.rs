@@ -2,8 +2,11 @@
 
 use crate::{
     DummyScriptFunctionRegistry, ScriptFunctionRegistryArc,
-    docgen::info::GetFunctionInfo,
-    function::script_function::{AppScriptFunctionRegistry, ScriptFunction},
+    docgen::info::{FunctionDeprecation, GetFunctionInfo},
+    function::{
+        error_mode::ErrorReturnMode,
+        script_function::{AppScriptFunctionRegistry, ScriptFunction},
+    },
 };
 use ::bevy_reflect::{GetTypeRegistration, Reflect};
 use bevy_ecs::{reflect::AppTypeRegistry, world::World};
@@ -147,6 +150,77 @@ impl<'a, S: IntoNamespace> NamespaceBuilder<'a, S> {
         self.register_inner(name, function, Some(docstring), Some(arg_names))
     }
 
+    /// Overrides how errors returned from an already registered function are surfaced to scripts, see [`ErrorReturnMode`].
+    ///
+    /// Does nothing if the function is not registered in this namespace.
+    pub fn set_error_mode(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        mode: ErrorReturnMode,
+    ) -> &mut Self {
+        // missing functions are simply skipped, there is nothing to override
+        let _ = self
+            .registry
+            .write()
+            .set_error_mode(S::into_namespace(), name, mode);
+        self
+    }
+
+    /// Adds a usage example written in the given scripting language to an already registered function.
+    ///
+    /// Examples end up in generated documentation, see [`crate::docgen::info::FunctionInfo::with_example`].
+    /// Does nothing if the function is not registered in this namespace.
+    pub fn add_example(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        language: impl Into<Cow<'static, str>>,
+        code: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        // missing functions are simply skipped, there is nothing to document
+        let _ = self
+            .registry
+            .write()
+            .add_example(S::into_namespace(), name, language, code);
+        self
+    }
+
+    /// Marks an already registered function as deprecated, see [`crate::docgen::info::FunctionInfo::with_deprecation`].
+    ///
+    /// Does nothing if the function is not registered in this namespace.
+    pub fn set_deprecation(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        deprecation: FunctionDeprecation,
+    ) -> &mut Self {
+        // missing functions are simply skipped, there is nothing to deprecate
+        let _ = self
+            .registry
+            .write()
+            .set_deprecation(S::into_namespace(), name, deprecation);
+        self
+    }
+
+    /// Keeps `old_name` callable as a deprecated alias of the already registered function `new_name`.
+    ///
+    /// Scripts calling the alias get a warning describing the deprecation, once per script.
+    /// The replacement of the deprecation defaults to `new_name` if not set.
+    /// Does nothing if `new_name` is not registered in this namespace.
+    pub fn register_deprecated_alias(
+        &mut self,
+        old_name: impl Into<Cow<'static, str>>,
+        new_name: impl Into<Cow<'static, str>>,
+        deprecation: FunctionDeprecation,
+    ) -> &mut Self {
+        // missing functions are simply skipped, there is nothing to alias
+        let _ = self.registry.write().register_deprecated_alias(
+            S::into_namespace(),
+            old_name,
+            new_name,
+            deprecation,
+        );
+        self
+    }
+
     fn register_inner<'env, N, F, M>(
         &mut self,
         name: N,
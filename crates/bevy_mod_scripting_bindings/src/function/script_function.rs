@@ -1,19 +1,22 @@
 //! Implementations of the [`ScriptFunction`] and [`ScriptFunctionMut`] traits for functions with up to 13 arguments.
 
 use super::MagicFunctions;
+use super::error_mode::{ErrorReturnMode, FallibleReturn};
 use super::{from::FromScript, into::IntoScript, namespace::Namespace};
-use crate::VariadicTuple;
-use crate::docgen::info::{FunctionInfo, GetFunctionInfo};
+use crate::docgen::info::{FunctionDeprecation, FunctionInfo, GetFunctionInfo};
 use crate::function::arg_meta::ArgMeta;
 use crate::{ScriptValue, error::InteropError};
+use crate::{VariadicTuple, WorldExtensions};
 use bevy_ecs::prelude::Resource;
+use bevy_log::warn;
 use bevy_mod_scripting_asset::Language;
 use bevy_mod_scripting_derive::DebugWithTypeInfo;
 use bevy_mod_scripting_display::DisplayWithTypeInfo;
+use bevy_mod_scripting_script::ScriptAttachment;
 use bevy_mod_scripting_world::{ThreadWorldContainer, WorldGuard};
-use bevy_platform::collections::HashMap;
+use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::Reflect;
-use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::hash::Hash;
@@ -236,6 +239,79 @@ impl DynamicScriptFunction {
         self.info = Arc::new(info);
         self
     }
+
+    /// Calls the function as a script would, surfacing errors of fallible functions according to their [`ErrorReturnMode`].
+    ///
+    /// Use this over [`Self::call`] when the return value is handed straight back to a script.
+    pub fn call_from_script<I: IntoIterator<Item = ScriptValue>>(
+        &self,
+        args: I,
+        context: FunctionCallContext,
+    ) -> Result<ScriptValue, InteropError> {
+        let result = self.call(args, context.clone());
+        self.into_script_return(result, &context)
+    }
+
+    /// Converts the result of calling this function according to its [`ErrorReturnMode`], see [`Self::call_from_script`].
+    pub fn into_script_return(
+        &self,
+        result: Result<ScriptValue, InteropError>,
+        context: &FunctionCallContext,
+    ) -> Result<ScriptValue, InteropError> {
+        let return_info = &self.info.return_info;
+        into_script_return(
+            return_info.error_mode,
+            return_info.is_fallible(),
+            result,
+            context,
+        )
+    }
+
+    /// Overrides how errors returned from this function are surfaced to scripts, see [`ErrorReturnMode`].
+    pub fn with_error_mode(mut self, mode: ErrorReturnMode) -> Self {
+        self.info = Arc::new(self.info.as_ref().clone().with_error_mode(mode));
+        self
+    }
+
+    /// Creates a new function which logs the given warning the first time each script calls it, before calling this function.
+    ///
+    /// The scripts which were already warned are tracked in `warnings`, which forgets them when they are unloaded.
+    /// The meta information of the function is kept as is.
+    pub fn with_warning_once_per_script(
+        self,
+        warning: impl Into<Cow<'static, str>>,
+        warnings: ScriptWarnings,
+    ) -> Self {
+        let func = self.func;
+        let warning: Arc<str> = Arc::from(warning.into().as_ref());
+        DynamicScriptFunction {
+            info: self.info,
+            func: Arc::new(move |context, args| {
+                let script = context
+                    .world()
+                    .ok()
+                    .and_then(|world| world.current_attachment().0);
+                if warnings.first_for_script(&warning, script) {
+                    warn!("{warning}, {context}");
+                }
+                func(context, args)
+            }),
+        }
+    }
+
+    /// Creates a new function which calls this function with the given value prepended to its arguments.
+    ///
+    /// The meta information of the function is kept as is.
+    pub fn bind_first_argument(self, argument: ScriptValue) -> Self {
+        let func = self.func;
+        DynamicScriptFunction {
+            info: self.info,
+            func: Arc::new(move |context, mut args| {
+                args.push_front(argument.clone());
+                func(context, args)
+            }),
+        }
+    }
 }
 
 #[profiling::all_functions]
@@ -274,6 +350,56 @@ impl DynamicScriptFunctionMut {
         self.info = Arc::new(info);
         self
     }
+
+    /// Calls the function as a script would, surfacing errors of fallible functions according to their [`ErrorReturnMode`].
+    ///
+    /// Use this over [`Self::call`] when the return value is handed straight back to a script.
+    pub fn call_from_script<I: IntoIterator<Item = ScriptValue>>(
+        &self,
+        args: I,
+        context: FunctionCallContext,
+    ) -> Result<ScriptValue, InteropError> {
+        let result = self.call(args, context.clone());
+        self.into_script_return(result, &context)
+    }
+
+    /// Converts the result of calling this function according to its [`ErrorReturnMode`], see [`Self::call_from_script`].
+    pub fn into_script_return(
+        &self,
+        result: Result<ScriptValue, InteropError>,
+        context: &FunctionCallContext,
+    ) -> Result<ScriptValue, InteropError> {
+        let return_info = &self.info.return_info;
+        into_script_return(
+            return_info.error_mode,
+            return_info.is_fallible(),
+            result,
+            context,
+        )
+    }
+
+    /// Overrides how errors returned from this function are surfaced to scripts, see [`ErrorReturnMode`].
+    pub fn with_error_mode(mut self, mode: ErrorReturnMode) -> Self {
+        self.info = Arc::new(self.info.as_ref().clone().with_error_mode(mode));
+        self
+    }
+}
+
+/// Converts the result of a call according to the given [`ErrorReturnMode`], or the one used by the caller's language if the call is fallible.
+pub(crate) fn into_script_return(
+    error_mode: Option<ErrorReturnMode>,
+    fallible: bool,
+    result: Result<ScriptValue, InteropError>,
+    context: &FunctionCallContext,
+) -> Result<ScriptValue, InteropError> {
+    if error_mode.is_none() && !fallible {
+        return result;
+    }
+    let Ok(world) = context.world() else {
+        return result;
+    };
+    let mode = error_mode.unwrap_or_else(|| world.error_return_mode(&context.language));
+    FallibleReturn { result, mode }.into_script(world)
 }
 
 impl PartialEq for DynamicScriptFunction {
@@ -369,6 +495,36 @@ pub struct FunctionKey {
     pub namespace: Namespace,
 }
 
+/// Tracks which warnings were already logged for which scripts, so that each script is only warned once.
+///
+/// Scripts are forgotten when they are unloaded, so they are warned again after being reloaded.
+#[derive(Clone, Default, Debug)]
+pub struct ScriptWarnings(Arc<Mutex<HashSet<(Arc<str>, Option<ScriptAttachment>)>>>);
+
+impl ScriptWarnings {
+    /// Records the warning for the given script, returning true if it was not recorded yet, i.e. if it should be logged.
+    pub fn first_for_script(&self, warning: &Arc<str>, script: Option<ScriptAttachment>) -> bool {
+        self.0.lock().insert((warning.clone(), script))
+    }
+
+    /// Forgets all warnings logged for the given script.
+    pub fn forget_script(&self, script: &ScriptAttachment) {
+        self.0
+            .lock()
+            .retain(|(_, warned)| warned.as_ref() != Some(script));
+    }
+
+    /// The number of recorded warnings across all scripts.
+    pub fn len(&self) -> usize {
+        self.0.lock().len()
+    }
+
+    /// Returns true if no warnings are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().is_empty()
+    }
+}
+
 #[derive(Default, DebugWithTypeInfo)]
 #[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
 /// A registry of dynamic script functions
@@ -376,6 +532,9 @@ pub struct ScriptFunctionRegistry {
     functions: HashMap<FunctionKey, DynamicScriptFunction>,
     /// A registry of magic functions
     pub magic_functions: MagicFunctions,
+    /// The warnings logged by deprecated aliases, per script
+    #[debug_with_type_info(skip)]
+    pub warnings: ScriptWarnings,
 }
 
 #[profiling::all_functions]
@@ -480,6 +639,127 @@ impl ScriptFunctionRegistry {
         Ok(overloads)
     }
 
+    /// Overrides how errors returned from all overloads of a function are surfaced to scripts, see [`ErrorReturnMode`].
+    pub fn set_error_mode(
+        &mut self,
+        namespace: Namespace,
+        name: impl Into<Cow<'static, str>>,
+        mode: ErrorReturnMode,
+    ) -> Result<(), Cow<'static, str>> {
+        let overloads: Vec<_> = self
+            .iter_overloads(namespace, name)?
+            .map(|overload| overload.info.name.clone())
+            .collect();
+        for name in overloads {
+            if let Some(function) = self.functions.get_mut(&FunctionKey { name, namespace }) {
+                *function = function.clone().with_error_mode(mode);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a usage example to all overloads of a function, see [`FunctionInfo::with_example`].
+    pub fn add_example(
+        &mut self,
+        namespace: Namespace,
+        name: impl Into<Cow<'static, str>>,
+        language: impl Into<Cow<'static, str>>,
+        code: impl Into<Cow<'static, str>>,
+    ) -> Result<(), Cow<'static, str>> {
+        let (language, code) = (language.into(), code.into());
+        let overloads: Vec<_> = self
+            .iter_overloads(namespace, name)?
+            .map(|overload| overload.info.name.clone())
+            .collect();
+        for name in overloads {
+            if let Some(function) = self.functions.get_mut(&FunctionKey { name, namespace }) {
+                let info = function
+                    .info
+                    .as_ref()
+                    .clone()
+                    .with_example(language.clone(), code.clone());
+                *function = function.clone().with_info(info);
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks all overloads of a function as deprecated, see [`FunctionInfo::with_deprecation`].
+    ///
+    /// This only affects the meta information of the function, i.e. generated documentation.
+    pub fn set_deprecation(
+        &mut self,
+        namespace: Namespace,
+        name: impl Into<Cow<'static, str>>,
+        deprecation: FunctionDeprecation,
+    ) -> Result<(), Cow<'static, str>> {
+        let overloads: Vec<_> = self
+            .iter_overloads(namespace, name)?
+            .map(|overload| overload.info.name.clone())
+            .collect();
+        for name in overloads {
+            if let Some(function) = self.functions.get_mut(&FunctionKey { name, namespace }) {
+                let info = function
+                    .info
+                    .as_ref()
+                    .clone()
+                    .with_deprecation(deprecation.clone());
+                *function = function.clone().with_info(info);
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers `old_name` as a deprecated alias of all overloads of the function called `new_name`, overwriting any existing function called `old_name`.
+    ///
+    /// The replacement of the deprecation defaults to `new_name` if not set.
+    /// The alias stays callable, but logs a warning describing the deprecation the first time each script calls it.
+    pub fn register_deprecated_alias(
+        &mut self,
+        namespace: Namespace,
+        old_name: impl Into<Cow<'static, str>>,
+        new_name: impl Into<Cow<'static, str>>,
+        deprecation: FunctionDeprecation,
+    ) -> Result<(), Cow<'static, str>> {
+        let old_name: Cow<'static, str> = old_name.into();
+        let new_name: Cow<'static, str> = new_name.into();
+        let overloads: Vec<_> = self
+            .iter_overloads(namespace, new_name.clone())?
+            .cloned()
+            .collect();
+
+        let deprecation = match deprecation.replacement {
+            Some(_) => deprecation,
+            None => deprecation.with_replacement(new_name),
+        };
+        let warning = format!("Function `{old_name}` is {}", deprecation.message());
+
+        // overloads of the alias mirror the ones of the new function
+        for (i, overload) in overloads.into_iter().enumerate() {
+            let alias_name: Cow<'static, str> = match i {
+                0 => old_name.clone(),
+                i => format!("{old_name}-{i}").into(),
+            };
+            let info = overload
+                .info
+                .as_ref()
+                .clone()
+                .with_name(alias_name.clone())
+                .with_deprecation(deprecation.clone());
+            let alias = overload
+                .with_info(info)
+                .with_warning_once_per_script(warning.clone(), self.warnings.clone());
+            self.functions.insert(
+                FunctionKey {
+                    name: alias_name,
+                    namespace,
+                },
+                alias,
+            );
+        }
+        Ok(())
+    }
+
     /// Register a script function with the given name. If the name already exists,
     /// the new function will be registered as an overload of the function.
     fn register_overload<'env, F, M>(
@@ -762,6 +1042,175 @@ mod test {
         assert_eq!(function.info.namespace, namespace);
     }
 
+    #[test]
+    fn test_error_return_mode_is_chosen_per_language() {
+        let mut registry = ScriptFunctionRegistry::default();
+        registry.register(Namespace::Global, "fail", || {
+            Err::<usize, _>(InteropError::str("failed"))
+        });
+        let function = registry
+            .get_function(Namespace::Global, "fail")
+            .expect("Failed to get function")
+            .clone();
+
+        let mut world = World::default();
+        world.insert_resource(crate::ErrorReturnModes {
+            default: ErrorReturnMode::Throw,
+            per_language: [(Language::Lua, ErrorReturnMode::NilAndMessage)].into(),
+        });
+        let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+        WorldGuard::with_static_guard(&mut world, cache, |world| {
+            ThreadWorldContainer.set_context(ThreadScriptContext { world });
+
+            let lua = function.call_from_script(vec![], FunctionCallContext::new(Language::Lua));
+            let Ok(ScriptValue::Tuple(values)) = lua else {
+                panic!("expected nil and a message, got: {lua:?}");
+            };
+            assert_eq!(values.0[0], ScriptValue::Unit);
+
+            let rhai = function.call_from_script(vec![], FunctionCallContext::new(Language::Rhai));
+            assert!(rhai.is_err(), "expected an error, got: {rhai:?}");
+        });
+    }
+
+    #[test]
+    fn test_deprecated_alias_calls_new_function() {
+        let mut registry = ScriptFunctionRegistry::default();
+        let namespace = Namespace::Global;
+        registry.register(namespace, "add", |a: usize, b: usize| a + b);
+        registry.register(namespace, "add", |a: usize| a);
+
+        registry
+            .register_deprecated_alias(
+                namespace,
+                "sum",
+                "add",
+                FunctionDeprecation::default().with_since("0.2.0"),
+            )
+            .expect("Failed to register alias");
+
+        let aliases = registry
+            .iter_overloads(namespace, "sum")
+            .expect("Failed to get alias")
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(aliases[0].info.name, "sum");
+        assert_eq!(aliases[1].info.name, "sum-1");
+
+        let deprecation = aliases[0]
+            .info
+            .deprecation
+            .as_ref()
+            .expect("Alias is not deprecated");
+        assert_eq!(deprecation.replacement.as_deref(), Some("add"));
+        assert_eq!(deprecation.since.as_deref(), Some("0.2.0"));
+
+        // the new function is not deprecated
+        let function = registry
+            .get_function(namespace, "add")
+            .expect("Failed to get function");
+        assert!(function.info.deprecation.is_none());
+
+        with_local_world(|| {
+            let result = aliases[0]
+                .call(
+                    vec![ScriptValue::from(1), ScriptValue::from(2)],
+                    FunctionCallContext::new(Language::Lua),
+                )
+                .expect("Failed to call alias");
+            assert_eq!(result, ScriptValue::from(3));
+        });
+    }
+
+    #[test]
+    fn test_deprecated_alias_warns_once_per_script() {
+        use bevy_asset::Handle;
+        use bevy_ecs::entity::Entity;
+        use bevy_log::{
+            tracing::{Event, Level, Subscriber, subscriber::with_default},
+            tracing_subscriber::{
+                Layer,
+                layer::{Context, SubscriberExt},
+            },
+        };
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountWarnings(Arc<AtomicUsize>);
+
+        impl<S: Subscriber> Layer<S> for CountWarnings {
+            fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+                if *event.metadata().level() == Level::WARN {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        }
+
+        let mut registry = ScriptFunctionRegistry::default();
+        let namespace = Namespace::Global;
+        registry.register(namespace, "add", |a: usize, b: usize| a + b);
+        registry
+            .register_deprecated_alias(namespace, "sum", "add", FunctionDeprecation::default())
+            .expect("Failed to register alias");
+        let alias = registry
+            .get_function(namespace, "sum")
+            .expect("Failed to get alias")
+            .clone();
+
+        let first_script = ScriptAttachment::StaticScript(Handle::default());
+        let second_script = ScriptAttachment::EntityScript(Entity::PLACEHOLDER, Handle::default());
+
+        let warnings = Arc::new(AtomicUsize::new(0));
+        let subscriber =
+            bevy_log::tracing_subscriber::registry().with(CountWarnings(warnings.clone()));
+        with_default(subscriber, || {
+            let mut world = World::default();
+            let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+            WorldGuard::with_static_guard(&mut world, cache, |world| {
+                ThreadWorldContainer.set_context(ThreadScriptContext {
+                    world: world.clone(),
+                });
+                for script in [&first_script, &first_script, &second_script] {
+                    world.set_current_attachment(script.clone());
+                    alias
+                        .call(
+                            vec![ScriptValue::from(1), ScriptValue::from(2)],
+                            FunctionCallContext::new(Language::Lua),
+                        )
+                        .expect("Failed to call alias");
+                }
+            });
+        });
+        assert_eq!(warnings.load(Ordering::SeqCst), 2);
+
+        // unloading a script forgets it was warned
+        registry.warnings.forget_script(&first_script);
+        assert_eq!(registry.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_add_example_applies_to_all_overloads() {
+        let mut registry = ScriptFunctionRegistry::default();
+        let namespace = Namespace::Global;
+        registry.register(namespace, "test", |a: usize| a);
+        registry.register(namespace, "test", |a: usize, b: usize| a + b);
+
+        registry
+            .add_example(namespace, "test", "lua", "test(1)")
+            .expect("Failed to add example");
+
+        let overloads = registry
+            .iter_overloads(namespace, "test")
+            .expect("Failed to get overloads")
+            .collect::<Vec<_>>();
+        assert_eq!(overloads.len(), 2);
+        for overload in overloads {
+            assert_eq!(overload.info.examples.len(), 1);
+            assert_eq!(overload.info.examples[0].language, "lua");
+            assert_eq!(overload.info.examples[0].code, "test(1)");
+        }
+    }
+
     #[test]
     fn test_optional_argument_not_required() {
         let fn_ = |a: usize, b: Option<usize>| a + b.unwrap_or(0);
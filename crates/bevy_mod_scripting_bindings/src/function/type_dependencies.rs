@@ -1,7 +1,7 @@
 //! This module contains the [`GetTypeDependencies`] trait and its implementations for various types.
 
 use super::{
-    DynamicScriptFunction, DynamicScriptFunctionMut,
+    DynamicScriptFunction, DynamicScriptFunctionMut, ScriptCallable,
     from::{M, R, Union, V},
     script_function::FunctionCallContext,
 };
@@ -10,7 +10,7 @@ use bevy_mod_scripting_derive::impl_get_type_dependencies;
 use bevy_platform::collections::HashMap;
 use bevy_reflect::{FromReflect, GetTypeRegistration, TypeRegistry, Typed};
 use std::collections::{HashMap as StdHashMap, VecDeque};
-use std::{ffi::OsString, hash::Hash, path::PathBuf};
+use std::{borrow::Cow, ffi::OsString, hash::Hash, path::PathBuf};
 
 macro_rules! impl_get_type_dependencies_primitives {
     ($($ty:ty),*) => {
@@ -43,6 +43,7 @@ impl_get_type_dependencies_primitives!(
     ScriptValue,
     DynamicScriptFunction,
     DynamicScriptFunctionMut,
+    ScriptCallable,
     InteropError,
     String,
     PathBuf,
@@ -57,6 +58,20 @@ impl GetTypeDependencies for () {
     }
 }
 
+impl GetTypeDependencies for Box<[u8]> {
+    type Underlying = Vec<u8>;
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<Vec<u8>>();
+    }
+}
+
+impl GetTypeDependencies for Cow<'static, [u8]> {
+    type Underlying = Vec<u8>;
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<Vec<u8>>();
+    }
+}
+
 impl GetTypeDependencies for &'static str {
     type Underlying = &'static str;
     fn register_type_dependencies(registry: &mut TypeRegistry) {
@@ -4,7 +4,7 @@ use ::{
     bevy_app::Plugin,
     bevy_asset::Handle,
     bevy_ecs::{entity::Entity, reflect::AppTypeRegistry, world::World},
-    bevy_reflect::TypeRegistration,
+    bevy_reflect::{TypeRegistration, Typed},
 };
 use bevy_app::App;
 use bevy_log::{warn, warn_once};
@@ -12,10 +12,10 @@ use bevy_mod_scripting_asset::ScriptAsset;
 use bevy_mod_scripting_derive::script_globals;
 use bevy_mod_scripting_world::WorldGuard;
 use bevy_platform::collections::HashMap;
-use std::{cell::RefCell, sync::Arc};
+use std::{any::TypeId, cell::RefCell, sync::Arc};
 
 use crate::{
-    ScriptComponentRegistration, ScriptResourceRegistration, ScriptTypeRegistration,
+    Json, Ron, ScriptComponentRegistration, ScriptResourceRegistration, ScriptTypeRegistration,
     WorldExtensions,
     function::from::{Union, V},
 };
@@ -118,6 +118,35 @@ fn register_static_core_globals(world: &mut World, filter: fn(&TypeRegistration)
         warn!("existing `entity` global was replaced by the core `entity` dummy type.")
     }
 
+    for (name, type_id, type_info, documentation) in [
+        (
+            "json",
+            TypeId::of::<Json>(),
+            Json::type_info(),
+            "Encodes script values into JSON strings and decodes them back via `json.encode` and `json.decode`.",
+        ),
+        (
+            "ron",
+            TypeId::of::<Ron>(),
+            Ron::type_info(),
+            "Encodes script values into RON strings and decodes them back via `ron.encode` and `ron.decode`.",
+        ),
+    ] {
+        if global_registry
+            .register_static_documented_dynamic(
+                type_id,
+                into_through_type_info(type_info),
+                name.into(),
+                documentation.into(),
+            )
+            .is_some()
+        {
+            warn!(
+                "existing `{name}` global was replaced by the core `{name}` serialization global."
+            )
+        }
+    }
+
     if global_registry.register_dummy_typed::<V<Handle<ScriptAsset>>>("script_asset", "the asset handle for this script. If the asset is ever unloaded, the handle will be less useful.").is_some() {
         warn!("existing `script_asset` global was replaced by the core `script_asset` dummy type.")
     };
@@ -205,8 +234,10 @@ mod test {
             .unwrap()
             .read();
 
-        // check that the type is not registered
-        assert!(globals.len() == 1);
+        // check that the type is not registered, only the core globals
+        assert!(globals.len() == 3);
         assert!(globals.get("types").is_some());
+        assert!(globals.get("json").is_some());
+        assert!(globals.get("ron").is_some());
     }
 }
@@ -13,6 +13,7 @@ pub mod reflection_extensions;
 pub mod schedule;
 pub mod script_component;
 pub mod script_value;
+pub mod serialization;
 pub mod type_data;
 pub mod world_extensions;
 
@@ -31,5 +32,6 @@ pub use reflection_extensions::*;
 pub use schedule::*;
 pub use script_component::*;
 pub use script_value::*;
+pub use serialization::*;
 pub use type_data::*;
 pub use world_extensions::*;
@@ -10,7 +10,7 @@ use bevy_reflect::{
     PartialReflect, ReflectMut, ReflectRef, TypeInfo, TypeRegistry, enums::VariantType,
 };
 
-use crate::{ScriptValue, convert};
+use crate::{IntegerOverflowPolicy, ScriptValue, convert};
 
 /// A key referencing into a `Reflect` supporting trait object.
 #[derive(DebugWithTypeInfo)]
@@ -143,6 +143,7 @@ impl ReferencePart {
         elem: &'a dyn PartialReflect,
         _type_registry: &TypeRegistry,
         one_indexed: bool,
+        policy: IntegerOverflowPolicy,
     ) -> Result<Option<&'a dyn PartialReflect>, ()> {
         Ok(match elem.reflect_ref() {
             ReflectRef::Struct(x) => x.field(self.expect_string()?),
@@ -158,7 +159,7 @@ impl ReferencePart {
             ReflectRef::Map(x) => {
                 let id = x.get_represented_map_info().ok_or(())?.key_ty().id();
                 self.with_any(one_indexed, |key| {
-                    let coerced = convert(key, id).ok_or(())?;
+                    let coerced = convert(key, id, policy).ok_or(())?;
                     Ok(x.get(coerced.as_ref()))
                 })?
             }
@@ -168,7 +169,7 @@ impl ReferencePart {
                     _ => unreachable!("impossible"),
                 };
                 self.with_any(one_indexed, |key| {
-                    let coerced = convert(key, id).ok_or(())?;
+                    let coerced = convert(key, id, policy).ok_or(())?;
                     Ok(x.get(coerced.as_ref()))
                 })?
             }
@@ -182,6 +183,7 @@ impl ReferencePart {
         elem: &'a mut dyn PartialReflect,
         _type_registry: &TypeRegistry,
         one_indexed: bool,
+        policy: IntegerOverflowPolicy,
     ) -> Result<Option<&'a mut dyn PartialReflect>, ()> {
         Ok(match elem.reflect_mut() {
             ReflectMut::Struct(x) => x.field_mut(self.expect_string()?),
@@ -197,7 +199,7 @@ impl ReferencePart {
             ReflectMut::Map(x) => {
                 let id = x.get_represented_map_info().ok_or(())?.key_ty().id();
                 self.with_any(one_indexed, |key| {
-                    let coerced = convert(key, id).ok_or(())?;
+                    let coerced = convert(key, id, policy).ok_or(())?;
                     Ok(x.get_mut(coerced.as_ref()))
                 })?
             }
@@ -212,6 +214,8 @@ impl ReferencePart {
 #[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
 pub struct ReferencePath {
     one_indexed: bool,
+    #[debug_with_type_info(skip)]
+    integer_overflow_policy: IntegerOverflowPolicy,
     path: Vec<ReferencePart>,
 }
 
@@ -221,6 +225,11 @@ impl ReferencePath {
         self.one_indexed = is_one_indexed;
     }
 
+    /// Sets the policy applied to integer map and set keys which do not fit into the key type
+    pub fn set_integer_overflow_policy(&mut self, policy: IntegerOverflowPolicy) {
+        self.integer_overflow_policy = policy;
+    }
+
     /// Traverses the reference path from the given root object.
     pub fn reflect_element<'a>(
         &self,
@@ -229,7 +238,12 @@ impl ReferencePath {
     ) -> Result<Option<&'a dyn PartialReflect>, ReferencePathError> {
         let mut next: &'a dyn PartialReflect = val;
         for i in &self.path {
-            next = match i.reflect_element(next, type_registry, self.one_indexed) {
+            next = match i.reflect_element(
+                next,
+                type_registry,
+                self.one_indexed,
+                self.integer_overflow_policy,
+            ) {
                 Ok(None) => return Ok(None),
                 Ok(Some(v)) => v,
                 Err(_) => {
@@ -252,7 +266,12 @@ impl ReferencePath {
         let mut next: &'a mut dyn PartialReflect = val;
         for i in &self.path {
             let type_info_current = next.get_represented_type_info();
-            next = match i.reflect_element_mut(next, type_registry, self.one_indexed) {
+            next = match i.reflect_element_mut(
+                next,
+                type_registry,
+                self.one_indexed,
+                self.integer_overflow_policy,
+            ) {
                 Ok(None) => return Ok(None),
                 Ok(Some(v)) => v,
                 Err(_) => {
@@ -5,8 +5,8 @@
 //! Scripting languages only really support `Clone` objects so if we want to support references,
 //! we need wrapper types which have owned and ref variants.
 use crate::{
-    ReferencePart, ReferencePath, ReflectAllocationId, ReflectAllocator, WorldExtensions,
-    error::InteropError, reflection_extensions::PartialReflectExt,
+    ReferencePart, ReferencePath, ReflectAllocationId, ReflectAllocator, WeakReflectAllocationId,
+    WorldExtensions, error::InteropError, reflection_extensions::PartialReflectExt,
 };
 use bevy_asset::{ReflectAsset, UntypedHandle};
 use bevy_ecs::{component::Component, ptr::Ptr, resource::Resource};
@@ -104,6 +104,47 @@ pub enum TypeIdSource {
 #[profiling::all_functions]
 
 impl ReflectReference {
+    /// Returns true if the value this reference points to still exists and can be reached via its path.
+    ///
+    /// Garbage collected allocations, components of despawned entities, removed resources and unloaded assets are all invalid.
+    pub fn is_valid(&self, world: WorldGuard) -> bool {
+        self.with_reflect(world, |_| ()).is_ok()
+    }
+
+    /// Creates a [`WeakReflectReference`] to the same value, which does not keep allocated values alive.
+    pub fn downgrade(&self) -> WeakReflectReference {
+        WeakReflectReference {
+            type_id: self.base.type_id,
+            base: match &self.base.base_id {
+                ReflectBase::Owned(id) => WeakReflectBase::Owned(id.downgrade()),
+                base => WeakReflectBase::Unowned(base.clone()),
+            },
+            reflect_path: self.reflect_path.clone(),
+        }
+    }
+
+    /// Fails with a [`InteropError::StaleReference`] if this references a component of a despawned entity, or a component which was removed.
+    ///
+    /// Entities carry their generation, so a stale reference never resolves to a new entity reusing the same index.
+    fn check_not_stale(&self, world: &WorldGuard) -> Result<(), InteropError> {
+        if let ReflectBase::Component(entity, component_id) = &self.base.base_id {
+            let cell = world.as_unsafe_world_cell()?;
+            let Ok(entity_cell) = cell.get_entity(*entity) else {
+                return Err(InteropError::stale_reference(
+                    self.clone(),
+                    format!("entity {entity} was despawned"),
+                ));
+            };
+            if !entity_cell.contains_id(*component_id) {
+                return Err(InteropError::stale_reference(
+                    self.clone(),
+                    format!("the component was removed from entity {entity}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// If this points to a variant of an enum, returns the name of the variant.
     pub fn variant_name(&self, world: WorldGuard) -> Result<Option<String>, InteropError> {
         self.with_reflect(world, |s| {
@@ -119,6 +160,24 @@ impl ReflectReference {
         ReflectRefIter::new_indexed(self)
     }
 
+    /// Creates a new infinite iterator which can also iterate over maps and sets.
+    ///
+    /// For maps and sets the keys are copied up front, and the iterator returns references to the values under each key in the original iteration order,
+    /// meaning ordered maps are iterated in order. Other types are iterated by index as with [`Self::into_iter_infinite`].
+    pub fn into_iter_infinite_keyed(
+        self,
+        world: WorldGuard,
+    ) -> Result<ReflectRefIter, InteropError> {
+        let keys = self.with_reflect(world, |r| r.container_keys())?;
+        Ok(match keys {
+            Some(keys) => ReflectRefIter::new_keyed(
+                self,
+                keys.into_iter().map(ReferencePart::MapAccess).collect(),
+            ),
+            None => ReflectRefIter::new_indexed(self),
+        })
+    }
+
     /// If this is a reference to something with a length accessible via reflection, returns that length.
     pub fn len(&self, world: WorldGuard) -> Result<Option<usize>, InteropError> {
         self.with_reflect(world, |r| match r.reflect_ref() {
@@ -504,6 +563,8 @@ impl ReflectReference {
             return self.walk_path(asset.as_partial_reflect(), &type_registry);
         }
 
+        self.check_not_stale(&world)?;
+
         let type_registry = world.type_registry();
         let type_registry = type_registry.read();
 
@@ -581,6 +642,8 @@ impl ReflectReference {
             return self.walk_path_mut(asset.as_partial_reflect_mut(), &type_registry);
         };
 
+        self.check_not_stale(&world)?;
+
         let type_registry = world.type_registry();
         let type_registry = type_registry.read();
 
@@ -886,6 +949,50 @@ impl ReflectBase {
     }
 }
 
+/// A version of [`ReflectReference`] which does not keep allocated values alive, created via [`ReflectReference::downgrade`].
+///
+/// References to components, resources and assets do not own the value they point to and are stored as is.
+#[derive(Clone, PartialEq, Reflect, DebugWithTypeInfo)]
+#[reflect(opaque)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+pub struct WeakReflectReference {
+    type_id: TypeId,
+    base: WeakReflectBase,
+    reflect_path: ReferencePath,
+}
+
+#[derive(Clone, PartialEq, DebugWithTypeInfo)]
+#[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
+enum WeakReflectBase {
+    Unowned(ReflectBase),
+    Owned(WeakReflectAllocationId),
+}
+
+#[profiling::all_functions]
+impl WeakReflectReference {
+    /// Returns a strong reference to the value, unless it was an allocation which was garbage collected since.
+    ///
+    /// The returned reference might still be invalid, see [`ReflectReference::is_valid`].
+    pub fn upgrade(&self) -> Option<ReflectReference> {
+        let base_id = match &self.base {
+            WeakReflectBase::Unowned(base) => base.clone(),
+            WeakReflectBase::Owned(id) => ReflectBase::Owned(id.upgrade()?),
+        };
+        Some(ReflectReference {
+            base: ReflectBaseType {
+                type_id: self.type_id,
+                base_id,
+            },
+            reflect_path: self.reflect_path.clone(),
+        })
+    }
+
+    /// Returns true if the reference can be upgraded, and the upgraded reference is valid.
+    pub fn is_valid(&self, world: WorldGuard) -> bool {
+        self.upgrade().is_some_and(|r| r.is_valid(world))
+    }
+}
+
 /// An iterator over a reflect reference that will keep returning the next element forever.
 pub trait ReflectionPathExt {
     /// Assumes the accesses are 1 indexed and converts them to 0 indexed
@@ -924,8 +1031,9 @@ impl ReflectionPathExt for ParsedPath {
 #[debug_with_type_info(bms_display_path = "bevy_mod_scripting_display")]
 pub struct ReflectRefIter {
     pub(crate) base: ReflectReference,
-    // TODO: support maps etc
     pub(crate) index: IterationKey,
+    /// If present, the keys to visit in order instead of indexing into the base
+    pub(crate) keys: Option<Vec<ReferencePart>>,
 }
 
 #[derive(Clone, PartialEq, Eq, DebugWithTypeInfo)]
@@ -943,6 +1051,24 @@ impl ReflectRefIter {
         Self {
             base,
             index: IterationKey::Index(0),
+            keys: None,
+        }
+    }
+
+    /// Creates a new iterator that will visit the given keys in order, after which it keeps returning invalid references forever.
+    pub fn new_keyed(base: ReflectReference, keys: Vec<ReferencePart>) -> Self {
+        Self {
+            base,
+            index: IterationKey::Index(0),
+            keys: Some(keys),
+        }
+    }
+
+    /// Returns the path element used to access the element at the given position
+    fn part_at(&self, index: usize) -> ReferencePart {
+        match self.keys.as_ref().and_then(|keys| keys.get(index)) {
+            Some(key) => key.clone(),
+            None => ReferencePart::IntegerAccess(index as i64, false),
         }
     }
 
@@ -954,12 +1080,11 @@ impl ReflectRefIter {
     /// Returns the next element in the iterator, it does not have a halting condition
     pub fn next_ref(&mut self) -> (ReflectReference, IterationKey) {
         let index = self.index();
-        let next = match &mut self.index {
+        let next = match index {
             IterationKey::Index(i) => {
                 let mut next = self.base.clone();
-                let parsed_path = ReferencePart::IntegerAccess(*i as i64, false);
-                next.push_path(parsed_path);
-                *i += 1;
+                next.push_path(self.part_at(i));
+                self.index = IterationKey::Index(i + 1);
                 next
             }
         };
@@ -972,19 +1097,8 @@ impl Iterator for ReflectRefIter {
     type Item = Result<ReflectReference, InteropError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let result: Result<_, _> = {
-            match &mut self.index {
-                IterationKey::Index(i) => {
-                    let mut next = self.base.clone();
-                    let parsed_path = ReferencePart::IntegerAccess(*i as i64, false);
-                    next.push_path(parsed_path);
-                    *i += 1;
-                    Ok(next)
-                }
-            }
-        };
-
-        Some(result)
+        let (next, _) = self.next_ref();
+        Some(Ok(next))
     }
 }
 
@@ -1281,4 +1395,51 @@ mod test {
             TypeId::of::<String>()
         );
     }
+
+    #[test]
+    fn test_component_ref_to_despawned_entity_is_stale() {
+        let mut world = setup_world();
+        let entity = world.spawn(TestComponent(vec!["hello".to_owned()])).id();
+
+        let component_ref = {
+            let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+            let world_guard = WorldGuard::new_exclusive(&mut world, cache);
+            let component_ref =
+                ReflectReference::new_component_ref::<TestComponent>(entity, world_guard.clone())
+                    .unwrap();
+            assert!(component_ref.is_valid(world_guard));
+            component_ref
+        };
+
+        world.despawn(entity);
+
+        let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+        let world_guard = WorldGuard::new_exclusive(&mut world, cache);
+        assert!(!component_ref.is_valid(world_guard.clone()));
+        let err = component_ref.with_reflect(world_guard, |_| ()).unwrap_err();
+        assert!(
+            matches!(err, InteropError::StaleReference { .. }),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_weak_allocation_ref_does_not_keep_value_alive() {
+        let mut world = setup_world();
+        let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+        let world_guard = WorldGuard::new_exclusive(&mut world, cache);
+        let allocator = world_guard.allocator();
+
+        let allocation_ref =
+            ReflectReference::new_allocated(TestComponent(vec![]), &mut allocator.write());
+        let weak_ref = allocation_ref.downgrade();
+        assert_eq!(weak_ref.upgrade(), Some(allocation_ref.clone()));
+        assert!(weak_ref.is_valid(world_guard.clone()));
+
+        drop(allocation_ref);
+        allocator.write().clean_garbage_allocations();
+
+        assert!(weak_ref.upgrade().is_none());
+        assert!(!weak_ref.is_valid(world_guard.clone()));
+    }
 }
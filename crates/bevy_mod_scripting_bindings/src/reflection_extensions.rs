@@ -100,6 +100,40 @@ pub trait PartialReflectExt {
         reflect: &dyn PartialReflect,
         world: WorldGuard,
     ) -> Result<Box<dyn Reflect>, InteropError>;
+
+    /// Checks if the given key is present in a map, or the given value is present in a set.
+    fn try_contains(&self, key: &dyn PartialReflect) -> Result<bool, InteropError>;
+
+    /// If the type is a map or set, returns owned copies of its keys or values in iteration order.
+    ///
+    /// Ordered containers such as `BTreeMap` will return their keys in order.
+    fn container_keys(&self) -> Option<Vec<Box<dyn PartialReflect>>>;
+
+    /// If both this type and the other type are sets, modifies this set according to the given set operation.
+    fn try_set_operation(
+        &mut self,
+        other: &dyn PartialReflect,
+        operation: SetOperation,
+    ) -> Result<(), InteropError>;
+}
+
+/// An operation combining two sets, see [`PartialReflectExt::try_set_operation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetOperation {
+    /// Keep the elements present in either set
+    Union,
+    /// Keep the elements present in both sets
+    Intersection,
+    /// Keep the elements present in the left set but not in the right set
+    Difference,
+}
+
+/// Clones a reflected value, preferring concrete values and falling back to dynamic ones.
+fn clone_boxed(value: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    match value.reflect_clone() {
+        Ok(cloned) => cloned.into_partial_reflect(),
+        Err(_) => value.to_dynamic(),
+    }
 }
 
 impl<T: PartialReflect + ?Sized> PartialReflectExt for T {
@@ -369,6 +403,7 @@ impl<T: PartialReflect + ?Sized> PartialReflectExt for T {
         let key: TypeId = match self.get_represented_type_info()? {
             TypeInfo::Map(map_info) => map_info.key_ty().id(),
             TypeInfo::List(_) | TypeInfo::Array(_) => TypeId::of::<usize>(),
+            TypeInfo::Set(set_info) => set_info.value_ty().id(),
             _ => return None,
         };
         Some(key)
@@ -427,6 +462,58 @@ impl<T: PartialReflect + ?Sized> PartialReflectExt for T {
         let mut allocator = allocator.write();
         ReflectReference::new_allocated_boxed(boxed, &mut allocator)
     }
+
+    fn try_contains(&self, key: &dyn PartialReflect) -> Result<bool, InteropError> {
+        match self.reflect_ref() {
+            ReflectRef::Map(m) => Ok(m.get(key).is_some()),
+            ReflectRef::Set(s) => Ok(s.contains(key)),
+            _ => Err(InteropError::unsupported_operation(
+                self.get_represented_type_info().map(|ti| ti.type_id()),
+                None,
+                "contains".to_owned(),
+            )),
+        }
+    }
+
+    fn container_keys(&self) -> Option<Vec<Box<dyn PartialReflect>>> {
+        match self.reflect_ref() {
+            ReflectRef::Map(m) => Some(m.iter().map(|(k, _)| clone_boxed(k)).collect()),
+            ReflectRef::Set(s) => Some(s.iter().map(clone_boxed).collect()),
+            _ => None,
+        }
+    }
+
+    fn try_set_operation(
+        &mut self,
+        other: &dyn PartialReflect,
+        operation: SetOperation,
+    ) -> Result<(), InteropError> {
+        match (self.reflect_mut(), other.reflect_ref()) {
+            (ReflectMut::Set(l), ReflectRef::Set(r)) => {
+                match operation {
+                    SetOperation::Union => {
+                        for value in r.iter() {
+                            if !l.contains(value) {
+                                l.insert_boxed(clone_boxed(value));
+                            }
+                        }
+                    }
+                    SetOperation::Intersection => l.retain(&mut |value| r.contains(value)),
+                    SetOperation::Difference => l.retain(&mut |value| !r.contains(value)),
+                }
+                Ok(())
+            }
+            (ReflectMut::Set(_), _) => Err(InteropError::string_type_mismatch(
+                "Set<T>".to_owned(),
+                other.get_represented_type_info().map(|ti| ti.type_id()),
+            )),
+            (_, _) => Err(InteropError::unsupported_operation(
+                self.get_represented_type_info().map(|ti| ti.type_id()),
+                None,
+                format!("{operation:?}"),
+            )),
+        }
+    }
 }
 
 /// Extension trait for TypeInfos providing additional functionality for working with type information.
@@ -662,6 +749,64 @@ mod test {
         assert!(set.contains(&4));
     }
 
+    #[test]
+    fn test_try_contains() {
+        let set = std::collections::HashSet::<i32>::from_iter([1, 2]);
+        assert!(set.try_contains(&1).unwrap());
+        assert!(!set.try_contains(&3).unwrap());
+
+        let map = std::collections::BTreeMap::<i32, bool>::from_iter([(1, true)]);
+        assert!(map.try_contains(&1).unwrap());
+        assert!(!map.try_contains(&2).unwrap());
+
+        assert!(vec![1].try_contains(&1).is_err());
+    }
+
+    #[test]
+    fn test_container_keys_preserve_order() {
+        let map =
+            std::collections::BTreeMap::<i32, bool>::from_iter([(3, true), (1, false), (2, true)]);
+        let keys = map
+            .container_keys()
+            .unwrap()
+            .into_iter()
+            .map(|k| *k.try_downcast_ref::<i32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3], keys);
+
+        assert!(vec![1].container_keys().is_none());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = std::collections::HashSet::<i32>::from_iter([1, 2, 3]);
+        let right = std::collections::HashSet::<i32>::from_iter([2, 3, 4]);
+
+        let mut union = left.clone();
+        union
+            .try_set_operation(&right, SetOperation::Union)
+            .unwrap();
+        assert_eq!(std::collections::HashSet::from_iter([1, 2, 3, 4]), union);
+
+        let mut intersection = left.clone();
+        intersection
+            .try_set_operation(&right, SetOperation::Intersection)
+            .unwrap();
+        assert_eq!(std::collections::HashSet::from_iter([2, 3]), intersection);
+
+        let mut difference = left.clone();
+        difference
+            .try_set_operation(&right, SetOperation::Difference)
+            .unwrap();
+        assert_eq!(std::collections::HashSet::from_iter([1]), difference);
+
+        assert!(
+            left.clone()
+                .try_set_operation(&vec![1], SetOperation::Union)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_try_insert_dynamic_map_into_map_of_maps() {
         let mut map = HashMap::<i32, HashMap<i32, i32>>::default();
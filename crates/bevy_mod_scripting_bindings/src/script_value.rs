@@ -30,6 +30,8 @@ pub enum ScriptValue {
     Float(f64),
     /// Represents a string value.
     String(Cow<'static, str>),
+    /// Represents a buffer of bytes, passed as a single value rather than a list of integers.
+    Bytes(Vec<u8>),
     /// Represents a list of other things passed by value
     List(VecDeque<ScriptValue>),
     /// Represents a tuple of values, which is best intepreted as multiple non-homogenous values.
@@ -65,6 +67,7 @@ impl DisplayWithTypeInfo for ScriptValue {
             ScriptValue::Integer(v) => write!(f, "{v}"),
             ScriptValue::Float(v) => write!(f, "{v}"),
             ScriptValue::String(v) => write!(f, "{v}"),
+            ScriptValue::Bytes(v) => write!(f, "b\"{}\"", v.escape_ascii()),
             ScriptValue::Tuple(VariadicTuple(v)) => {
                 f.write_str("(")?;
                 let mut first = true;
@@ -121,6 +124,7 @@ impl PartialEq for ScriptValue {
             (Self::Integer(l0), Self::Integer(r0)) => l0 == r0,
             (Self::Float(l0), Self::Float(r0)) => l0 == r0,
             (Self::String(l0), Self::String(r0)) => l0 == r0,
+            (Self::Bytes(l0), Self::Bytes(r0)) => l0 == r0,
             (Self::List(l0), Self::List(r0)) => l0 == r0,
             (Self::Map(l0), Self::Map(r0)) => l0 == r0,
             (Self::Reference(l0), Self::Reference(r0)) => l0 == r0,
@@ -140,6 +144,14 @@ impl ScriptValue {
         }
     }
 
+    /// Returns the contained bytes if this is a bytes variant otherwise returns the original value.
+    pub fn as_bytes(self) -> Result<Vec<u8>, Self> {
+        match self {
+            ScriptValue::Bytes(b) => Ok(b),
+            other => Err(other),
+        }
+    }
+
     /// Returns the variant of the value as a string.
     pub fn type_name(&self) -> String {
         match self {
@@ -148,6 +160,7 @@ impl ScriptValue {
             ScriptValue::Integer(_) => "Integer".to_owned(),
             ScriptValue::Float(_) => "Float".to_owned(),
             ScriptValue::String(_) => "String".to_owned(),
+            ScriptValue::Bytes(_) => "Bytes".to_owned(),
             ScriptValue::List(_) => "List".to_owned(),
             ScriptValue::Tuple(_) => "Tuple".to_owned(),
             ScriptValue::Reference(_) => "Reference".to_owned(),
@@ -208,6 +221,13 @@ impl From<Cow<'static, str>> for ScriptValue {
     }
 }
 
+#[profiling::all_functions]
+impl From<Vec<u8>> for ScriptValue {
+    fn from(value: Vec<u8>) -> Self {
+        ScriptValue::Bytes(value)
+    }
+}
+
 #[profiling::all_functions]
 impl From<VecDeque<ScriptValue>> for ScriptValue {
     fn from(value: VecDeque<ScriptValue>) -> Self {
@@ -0,0 +1,515 @@
+//! Serialization of [`ScriptValue`]s into text formats such as JSON and RON.
+//!
+//! Plain values are serialized through serde directly, while [`ReflectReference`]s are serialized through bevy's reflection serializer,
+//! producing a single entry map from the type path of the referenced value to the value itself, i.e. `{ "my_crate::Health": { "hp": 10 } }`.
+//!
+//! When deserializing, single entry maps whose key is the path of a type registered in the [`bevy_ecs::reflect::AppTypeRegistry`]
+//! are reconstructed into references to values of that type, all other values are deserialized into plain script values.
+//!
+//! Byte strings are tagged explicitly as a single entry map from [`BYTES_KEY`] to the list of bytes, i.e. `{ "$bytes": [1, 2, 3] }`,
+//! so that they survive formats without a native byte string representation.
+
+use std::fmt;
+
+use bevy_mod_scripting_world::WorldGuard;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{
+    PartialReflect, Reflect, ReflectFromReflect, TypeRegistry,
+    serde::{ReflectSerializer, TypedReflectDeserializer},
+};
+use serde::{
+    Serialize, Serializer,
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{self, SerializeMap, SerializeSeq},
+};
+
+use crate::{InteropError, ReflectAllocator, ReflectReference, ScriptValue, WorldExtensions};
+
+/// The key of the single entry map byte strings are serialized as.
+pub const BYTES_KEY: &str = "$bytes";
+
+/// The JSON serialization format, exposed to scripts as the `json` global.
+#[derive(Reflect, Clone, Copy, Debug, Default)]
+pub struct Json;
+
+impl Json {
+    /// Serializes a script value into a JSON string.
+    pub fn encode(
+        value: &ScriptValue,
+        pretty: bool,
+        world: WorldGuard,
+    ) -> Result<String, InteropError> {
+        let registry = world.type_registry().clone();
+        let registry = registry.read();
+        let serializable = SerializableScriptValue::new(value, &registry, world);
+        if pretty {
+            serde_json::to_string_pretty(&serializable)
+        } else {
+            serde_json::to_string(&serializable)
+        }
+        .map_err(|e| InteropError::external(e).with_context("encoding json"))
+    }
+
+    /// Deserializes a script value from a JSON string.
+    pub fn decode(text: &str, world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        let registry = world.type_registry().clone();
+        let registry = registry.read();
+        let allocator = world.allocator();
+        let mut allocator = allocator.write();
+
+        let mut deserializer = serde_json::Deserializer::from_str(text);
+        let value = ScriptValueSeed::new(&registry, &mut allocator)
+            .deserialize(&mut deserializer)
+            .and_then(|value| deserializer.end().map(|_| value))
+            .map_err(|e| InteropError::external(e).with_context("decoding json"))?;
+        Ok(value)
+    }
+}
+
+/// The RON serialization format, exposed to scripts as the `ron` global.
+#[derive(Reflect, Clone, Copy, Debug, Default)]
+pub struct Ron;
+
+impl Ron {
+    /// Serializes a script value into a RON string.
+    pub fn encode(
+        value: &ScriptValue,
+        pretty: bool,
+        world: WorldGuard,
+    ) -> Result<String, InteropError> {
+        let registry = world.type_registry().clone();
+        let registry = registry.read();
+        let serializable = SerializableScriptValue::new(value, &registry, world);
+        if pretty {
+            ron::ser::to_string_pretty(&serializable, ron::ser::PrettyConfig::default())
+        } else {
+            ron::to_string(&serializable)
+        }
+        .map_err(|e| InteropError::external(e).with_context("encoding ron"))
+    }
+
+    /// Deserializes a script value from a RON string.
+    pub fn decode(text: &str, world: WorldGuard) -> Result<ScriptValue, InteropError> {
+        let registry = world.type_registry().clone();
+        let registry = registry.read();
+        let allocator = world.allocator();
+        let mut allocator = allocator.write();
+
+        ron::Options::default()
+            .from_str_seed(text, ScriptValueSeed::new(&registry, &mut allocator))
+            .map_err(|e| InteropError::external(e).with_context("decoding ron"))
+    }
+}
+
+/// A [`ScriptValue`] which can be serialized with serde.
+///
+/// References are serialized using their reflected type path, see the [module level documentation](self).
+pub struct SerializableScriptValue<'a, 'w> {
+    value: &'a ScriptValue,
+    registry: &'a TypeRegistry,
+    world: WorldGuard<'w>,
+}
+
+impl<'a, 'w> SerializableScriptValue<'a, 'w> {
+    /// Creates a new serializable script value.
+    pub fn new(value: &'a ScriptValue, registry: &'a TypeRegistry, world: WorldGuard<'w>) -> Self {
+        Self {
+            value,
+            registry,
+            world,
+        }
+    }
+
+    fn nested(&self, value: &'a ScriptValue) -> Self {
+        Self::new(value, self.registry, self.world.clone())
+    }
+}
+
+impl Serialize for SerializableScriptValue<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value {
+            ScriptValue::Unit => serializer.serialize_unit(),
+            ScriptValue::Bool(b) => serializer.serialize_bool(*b),
+            ScriptValue::Integer(i) => serializer.serialize_i64(*i),
+            ScriptValue::Float(f) => serializer.serialize_f64(*f),
+            ScriptValue::String(s) => serializer.serialize_str(s),
+            ScriptValue::Bytes(b) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(BYTES_KEY, b)?;
+                map.end()
+            }
+            ScriptValue::List(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(&self.nested(value))?;
+                }
+                seq.end()
+            }
+            ScriptValue::Tuple(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.0.len()))?;
+                for value in &values.0 {
+                    seq.serialize_element(&self.nested(value))?;
+                }
+                seq.end()
+            }
+            ScriptValue::Map(entries) => {
+                // sort the keys so the output is deterministic
+                let mut entries = entries.iter().collect::<Vec<_>>();
+                entries.sort_by_key(|(key, _)| *key);
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, &self.nested(value))?;
+                }
+                map.end()
+            }
+            ScriptValue::Reference(reference) => reference
+                .with_reflect(self.world.clone(), |value| {
+                    ReflectSerializer::new(value, self.registry).serialize(serializer)
+                })
+                .map_err(ser::Error::custom)?,
+            ScriptValue::Function(_) | ScriptValue::FunctionMut(_) => {
+                Err(ser::Error::custom("functions cannot be serialized"))
+            }
+            ScriptValue::Error(e) => Err(ser::Error::custom(e)),
+        }
+    }
+}
+
+/// A [`DeserializeSeed`] producing [`ScriptValue`]s, reconstructing typed values as references allocated in the given allocator.
+///
+/// See the [module level documentation](self) for details on which values are reconstructed.
+pub struct ScriptValueSeed<'a> {
+    registry: &'a TypeRegistry,
+    allocator: &'a mut ReflectAllocator,
+}
+
+impl<'a> ScriptValueSeed<'a> {
+    /// Creates a new seed, allocating reconstructed typed values in the given allocator.
+    pub fn new(registry: &'a TypeRegistry, allocator: &'a mut ReflectAllocator) -> Self {
+        Self {
+            registry,
+            allocator,
+        }
+    }
+
+    fn reborrow(&mut self) -> ScriptValueSeed<'_> {
+        ScriptValueSeed {
+            registry: self.registry,
+            allocator: &mut *self.allocator,
+        }
+    }
+
+    /// Allocates a deserialized value, converting it into its concrete type if possible.
+    fn allocate<E: de::Error>(&mut self, value: Box<dyn PartialReflect>) -> Result<ScriptValue, E> {
+        let concrete = value
+            .get_represented_type_info()
+            .and_then(|info| {
+                self.registry
+                    .get_type_data::<ReflectFromReflect>(info.type_id())
+            })
+            .and_then(|from_reflect| from_reflect.from_reflect(value.as_ref()));
+
+        let reference = match concrete {
+            Some(concrete) => ReflectReference::new_allocated_boxed(concrete, self.allocator),
+            None => ReflectReference::new_allocated_boxed_parial_reflect(value, self.allocator)
+                .map_err(de::Error::custom)?,
+        };
+        Ok(ScriptValue::Reference(reference))
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ScriptValueSeed<'_> {
+    type Value = ScriptValue;
+
+    fn deserialize<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ScriptValueSeed<'_> {
+    type Value = ScriptValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a script value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(ScriptValue::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(ScriptValue::Integer(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        i64::try_from(v).map(ScriptValue::Integer).map_err(|_| {
+            E::custom(InteropError::integer_overflow(
+                v,
+                std::any::TypeId::of::<i64>(),
+            ))
+        })
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_i128(i128::from(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        i64::try_from(v).map(ScriptValue::Integer).map_err(|_| {
+            E::custom(InteropError::integer_overflow(
+                v,
+                std::any::TypeId::of::<i64>(),
+            ))
+        })
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(ScriptValue::Float(v))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(ScriptValue::String(v.to_string().into()))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(ScriptValue::String(v.to_owned().into()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(ScriptValue::String(v.into()))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(ScriptValue::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(ScriptValue::Bytes(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(ScriptValue::Unit)
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(ScriptValue::Unit)
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::VecDeque::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element_seed(self.reborrow())? {
+            values.push_back(value);
+        }
+        Ok(ScriptValue::List(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = HashMap::<String, ScriptValue>::default();
+
+        let Some(first_key) = map.next_key::<String>()? else {
+            return Ok(ScriptValue::Map(entries));
+        };
+
+        let registry = self.registry;
+        let registration = registry.get_with_type_path(&first_key);
+
+        let first_value = match registration {
+            Some(registration) => {
+                let value =
+                    map.next_value_seed(TypedReflectDeserializer::new(registration, registry))?;
+                self.allocate(value)?
+            }
+            None if first_key == BYTES_KEY => ScriptValue::Bytes(map.next_value()?),
+            None => map.next_value_seed(self.reborrow())?,
+        };
+
+        let Some(key) = map.next_key::<String>()? else {
+            if registration.is_some() || matches!(first_value, ScriptValue::Bytes(_)) {
+                return Ok(first_value);
+            }
+            entries.insert(first_key, first_value);
+            return Ok(ScriptValue::Map(entries));
+        };
+
+        entries.insert(first_key, first_value);
+        let value = map.next_value_seed(self.reborrow())?;
+        entries.insert(key, value);
+        while let Some(key) = map.next_key::<String>()? {
+            let value = map.next_value_seed(self.reborrow())?;
+            entries.insert(key, value);
+        }
+        Ok(ScriptValue::Map(entries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy_ecs::{reflect::AppTypeRegistry, world::World};
+    use bevy_mod_scripting_world::WorldAccessGuard;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        AppReflectAllocator, CurrentScriptAttachment, function::script_function::ScriptFunction,
+    };
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    struct Health {
+        hp: u32,
+        name: String,
+    }
+
+    fn with_world(f: impl FnOnce(WorldGuard)) {
+        let mut world = World::default();
+        world.init_resource::<AppReflectAllocator>();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Health>();
+        registry.write().register::<u32>();
+        world.insert_resource(registry);
+        let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+        f(WorldAccessGuard::new_exclusive(&mut world, cache));
+    }
+
+    fn sample_value() -> ScriptValue {
+        let mut map = HashMap::default();
+        map.insert("name".to_owned(), ScriptValue::String("player".into()));
+        map.insert(
+            "scores".to_owned(),
+            ScriptValue::List(vec![ScriptValue::Integer(1), ScriptValue::Float(2.5)].into()),
+        );
+        map.insert("alive".to_owned(), ScriptValue::Bool(true));
+        ScriptValue::Map(map)
+    }
+
+    #[test]
+    fn plain_values_round_trip_through_json() {
+        with_world(|world| {
+            let encoded = Json::encode(&sample_value(), false, world.clone()).unwrap();
+            assert_eq!(
+                encoded,
+                r#"{"alive":true,"name":"player","scores":[1,2.5]}"#
+            );
+            let decoded = Json::decode(&encoded, world).unwrap();
+            assert_eq!(decoded, sample_value());
+        });
+    }
+
+    #[test]
+    fn plain_values_round_trip_through_ron() {
+        with_world(|world| {
+            let encoded = Ron::encode(&sample_value(), true, world.clone()).unwrap();
+            let decoded = Ron::decode(&encoded, world).unwrap();
+            assert_eq!(decoded, sample_value());
+        });
+    }
+
+    #[test]
+    fn references_serialize_with_type_path() {
+        with_world(|world| {
+            let health = Health {
+                hp: 10,
+                name: "player".to_owned(),
+            };
+            let reference = {
+                let allocator = world.allocator();
+                let mut allocator = allocator.write();
+                ReflectReference::new_allocated(health.clone(), &mut allocator)
+            };
+
+            let encoded =
+                Json::encode(&ScriptValue::Reference(reference), false, world.clone()).unwrap();
+            assert_eq!(
+                encoded,
+                format!(
+                    r#"{{"{}":{{"hp":10,"name":"player"}}}}"#,
+                    std::any::type_name::<Health>()
+                )
+            );
+
+            let decoded = Json::decode(&encoded, world.clone()).unwrap();
+            let ScriptValue::Reference(reference) = decoded else {
+                panic!("expected a reference, got: {decoded:?}");
+            };
+            assert_eq!(reference.downcast::<Health>(world).unwrap(), health);
+        });
+    }
+
+    #[test]
+    fn unknown_type_paths_deserialize_as_maps() {
+        with_world(|world| {
+            let decoded = Json::decode(r#"{"unknown::Type":{"a":1}}"#, world).unwrap();
+            assert!(matches!(decoded, ScriptValue::Map(_)));
+        });
+    }
+
+    #[test]
+    fn registered_primitive_type_paths_deserialize_as_references() {
+        with_world(|world| {
+            let decoded = Json::decode(r#"{"u32":5}"#, world.clone()).unwrap();
+            let ScriptValue::Reference(reference) = decoded else {
+                panic!("expected a reference, got: {decoded:?}");
+            };
+            assert_eq!(reference.downcast::<u32>(world).unwrap(), 5);
+        });
+    }
+
+    #[test]
+    fn bytes_round_trip_through_json() {
+        with_world(|world| {
+            let bytes = ScriptValue::Bytes(vec![0, 1, 255]);
+            let encoded = Json::encode(&bytes, false, world.clone()).unwrap();
+            assert_eq!(encoded, r#"{"$bytes":[0,1,255]}"#);
+            let decoded = Json::decode(&encoded, world).unwrap();
+            assert_eq!(decoded, bytes);
+        });
+    }
+
+    #[test]
+    fn bytes_round_trip_through_ron() {
+        with_world(|world| {
+            let bytes = ScriptValue::Bytes(vec![0, 1, 255]);
+            let encoded = Ron::encode(&bytes, false, world.clone()).unwrap();
+            let decoded = Ron::decode(&encoded, world).unwrap();
+            assert_eq!(decoded, bytes);
+        });
+    }
+
+    #[test]
+    fn nested_bytes_round_trip_through_json() {
+        with_world(|world| {
+            let mut map = HashMap::default();
+            map.insert("data".to_owned(), ScriptValue::Bytes(vec![4, 2]));
+            map.insert("len".to_owned(), ScriptValue::Integer(2));
+            let value = ScriptValue::Map(map);
+            let encoded = Json::encode(&value, false, world.clone()).unwrap();
+            let decoded = Json::decode(&encoded, world).unwrap();
+            assert_eq!(decoded, value);
+        });
+    }
+
+    #[test]
+    fn functions_cannot_be_serialized() {
+        with_world(|world| {
+            let function = (|| ()).into_dynamic_script_function();
+            let function = ScriptValue::Function(function);
+            assert!(Json::encode(&function, false, world).is_err());
+        });
+    }
+}
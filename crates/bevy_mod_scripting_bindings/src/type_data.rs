@@ -40,3 +40,61 @@ impl<T> FromType<T> for MarkAsCore {
         Self
     }
 }
+
+/// Attributes controlling how a type, field or variant is presented in generated documentation and declaration files.
+///
+/// These do not change how scripts interact with the item, only how it is documented.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScriptAttributes {
+    /// If true, the item is left out of generated outputs
+    pub hidden: bool,
+    /// If set, the name the item is documented under instead of its rust name
+    pub rename: Option<&'static str>,
+    /// If set, the item is documented as deprecated with the given message, which may be empty
+    pub deprecated: Option<&'static str>,
+}
+
+/// Implemented by types which carry [`ScriptAttributes`], usually via `#[derive(ScriptAttributes)]`.
+pub trait GetScriptAttributes {
+    /// Returns the attributes of the type and its members
+    fn script_attributes() -> ReflectScriptAttributes;
+}
+
+/// Type data containing the [`ScriptAttributes`] of a type and its members.
+///
+/// Registered via `#[reflect(ScriptAttributes)]` on types implementing [`GetScriptAttributes`]:
+/// ```rust,ignore
+/// #[derive(Reflect, ScriptAttributes)]
+/// #[reflect(ScriptAttributes)]
+/// #[script(rename = "Player")]
+/// struct PlayerState {
+///     #[script(hidden)]
+///     internal_id: u32,
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReflectScriptAttributes {
+    /// The attributes of the type itself
+    pub type_attributes: ScriptAttributes,
+    /// The attributes of members which have any, keyed by their path within the type:
+    /// - `field` or `0` for fields of structs and tuple structs
+    /// - `Variant` for enum variants
+    /// - `Variant.field` or `Variant.0` for fields of enum variants
+    pub members: Vec<(&'static str, ScriptAttributes)>,
+}
+
+impl ReflectScriptAttributes {
+    /// Returns the attributes of the member at the given path, or the default attributes if it has none
+    pub fn member(&self, path: &str) -> ScriptAttributes {
+        self.members
+            .iter()
+            .find_map(|(member, attributes)| (*member == path).then_some(*attributes))
+            .unwrap_or_default()
+    }
+}
+
+impl<T: GetScriptAttributes> FromType<T> for ReflectScriptAttributes {
+    fn from_type() -> Self {
+        T::script_attributes()
+    }
+}
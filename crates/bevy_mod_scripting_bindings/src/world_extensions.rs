@@ -7,16 +7,22 @@ use super::{
     ScriptTypeRegistration, Union,
     function::{
         namespace::Namespace,
-        script_function::{AppScriptFunctionRegistry, DynamicScriptFunction, FunctionCallContext},
+        script_function::{
+            AppScriptFunctionRegistry, DynamicScriptFunction, FunctionCallContext,
+            ScriptFunctionRegistry,
+        },
     },
     schedule::AppScheduleRegistry,
     script_value::ScriptValue,
 };
 use crate::{
-    DynamicComponent, DynamicComponentInfo, ScriptQueryBuilder, ScriptQueryResult,
+    DynamicComponent, DynamicComponentInfo, ErrorReturnMode, ErrorReturnModes,
+    IntegerOverflowPolicy, ScriptQueryBuilder, ScriptQueryResult,
+    docgen::info::FunctionInfo,
     error::InteropError,
     function::{from::FromScript, from_ref::FromScriptRef},
     reflection_extensions::PartialReflectExt,
+    script_value_into_reflect,
 };
 use ::{
     bevy_asset::{AssetServer, Handle, LoadState},
@@ -41,13 +47,13 @@ use bevy_ecs::{
     system::Commands,
     world::{CommandQueue, EntityRef, Mut},
 };
-use bevy_mod_scripting_asset::ScriptAsset;
+use bevy_mod_scripting_asset::{Language, ScriptAsset};
 use bevy_mod_scripting_script::ScriptAttachment;
 use bevy_mod_scripting_world::{CachedRegistry, RegistryCache, WorldAccessGuard, WorldGuard};
 use bevy_platform::collections::HashMap;
 use bevy_reflect::{
-    GetTypeRegistration, TypeInfo, enums::VariantInfo, structs::DynamicStruct, tuple::DynamicTuple,
-    tuple_struct::DynamicTupleStruct,
+    GetTypeRegistration, TypeInfo, TypeRegistry, enums::VariantInfo, structs::DynamicStruct,
+    tuple::DynamicTuple, tuple_struct::DynamicTupleStruct,
 };
 use bevy_system_reflection::ReflectSchedule;
 use std::{
@@ -229,6 +235,8 @@ pub trait WorldExtensions {
     ) -> Result<Box<dyn PartialReflect>, InteropError>;
 
     /// Attempts to call a function overload matching the provided arguments.
+    ///
+    /// The result is surfaced to the script like [`DynamicScriptFunction::call_from_script`] does, according to the [`ErrorReturnMode`] of the called overload.
     fn try_call_overloads(
         &self,
         type_id: TypeId,
@@ -250,6 +258,16 @@ pub trait WorldExtensions {
         name: impl Into<Cow<'static, str>>,
     ) -> Result<DynamicScriptFunction, Cow<'static, str>>;
 
+    /// Looks up a function callable on a static reference to the given type, i.e. `Transform.from_table(...)`.
+    ///
+    /// Functions defined on the type take priority, including the variant constructors of enums, see [`register_enum_variant_constructors`].
+    /// Otherwise functions defined on [`ScriptTypeRegistration`] are returned, with the registration of the type bound as their first argument.
+    fn lookup_static_function(
+        &self,
+        type_id: TypeId,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<DynamicScriptFunction, Cow<'static, str>>;
+
     /// Resolves a type registration by name.
     fn get_type_by_name(&self, type_name: &str) -> Option<ScriptTypeRegistration>;
 
@@ -309,6 +327,12 @@ pub trait WorldExtensions {
     /// Sets the current attachment for the world guard context.
     fn set_current_attachment(&self, attachment: ScriptAttachment);
 
+    /// Returns the policy for converting integers which do not fit into their target type.
+    fn integer_overflow_policy(&self) -> IntegerOverflowPolicy;
+
+    /// Returns the mode used to surface errors of fallible functions called from the given language which do not override it.
+    fn error_return_mode(&self, language: &Language) -> ErrorReturnMode;
+
     /// Registers a dynamic script component, and returns a reference to its registration
     fn register_script_component(
         &self,
@@ -325,6 +349,8 @@ pub trait WorldExtensions {
         function_registry: AppScriptFunctionRegistry,
         schedule_registry: AppScheduleRegistry,
         component_registry: AppScriptComponentRegistry,
+        integer_overflow_policy: IntegerOverflowPolicy,
+        error_return_modes: ErrorReturnModes,
     ) -> RegistryCache;
 }
 
@@ -812,7 +838,8 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
                         ))
                     })
                     .collect::<Result<Vec<_>, InteropError>>()?;
-                let mut dynamic = construct_dynamic_struct(self, &mut payload, fields_iter)?;
+                let mut dynamic =
+                    construct_dynamic_struct(self, &mut payload, fields_iter, one_indexed)?;
                 dynamic.set_represented_type(Some(type_info));
                 Box::new(dynamic)
             }
@@ -901,7 +928,8 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
                             })
                             .collect::<Result<Vec<_>, InteropError>>()?;
 
-                        let dynamic = construct_dynamic_struct(self, &mut payload, fields_iter)?;
+                        let dynamic =
+                            construct_dynamic_struct(self, &mut payload, fields_iter, one_indexed)?;
                         DynamicVariant::Struct(dynamic)
                     }
                     VariantInfo::Tuple(tuple_variant_info) => {
@@ -969,12 +997,13 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
         let mut last_error = None;
         for overload in overload_iter {
             match overload.call(args.clone(), context.clone()) {
-                Ok(out) => return Ok(out),
-                Err(e) => last_error = Some(e),
+                Ok(out) => return overload.into_script_return(Ok(out), &context),
+                Err(e) => last_error = Some((overload, e)),
             }
         }
 
-        Err(last_error.ok_or_else(|| InteropError::invariant("invariant, iterator should always return at least one item, and if the call fails it should return an error"))?)
+        let (overload, error) = last_error.ok_or_else(|| InteropError::invariant("invariant, iterator should always return at least one item, and if the call fails it should return an error"))?;
+        overload.into_script_return(Err(error), &context)
     }
 
     fn get_functions_on_type(
@@ -1013,6 +1042,37 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
         Err(name)
     }
 
+    fn lookup_static_function(
+        &self,
+        type_id: TypeId,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<DynamicScriptFunction, Cow<'static, str>> {
+        let name = match self.lookup_function([type_id], name) {
+            Ok(func) => return Ok(func),
+            Err(name) => name,
+        };
+
+        let registration = {
+            let type_registry = self.type_registry();
+            let type_registry = type_registry.read();
+            type_registry
+                .get(type_id)
+                .map(|registration| ScriptTypeRegistration::new(Arc::new(registration.clone())))
+        };
+
+        let func = self.lookup_function([TypeId::of::<ScriptTypeRegistration>()], name)?;
+        let Some(registration) = registration else {
+            return Err(func.name().clone());
+        };
+
+        let registration = {
+            let allocator = self.allocator();
+            let mut allocator = allocator.write();
+            ReflectReference::new_allocated(registration, &mut allocator)
+        };
+        Ok(func.bind_first_argument(ScriptValue::Reference(registration)))
+    }
+
     fn get_type_by_name(&self, type_name: &str) -> Option<ScriptTypeRegistration> {
         let type_registry = self.type_registry();
         let type_registry = type_registry.read();
@@ -1139,6 +1199,18 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
         )));
     }
 
+    fn integer_overflow_policy(&self) -> IntegerOverflowPolicy {
+        self.get_cached_registry::<IntegerOverflowPolicy>()
+            .map(|r| *r)
+            .unwrap_or_default()
+    }
+
+    fn error_return_mode(&self, language: &Language) -> ErrorReturnMode {
+        self.get_cached_registry::<ErrorReturnModes>()
+            .map(|r| r.for_language(language))
+            .unwrap_or_default()
+    }
+
     fn register_script_component(
         &self,
         component_name: String,
@@ -1198,12 +1270,16 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
         function_registry: AppScriptFunctionRegistry,
         schedule_registry: AppScheduleRegistry,
         component_registry: AppScriptComponentRegistry,
+        integer_overflow_policy: IntegerOverflowPolicy,
+        error_return_modes: ErrorReturnModes,
     ) -> RegistryCache {
         debug_assert_eq!(AppReflectAllocator::SLOT, 0);
         debug_assert_eq!(AppScriptFunctionRegistry::SLOT, 1);
         debug_assert_eq!(AppScheduleRegistry::SLOT, 2);
         debug_assert_eq!(AppScriptComponentRegistry::SLOT, 3);
         debug_assert_eq!(CurrentScriptAttachment::SLOT, 4);
+        debug_assert_eq!(IntegerOverflowPolicy::SLOT, 5);
+        debug_assert_eq!(ErrorReturnModes::SLOT, 6);
 
         [
             Rc::new(RefCell::new(allocator)),
@@ -1211,6 +1287,8 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
             Rc::new(RefCell::new(schedule_registry)),
             Rc::new(RefCell::new(component_registry)),
             Rc::new(RefCell::new(attachment)),
+            Rc::new(RefCell::new(integer_overflow_policy)),
+            Rc::new(RefCell::new(error_return_modes)),
         ]
     }
 
@@ -1220,6 +1298,8 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
         debug_assert_eq!(AppScheduleRegistry::SLOT, 2);
         debug_assert_eq!(AppScriptComponentRegistry::SLOT, 3);
         debug_assert_eq!(CurrentScriptAttachment::SLOT, 4);
+        debug_assert_eq!(IntegerOverflowPolicy::SLOT, 5);
+        debug_assert_eq!(ErrorReturnModes::SLOT, 6);
 
         [
             Rc::new(RefCell::new(
@@ -1247,6 +1327,18 @@ impl<'w> WorldExtensions for WorldAccessGuard<'w> {
                     .unwrap_or_default(),
             )),
             Rc::new(RefCell::new(attachment)),
+            Rc::new(RefCell::new(
+                world
+                    .get_resource::<IntegerOverflowPolicy>()
+                    .copied()
+                    .unwrap_or_default(),
+            )),
+            Rc::new(RefCell::new(
+                world
+                    .get_resource::<ErrorReturnModes>()
+                    .cloned()
+                    .unwrap_or_default(),
+            )),
         ]
     }
 
@@ -1272,6 +1364,7 @@ fn construct_from_script_value(
     descriptor: impl Into<Cow<'static, str>>,
     type_id: TypeId,
     value: Option<ScriptValue>,
+    one_indexed: bool,
 ) -> Result<Box<dyn PartialReflect>, InteropError> {
     // if the value is missing, try to construct a default and return it
     let value = match value {
@@ -1296,14 +1389,15 @@ fn construct_from_script_value(
         }
     };
 
-    // otherwise we need to use from_script_ref
-    <Box<dyn PartialReflect>>::from_script_ref(type_id, value, guard.clone())
+    // otherwise convert the value, constructing nested payloads as we go
+    script_value_into_reflect(value, type_id, one_indexed, guard.clone())
 }
 
 fn construct_dynamic_struct(
     guard: &WorldGuard,
     payload: &mut HashMap<String, ScriptValue>,
     fields: Vec<(&'static str, TypeId)>,
+    one_indexed: bool,
 ) -> Result<DynamicStruct, InteropError> {
     let mut dynamic = DynamicStruct::default();
     for (field_name, field_type_id) in fields {
@@ -1312,6 +1406,7 @@ fn construct_dynamic_struct(
             field_name,
             field_type_id,
             payload.remove(field_name),
+            one_indexed,
         )?;
 
         dynamic.insert_boxed(field_name, constructed);
@@ -1339,11 +1434,97 @@ fn construct_dynamic_tuple_struct(
             field_string.clone(),
             field_type_id,
             payload.remove(&field_string),
+            one_indexed,
         )?);
     }
     Ok(dynamic)
 }
 
+/// Registers a function constructing each variant of every reflected enum on the enum's namespace, i.e. `MyEnum.Attack{damage=1}`.
+///
+/// Variants whose name is already taken by a function on the enum's namespace are skipped, so explicitly registered functions take priority.
+pub fn register_enum_variant_constructors(
+    type_registry: &TypeRegistry,
+    function_registry: &mut ScriptFunctionRegistry,
+) {
+    for registration in type_registry.iter() {
+        let TypeInfo::Enum(enum_info) = registration.type_info() else {
+            continue;
+        };
+        let namespace = Namespace::OnType(registration.type_id());
+        let script_registration = ScriptTypeRegistration::new(Arc::new(registration.clone()));
+        for variant in enum_info.iter() {
+            if function_registry.contains(namespace, variant.name()) {
+                continue;
+            }
+            function_registry.raw_insert(
+                namespace,
+                variant.name(),
+                enum_variant_constructor(script_registration.clone(), variant.name().into()),
+            );
+        }
+    }
+}
+
+/// Creates a function constructing the given variant of an enum, i.e. `MyEnum.Attack{damage=1}`.
+///
+/// The function accepts an optional payload, either a map of field names for struct variants or a list of fields for tuple variants,
+/// and returns a reference to the newly allocated enum.
+fn enum_variant_constructor(
+    registration: ScriptTypeRegistration,
+    variant: Cow<'static, str>,
+) -> DynamicScriptFunction {
+    let type_id = registration.type_id();
+    let enum_name = registration.short_name();
+    let variant_name = variant.clone();
+    DynamicScriptFunction::from(
+        move |ctxt: FunctionCallContext, mut args: VecDeque<ScriptValue>| {
+            let mut construct = || -> Result<ScriptValue, InteropError> {
+                let world = ctxt.world()?;
+                let one_indexed = ctxt.convert_to_0_indexed();
+                let mut payload = match args.pop_front().unwrap_or(ScriptValue::Unit) {
+                    ScriptValue::Unit => HashMap::default(),
+                    ScriptValue::Map(map) => map.into_iter().collect(),
+                    ScriptValue::List(list) => list
+                        .into_iter()
+                        .enumerate()
+                        .map(|(idx, v)| {
+                            let idx = if one_indexed { idx + 1 } else { idx };
+                            (idx.to_string(), v)
+                        })
+                        .collect(),
+                    other => {
+                        return Err(InteropError::value_mismatch(
+                            TypeId::of::<HashMap<String, ScriptValue>>(),
+                            other,
+                        ));
+                    }
+                };
+                payload.insert(
+                    "variant".to_owned(),
+                    ScriptValue::String(variant_name.clone()),
+                );
+
+                let value = world.construct(registration.clone(), payload, one_indexed)?;
+                let allocator = world.allocator();
+                let mut allocator = allocator.write();
+                Ok(ScriptValue::Reference(
+                    ReflectReference::new_allocated_boxed_parial_reflect(value, &mut allocator)?,
+                ))
+            };
+            construct().unwrap_or_else(ScriptValue::Error)
+        },
+    )
+    .with_info(
+        FunctionInfo::new_for(variant.clone(), Namespace::OnType(type_id))
+            .add_arg::<Option<ScriptValue>>(Some("fields".into()))
+            .add_return::<ReflectReference>()
+            .with_docs(format!(
+                "Constructs the `{variant}` variant of `{enum_name}`, from a table of named fields or a list of unnamed fields."
+            )),
+    )
+}
+
 fn construct_dynamic_tuple(
     guard: &WorldGuard,
     payload: &mut HashMap<String, ScriptValue>,
@@ -1366,6 +1547,7 @@ fn construct_dynamic_tuple(
             field_string.clone(),
             field_type_id,
             payload.remove(&field_string),
+            one_indexed,
         )?);
     }
     Ok(dynamic)
@@ -1546,6 +1728,89 @@ mod test {
         pretty_assertions::assert_str_eq!(format!("{result:#?}"), format!("{expected:#?}"));
     }
 
+    #[test]
+    fn test_enum_variant_constructors_are_registered() {
+        let mut world = setup_world(|_, _| {});
+        let namespace = Namespace::OnType(TypeId::of::<SimpleEnum>());
+        {
+            let type_registry = world
+                .resource::<bevy_ecs::reflect::AppTypeRegistry>()
+                .clone();
+            let mut function_registry = world
+                .get_resource_or_init::<AppScriptFunctionRegistry>()
+                .clone();
+            let mut function_registry = function_registry.write();
+            function_registry.register(namespace, "Unit", || 1);
+            register_enum_variant_constructors(&type_registry.read(), &mut function_registry);
+        }
+        let world = make_guard(&mut world);
+
+        for variant in ["Struct", "TupleStruct"] {
+            let function = world
+                .lookup_static_function(TypeId::of::<SimpleEnum>(), variant)
+                .unwrap();
+            assert_eq!(function.info.namespace, namespace);
+            assert_eq!(function.info.arg_info.len(), 1);
+            assert!(
+                world
+                    .get_functions_on_type(TypeId::of::<SimpleEnum>())
+                    .iter()
+                    .any(|(name, _)| name == variant)
+            );
+        }
+
+        // explicitly registered functions are not replaced
+        let unit = world
+            .lookup_static_function(TypeId::of::<SimpleEnum>(), "Unit")
+            .unwrap();
+        assert!(unit.info.arg_info.is_empty());
+    }
+
+    #[test]
+    fn test_operator_dispatch_applies_language_error_return_mode() {
+        let mut world = setup_world(|_, _| {});
+        world.insert_resource(ErrorReturnModes {
+            default: ErrorReturnMode::Throw,
+            per_language: [(Language::Lua, ErrorReturnMode::NilAndMessage)].into(),
+        });
+        world
+            .get_resource_or_init::<AppScriptFunctionRegistry>()
+            .write()
+            .register(
+                Namespace::OnType(TypeId::of::<SimpleStruct>()),
+                "add",
+                |_: ScriptValue, _: ScriptValue| Err::<usize, _>(InteropError::str("failed")),
+            );
+        let cache = WorldAccessGuard::setup_cache(&world, CurrentScriptAttachment::default());
+        WorldGuard::with_static_guard(&mut world, cache, |world| {
+            bevy_mod_scripting_world::ThreadWorldContainer.set_context(
+                bevy_mod_scripting_world::ThreadScriptContext {
+                    world: world.clone(),
+                },
+            );
+            let args = vec![ScriptValue::Unit, ScriptValue::Unit];
+
+            let lua = world.try_call_overloads(
+                TypeId::of::<SimpleStruct>(),
+                "add",
+                args.clone(),
+                FunctionCallContext::new(Language::Lua),
+            );
+            assert!(
+                matches!(lua, Ok(ScriptValue::Tuple(_))),
+                "expected nil and a message, got: {lua:?}"
+            );
+
+            let rhai = world.try_call_overloads(
+                TypeId::of::<SimpleStruct>(),
+                "add",
+                args,
+                FunctionCallContext::new(Language::Rhai),
+            );
+            assert!(rhai.is_err(), "expected an error, got: {rhai:?}");
+        });
+    }
+
     fn make_guard(world: &mut World) -> WorldAccessGuard<'_> {
         let cache = WorldAccessGuard::setup_cache(world, CurrentScriptAttachment::default());
         WorldAccessGuard::new_exclusive(world, cache)
@@ -2004,6 +2269,8 @@ mod test {
                 .get_resource::<AppScriptComponentRegistry>()
                 .cloned()
                 .unwrap_or_default(),
+            IntegerOverflowPolicy::default(),
+            ErrorReturnModes::default(),
         );
         let guard = WorldAccessGuard::new_exclusive(&mut world, cache);
         assert!(guard.spawn().is_ok());
@@ -0,0 +1,16 @@
+/// Describes a deprecation in a short sentence, i.e. "deprecated since 0.2, use `new` instead".
+///
+/// Shared between the runtime and LAD file representations of function deprecations, so both read the same.
+pub fn deprecation_message(since: Option<&str>, replacement: Option<&str>) -> String {
+    let mut message = "deprecated".to_owned();
+    if let Some(since) = since {
+        message.push_str(" since ");
+        message.push_str(since);
+    }
+    if let Some(replacement) = replacement {
+        message.push_str(", use `");
+        message.push_str(replacement);
+        message.push_str("` instead");
+    }
+    message
+}
@@ -23,6 +23,30 @@ pub enum ScriptOperatorNames {
     Equality,
     /// a < b
     LessThanComparison,
+    /// a <= b
+    LessThanOrEqualComparison,
+    /// a > b
+    GreaterThanComparison,
+    /// a >= b
+    GreaterThanOrEqualComparison,
+    /// a & b
+    BitwiseAnd,
+    /// a | b
+    BitwiseOr,
+    /// a ^ b or a ~ b
+    BitwiseXor,
+    /// a << b
+    ShiftLeft,
+    /// a >> b
+    ShiftRight,
+    /// !a or ~a
+    BitwiseNot,
+    /// a // b
+    FloorDivision,
+    /// a .. b
+    Concatenation,
+    /// a(b, c)
+    Call,
     /// len(a)
     Length,
     /// for a in b.iter()
@@ -46,6 +70,18 @@ impl ScriptOperatorNames {
             ScriptOperatorNames::Exponentiation => "pow",
             ScriptOperatorNames::Equality => "eq",
             ScriptOperatorNames::LessThanComparison => "lt",
+            ScriptOperatorNames::LessThanOrEqualComparison => "le",
+            ScriptOperatorNames::GreaterThanComparison => "gt",
+            ScriptOperatorNames::GreaterThanOrEqualComparison => "ge",
+            ScriptOperatorNames::BitwiseAnd => "bitand",
+            ScriptOperatorNames::BitwiseOr => "bitor",
+            ScriptOperatorNames::BitwiseXor => "bitxor",
+            ScriptOperatorNames::ShiftLeft => "shl",
+            ScriptOperatorNames::ShiftRight => "shr",
+            ScriptOperatorNames::BitwiseNot => "not",
+            ScriptOperatorNames::FloorDivision => "idiv",
+            ScriptOperatorNames::Concatenation => "concat",
+            ScriptOperatorNames::Call => "call",
             ScriptOperatorNames::Length => "len",
             ScriptOperatorNames::Iteration => "iter",
             ScriptOperatorNames::DisplayPrint => "display",
@@ -65,6 +101,18 @@ impl ScriptOperatorNames {
             "pow" => Some(ScriptOperatorNames::Exponentiation),
             "eq" => Some(ScriptOperatorNames::Equality),
             "lt" => Some(ScriptOperatorNames::LessThanComparison),
+            "le" => Some(ScriptOperatorNames::LessThanOrEqualComparison),
+            "gt" => Some(ScriptOperatorNames::GreaterThanComparison),
+            "ge" => Some(ScriptOperatorNames::GreaterThanOrEqualComparison),
+            "bitand" => Some(ScriptOperatorNames::BitwiseAnd),
+            "bitor" => Some(ScriptOperatorNames::BitwiseOr),
+            "bitxor" => Some(ScriptOperatorNames::BitwiseXor),
+            "shl" => Some(ScriptOperatorNames::ShiftLeft),
+            "shr" => Some(ScriptOperatorNames::ShiftRight),
+            "not" => Some(ScriptOperatorNames::BitwiseNot),
+            "idiv" => Some(ScriptOperatorNames::FloorDivision),
+            "concat" => Some(ScriptOperatorNames::Concatenation),
+            "call" => Some(ScriptOperatorNames::Call),
             "len" => Some(ScriptOperatorNames::Length),
             "iter" => Some(ScriptOperatorNames::Iteration),
             "display" => Some(ScriptOperatorNames::DisplayPrint),
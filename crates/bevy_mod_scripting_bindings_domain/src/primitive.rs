@@ -28,6 +28,8 @@ pub enum ReflectionPrimitiveKind {
     String,
     OsString,
     PathBuf,
+    /// A contiguous buffer of bytes, i.e. `Vec<u8>`
+    Bytes,
     FunctionCallContext,
     DynamicFunction,
     ScriptValue,
@@ -61,6 +63,7 @@ impl std::fmt::Display for ReflectionPrimitiveKind {
             ReflectionPrimitiveKind::String => f.write_str("String"),
             ReflectionPrimitiveKind::OsString => f.write_str("OsString"),
             ReflectionPrimitiveKind::PathBuf => f.write_str("PathBuf"),
+            ReflectionPrimitiveKind::Bytes => f.write_str("Bytes"),
             ReflectionPrimitiveKind::FunctionCallContext => f.write_str("FunctionCallContext"),
             ReflectionPrimitiveKind::DynamicFunction => f.write_str("DynamicFunction"),
             ReflectionPrimitiveKind::ScriptValue => f.write_str("ScriptValue"),
@@ -0,0 +1,150 @@
+//! Contains the registry documenting the callbacks scripts can implement.
+//!
+//! The registry is purely informational, it does not affect which callbacks are dispatched, but it is used to generate documentation and declaration files.
+
+use std::{borrow::Cow, sync::Arc};
+
+use ::{bevy_ecs::resource::Resource, bevy_platform::collections::HashMap};
+use bevy_mod_scripting_bindings::{ThroughTypeInfo, TypedThrough};
+use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::event::{CallbackLabel, IntoCallbackLabel};
+
+/// A send + sync wrapper around the [`ScriptCallbackRegistry`].
+#[derive(Default, Resource, Clone)]
+pub struct AppScriptCallbackRegistry(Arc<RwLock<ScriptCallbackRegistry>>);
+
+impl AppScriptCallbackRegistry {
+    /// Returns a reference to the inner [`ScriptCallbackRegistry`].
+    pub fn read(&self) -> RwLockReadGuard<'_, ScriptCallbackRegistry> {
+        self.0.read()
+    }
+
+    /// Returns a mutable reference to the inner [`ScriptCallbackRegistry`].
+    pub fn write(&self) -> RwLockWriteGuard<'_, ScriptCallbackRegistry> {
+        self.0.write()
+    }
+}
+
+/// An argument passed to a script callback.
+#[derive(Clone)]
+pub struct ScriptCallbackArgument {
+    /// The name of the argument
+    pub name: Cow<'static, str>,
+    /// The documentation for the argument
+    pub documentation: Option<Cow<'static, str>>,
+    /// Rich type information for the argument
+    pub type_information: ThroughTypeInfo,
+}
+
+/// The value a script callback is expected to return.
+#[derive(Clone)]
+pub struct ScriptCallbackReturn {
+    /// The documentation for the return value, i.e. what it is used for
+    pub documentation: Option<Cow<'static, str>>,
+    /// Rich type information for the return value
+    pub type_information: ThroughTypeInfo,
+}
+
+/// Describes the signature of a callback which scripts can implement.
+#[derive(Clone)]
+pub struct ScriptCallbackInfo {
+    /// The label of the callback, i.e. the name of the function scripts implement
+    pub label: CallbackLabel,
+    /// The documentation for the callback
+    pub documentation: Option<Cow<'static, str>>,
+    /// The arguments the callback is called with, in order
+    pub arguments: Vec<ScriptCallbackArgument>,
+    /// The value the callback is expected to return, if `None` the return value is ignored
+    pub returns: Option<ScriptCallbackReturn>,
+}
+
+impl ScriptCallbackInfo {
+    /// Creates a new callback info with the given label and no arguments
+    pub fn new(label: impl Into<CallbackLabel>) -> Self {
+        Self {
+            label: label.into(),
+            documentation: None,
+            arguments: Vec::new(),
+            returns: None,
+        }
+    }
+
+    /// Creates a new callback info for the given callback label type
+    pub fn of<L: IntoCallbackLabel>() -> Self {
+        Self::new(L::into_callback_label())
+    }
+
+    /// Sets the documentation of the callback
+    pub fn with_documentation(mut self, documentation: impl Into<Cow<'static, str>>) -> Self {
+        self.documentation = Some(documentation.into());
+        self
+    }
+
+    /// Appends an argument to the callback signature
+    pub fn with_argument<T: TypedThrough>(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        documentation: Option<&'static str>,
+    ) -> Self {
+        self.arguments.push(ScriptCallbackArgument {
+            name: name.into(),
+            documentation: documentation.map(Cow::Borrowed),
+            type_information: T::through_type_info(),
+        });
+        self
+    }
+
+    /// Sets the value the callback is expected to return
+    pub fn with_return<T: TypedThrough>(mut self, documentation: Option<&'static str>) -> Self {
+        self.returns = Some(ScriptCallbackReturn {
+            documentation: documentation.map(Cow::Borrowed),
+            type_information: T::through_type_info(),
+        });
+        self
+    }
+}
+
+/// A registry of callbacks which scripts can implement.
+#[derive(Default)]
+pub struct ScriptCallbackRegistry {
+    callbacks: HashMap<CallbackLabel, ScriptCallbackInfo>,
+}
+
+impl ScriptCallbackRegistry {
+    /// Registers a callback, returns the previous registration with the same label if it existed.
+    pub fn register(&mut self, info: ScriptCallbackInfo) -> Option<ScriptCallbackInfo> {
+        self.callbacks.insert(info.label.clone(), info)
+    }
+
+    /// Registers a callback unless one with the same label is already registered, returns true if the callback was registered.
+    ///
+    /// Useful for providing default documentation which users can override.
+    pub fn register_if_missing(&mut self, info: ScriptCallbackInfo) -> bool {
+        if self.callbacks.contains_key(&info.label) {
+            return false;
+        }
+        self.callbacks.insert(info.label.clone(), info);
+        true
+    }
+
+    /// Gets the callback registered with the given label
+    pub fn get(&self, label: &CallbackLabel) -> Option<&ScriptCallbackInfo> {
+        self.callbacks.get(label)
+    }
+
+    /// Iterates over all registered callbacks
+    pub fn iter(&self) -> impl Iterator<Item = &ScriptCallbackInfo> {
+        self.callbacks.values()
+    }
+
+    /// Returns the number of registered callbacks
+    pub fn len(&self) -> usize {
+        self.callbacks.len()
+    }
+
+    /// Returns true if no callbacks are registered
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     event::{CallbackLabel, ScriptAttachedEvent, ScriptCallbackResponseEvent, ScriptDetachedEvent},
     handler::{ScriptingHandler, send_callback_response, send_script_errors},
     pipeline::RunProcessingPipelineOnce,
-    script::Context,
+    script::{Context, ScriptPriorities},
 };
 use bevy_ecs::{
     error::{BevyError, Severity},
@@ -234,12 +234,22 @@ impl<P: IntoScriptPluginParams> Command for RunScriptCallback<P> {
 
 /// Command which emits a [`ScriptAttachedEvent`] and then runs the processing pipeline to immediately process it.
 /// The end result is equivalent to attaching a script component or adding a static script and waiting for the normal pipeline to process it.
-pub struct AttachScript<P: IntoScriptPluginParams>(ScriptAttachedEvent, PhantomData<fn(P)>);
+pub struct AttachScript<P: IntoScriptPluginParams>(
+    ScriptAttachedEvent,
+    Option<i32>,
+    PhantomData<fn(P)>,
+);
 
 impl<P: IntoScriptPluginParams> AttachScript<P> {
     /// Creates a new [`AttachScript`] command, which will create the given attachment, run expected callbacks, and
     pub fn new(attachment: ScriptAttachment) -> Self {
-        Self(ScriptAttachedEvent(attachment), Default::default())
+        Self(ScriptAttachedEvent(attachment), None, Default::default())
+    }
+
+    /// Sets the callback priority of the attachment, see [`ScriptPriorities`]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.1 = Some(priority);
+        self
     }
 }
 
@@ -256,6 +266,11 @@ impl<P: IntoScriptPluginParams> DetachScript<P> {
 
 impl<P: IntoScriptPluginParams> Command for AttachScript<P> {
     fn apply(self, world: &mut World) {
+        if let Some(priority) = self.1 {
+            world
+                .get_resource_or_init::<ScriptPriorities>()
+                .set(self.0.0.clone(), priority);
+        }
         world.write_message(self.0);
         RunProcessingPipelineOnce::<P>::new(Some(Duration::from_secs(9999))).apply(world)
     }
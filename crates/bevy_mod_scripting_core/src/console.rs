@@ -0,0 +1,488 @@
+//! An interactive console for evaluating snippets of code against live script contexts.
+//!
+//! Code is either evaluated inside the context of an existing [`ScriptAttachment`], or inside a fresh throwaway context
+//! loaded with the same initializers as any other script.
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    marker::PhantomData,
+    net::{TcpListener, ToSocketAddrs},
+    sync::{
+        Arc,
+        mpsc::{Receiver, Sender, channel},
+    },
+    thread::{self, JoinHandle},
+};
+
+use bevy_app::{App, Plugin, Update};
+use bevy_asset::Handle;
+use bevy_ecs::{resource::Resource, world::World};
+use bevy_log::{error, info};
+use bevy_mod_scripting_bindings::{
+    CurrentScriptAttachment, InteropError, ScriptValue, WorldExtensions,
+};
+use bevy_mod_scripting_display::{DisplayProxy, WithTypeInfo};
+use bevy_mod_scripting_script::ScriptAttachment;
+use bevy_mod_scripting_world::{WorldAccessGuard, WorldGuard};
+use parking_lot::Mutex;
+
+use crate::{
+    IntoScriptPluginParams,
+    context::ScriptingLoader,
+    error::ScriptError,
+    script::{Context, ScriptContexts},
+};
+
+/// A function evaluating a snippet of code inside a context, returning the value the snippet evaluates to
+pub type EvalFn<P> = fn(
+    code: &str,
+    context_key: &ScriptAttachment,
+    context: &mut <P as IntoScriptPluginParams>::C,
+    world_id: bevy_ecs::world::WorldId,
+) -> Result<ScriptValue, InteropError>;
+
+/// The default [`EvalFn`] for languages which do not support evaluating code, always fails with an unsupported operation error.
+pub fn unsupported_evaluator<P: IntoScriptPluginParams + ?Sized>(
+    _code: &str,
+    _context_key: &ScriptAttachment,
+    _context: &mut P::C,
+    _world_id: bevy_ecs::world::WorldId,
+) -> Result<ScriptValue, InteropError> {
+    Err(InteropError::unsupported_operation(
+        None,
+        None,
+        format!("evaluating code in {} contexts", P::LANGUAGE),
+    ))
+}
+
+/// A utility trait, implemented for all types implementing `IntoScriptPluginParams`.
+///
+/// Calls the underlying evaluator function while providing the necessary thread local context.
+pub trait ScriptingEvaluator<P: IntoScriptPluginParams> {
+    /// Evaluates the code inside the given context
+    fn evaluate(
+        code: &str,
+        context_key: &ScriptAttachment,
+        script_ctxt: &mut P::C,
+        world: WorldGuard,
+    ) -> Result<ScriptValue, InteropError>;
+}
+
+impl<P: IntoScriptPluginParams> ScriptingEvaluator<P> for P {
+    fn evaluate(
+        code: &str,
+        context_key: &ScriptAttachment,
+        script_ctxt: &mut P::C,
+        world: WorldGuard,
+    ) -> Result<ScriptValue, InteropError> {
+        WorldGuard::with_existing_static_guard(world, |world| {
+            world.set_current_attachment(context_key.clone());
+            let world_id = world.id();
+            Self::evaluator()(code, context_key, script_ctxt, world_id)
+        })
+    }
+}
+
+/// A request sent to the [`ScriptConsole`], processed once per frame.
+#[derive(Debug)]
+pub enum ConsoleRequest {
+    /// Evaluate code and reply with the formatted result
+    Evaluate {
+        /// The attachment whose context to evaluate the code in, if `None` a throwaway context is used
+        target: Option<ScriptAttachment>,
+        /// The code to evaluate
+        code: String,
+        /// Where to send the formatted result
+        reply: Sender<String>,
+    },
+    /// List all attachments which can be targeted
+    ListAttachments {
+        /// Where to send the attachments
+        reply: Sender<Vec<ScriptAttachment>>,
+    },
+}
+
+/// A console evaluating code against live script contexts of the `P` language.
+///
+/// Code can be evaluated directly via [`ScriptConsole::evaluate`], or submitted from other threads via [`ScriptConsole::submit`],
+/// in which case it will be evaluated by the [`process_console_requests`] system added by the [`ScriptConsolePlugin`].
+#[derive(Resource)]
+pub struct ScriptConsole<P: IntoScriptPluginParams> {
+    sender: Sender<ConsoleRequest>,
+    receiver: Arc<Mutex<Receiver<ConsoleRequest>>>,
+    _ph: PhantomData<fn(P)>,
+}
+
+impl<P: IntoScriptPluginParams> Default for ScriptConsole<P> {
+    fn default() -> Self {
+        let (sender, receiver) = channel();
+        Self {
+            sender,
+            receiver: Arc::new(Mutex::new(receiver)),
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<P: IntoScriptPluginParams> Clone for ScriptConsole<P> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            receiver: self.receiver.clone(),
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<P: IntoScriptPluginParams> ScriptConsole<P> {
+    /// A sender which can be used to submit requests from any thread
+    pub fn sender(&self) -> Sender<ConsoleRequest> {
+        self.sender.clone()
+    }
+
+    /// Submits code to be evaluated on the next run of [`process_console_requests`], the formatted result is sent to the returned receiver.
+    pub fn submit(
+        &self,
+        target: Option<ScriptAttachment>,
+        code: impl Into<String>,
+    ) -> Receiver<String> {
+        let (reply, response) = channel();
+        let _ = self.sender.send(ConsoleRequest::Evaluate {
+            target,
+            code: code.into(),
+            reply,
+        });
+        response
+    }
+
+    /// Evaluates the code in the context of the given attachment, or in a fresh throwaway context if no attachment is given.
+    ///
+    /// Throwaway contexts are loaded with the same initializers as normal scripts, and are discarded after the evaluation.
+    pub fn evaluate(
+        world: &mut World,
+        target: Option<&ScriptAttachment>,
+        code: &str,
+    ) -> Result<ScriptValue, ScriptError> {
+        let script_contexts = world.get_resource_or_init::<ScriptContexts<P>>().clone();
+        let attachment = target
+            .cloned()
+            .unwrap_or_else(|| ScriptAttachment::StaticScript(Handle::default()));
+        let cache =
+            WorldAccessGuard::setup_cache(world, CurrentScriptAttachment(Some(attachment.clone())));
+        let guard = WorldGuard::new_exclusive(world, cache);
+
+        let context = target.and_then(|attachment| script_contexts.read().get_context(attachment));
+        let result = match target {
+            Some(attachment) => match context {
+                Some(Context::LoadedAndActive(context)) => {
+                    P::evaluate(code, attachment, &mut context.lock(), guard.clone())
+                }
+                Some(s) => Err(InteropError::string(format!(
+                    "Cannot evaluate code in script while in state of: {s}"
+                ))),
+                None => Err(InteropError::str("No context found for script")),
+            },
+            None => <P as ScriptingLoader<P>>::load(&attachment, &[], guard.clone()).and_then(
+                |mut context| P::evaluate(code, &attachment, &mut context, guard.clone()),
+            ),
+        };
+
+        result.map_err(|e| {
+            let err = ScriptError::from(e)
+                .with_context("in console")
+                .with_language(P::LANGUAGE);
+            match target {
+                Some(attachment) => err.with_script(attachment.script().display()),
+                None => err,
+            }
+        })
+    }
+
+    /// Equivalent to [`Self::evaluate`], but formats the resulting value or error for display
+    pub fn evaluate_formatted(
+        world: &mut World,
+        target: Option<&ScriptAttachment>,
+        code: &str,
+    ) -> String {
+        let result = Self::evaluate(world, target, code);
+        let cache = WorldAccessGuard::setup_cache(world, CurrentScriptAttachment::default());
+        let guard = WorldAccessGuard::new_exclusive(world, cache);
+        match &result {
+            Ok(value) => WithTypeInfo::new_with_info(value, &guard).to_string(),
+            Err(err) => WithTypeInfo::new_with_info(err, &guard).to_string(),
+        }
+    }
+}
+
+/// Evaluates all pending [`ConsoleRequest`]s submitted to the [`ScriptConsole`]
+pub fn process_console_requests<P: IntoScriptPluginParams>(world: &mut World) {
+    let Some(console) = world.get_resource::<ScriptConsole<P>>().cloned() else {
+        return;
+    };
+    let requests = console.receiver.lock().try_iter().collect::<Vec<_>>();
+    for request in requests {
+        match request {
+            ConsoleRequest::Evaluate {
+                target,
+                code,
+                reply,
+            } => {
+                let _ = reply.send(ScriptConsole::<P>::evaluate_formatted(
+                    world,
+                    target.as_ref(),
+                    &code,
+                ));
+            }
+            ConsoleRequest::ListAttachments { reply } => {
+                let script_contexts = world.get_resource_or_init::<ScriptContexts<P>>().clone();
+                let mut attachments = script_contexts
+                    .read()
+                    .all_residents()
+                    .map(|(attachment, _)| attachment)
+                    .collect::<Vec<_>>();
+                attachments.sort_by_key(|a| a.to_string());
+                let _ = reply.send(attachments);
+            }
+        }
+    }
+}
+
+/// The transport used to expose the console to external clients.
+#[derive(Debug, Clone, Default)]
+pub enum ConsoleTransport {
+    /// Do not start a front-end, code is submitted manually via the [`ScriptConsole`] resource
+    #[default]
+    None,
+    /// Accept line based sessions on the given TCP address, i.e. `127.0.0.1:4712`
+    Tcp(String),
+    /// Run a line based session over the process's stdin and stdout
+    Stdio,
+}
+
+/// Adds the [`ScriptConsole`] resource for the `P` language and the system evaluating requests submitted to it.
+pub struct ScriptConsolePlugin<P: IntoScriptPluginParams> {
+    /// The front-end to expose the console over
+    pub transport: ConsoleTransport,
+    _ph: PhantomData<fn(P)>,
+}
+
+impl<P: IntoScriptPluginParams> Default for ScriptConsolePlugin<P> {
+    fn default() -> Self {
+        Self::new(ConsoleTransport::None)
+    }
+}
+
+impl<P: IntoScriptPluginParams> ScriptConsolePlugin<P> {
+    /// Creates a new console plugin with the given transport
+    pub fn new(transport: ConsoleTransport) -> Self {
+        Self {
+            transport,
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<P: IntoScriptPluginParams> Plugin for ScriptConsolePlugin<P> {
+    fn build(&self, app: &mut App) {
+        let console = ScriptConsole::<P>::default();
+        match &self.transport {
+            ConsoleTransport::None => {}
+            ConsoleTransport::Tcp(address) => {
+                if let Err(err) = listen_tcp(console.sender(), address.as_str()) {
+                    error!("Failed to start script console on {address}: {err}");
+                }
+            }
+            ConsoleTransport::Stdio => {
+                serve_stdio(console.sender());
+            }
+        }
+        app.insert_resource(console)
+            .add_systems(Update, process_console_requests::<P>);
+    }
+}
+
+/// Accepts line based console sessions on the given TCP address on a background thread, serving one client at a time.
+pub fn listen_tcp(
+    sender: Sender<ConsoleRequest>,
+    address: impl ToSocketAddrs,
+) -> io::Result<JoinHandle<()>> {
+    let listener = TcpListener::bind(address)?;
+    if let Ok(address) = listener.local_addr() {
+        info!("Script console listening on {address}");
+    }
+    thread::Builder::new()
+        .name("bms_script_console".into())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(err) => {
+                        error!("Script console failed to accept client: {err}");
+                        continue;
+                    }
+                };
+                match stream.try_clone() {
+                    Ok(reader) => {
+                        if let Err(err) =
+                            run_console_session(sender.clone(), BufReader::new(reader), stream)
+                        {
+                            error!("Script console session ended with error: {err}");
+                        }
+                    }
+                    Err(err) => error!("Script console failed to set up client stream: {err}"),
+                }
+            }
+        })
+}
+
+/// Runs a line based console session over stdin and stdout on a background thread.
+pub fn serve_stdio(sender: Sender<ConsoleRequest>) -> JoinHandle<()> {
+    thread::spawn(move || {
+        if let Err(err) = run_console_session(sender, io::stdin().lock(), io::stdout()) {
+            error!("Script console session ended with error: {err}");
+        }
+    })
+}
+
+const CONSOLE_HELP: &str = "\
+Each line is evaluated as code, the following commands are also available:
+  :list       list all script attachments
+  :use <n>    evaluate code in the context of the n-th attachment from the last :list
+  :use none   evaluate code in throwaway contexts (default)
+  :help       show this message";
+
+/// Runs a line based console session until the input stream closes.
+///
+/// Each line is evaluated as code, lines starting with `:` are interpreted as console commands, see `:help`.
+pub fn run_console_session(
+    sender: Sender<ConsoleRequest>,
+    reader: impl BufRead,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    let mut listed = Vec::<ScriptAttachment>::default();
+    let mut target = None::<ScriptAttachment>;
+
+    write!(writer, "> ")?;
+    writer.flush()?;
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        let output = match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [] => None,
+            [":help"] => Some(CONSOLE_HELP.to_owned()),
+            [":list"] => {
+                let (reply, response) = channel();
+                let _ = sender.send(ConsoleRequest::ListAttachments { reply });
+                listed = response.recv().unwrap_or_default();
+                Some(
+                    listed
+                        .iter()
+                        .enumerate()
+                        .map(|(i, attachment)| format!("{i}: {attachment}"))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            }
+            [":use", "none"] => {
+                target = None;
+                Some("evaluating in throwaway contexts".to_owned())
+            }
+            [":use", index] => Some(
+                match index.parse::<usize>().ok().and_then(|i| listed.get(i)) {
+                    Some(attachment) => {
+                        target = Some(attachment.clone());
+                        format!("evaluating in the context of {attachment}")
+                    }
+                    None => format!("no attachment at index {index}, use :list first"),
+                },
+            ),
+            [command, ..] if command.starts_with(':') => {
+                Some(format!("unknown command {command}, see :help"))
+            }
+            _ => {
+                let (reply, response) = channel();
+                let _ = sender.send(ConsoleRequest::Evaluate {
+                    target: target.clone(),
+                    code: line.to_owned(),
+                    reply,
+                });
+                Some(
+                    response
+                        .recv()
+                        .unwrap_or_else(|_| "the console is no longer running".to_owned()),
+                )
+            }
+        };
+
+        if let Some(output) = output {
+            writeln!(writer, "{output}")?;
+        }
+        write!(writer, "> ")?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use bevy_app::Plugin;
+    use bevy_ecs::entity::Entity;
+    use test_utils::make_test_plugin;
+
+    use super::*;
+    use crate::config::{GetPluginThreadConfig, ScriptingPluginConfiguration};
+
+    make_test_plugin!(crate);
+
+    #[test]
+    fn unsupported_evaluator_fails() {
+        let world = World::new();
+        let result = unsupported_evaluator::<TestPlugin>(
+            "1 + 1",
+            &ScriptAttachment::StaticScript(Handle::default()),
+            &mut TestContext::default(),
+            world.id(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn console_session_forwards_code_and_commands() {
+        let (sender, receiver) = channel();
+        let handle = thread::spawn(move || {
+            let mut output = Vec::default();
+            run_console_session(
+                sender,
+                Cursor::new(":list\n:use 0\n1 + 1\n:use 5\n:nope\n"),
+                &mut output,
+            )
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        });
+
+        let attachment = ScriptAttachment::StaticScript(Handle::default());
+        for request in receiver.iter() {
+            match request {
+                ConsoleRequest::ListAttachments { reply } => {
+                    reply.send(vec![attachment.clone()]).unwrap();
+                }
+                ConsoleRequest::Evaluate {
+                    target,
+                    code,
+                    reply,
+                } => {
+                    assert_eq!(target, Some(attachment.clone()));
+                    reply.send(format!("evaluated {code}")).unwrap();
+                }
+            }
+        }
+
+        let output = handle.join().unwrap();
+        assert!(output.contains(&format!("0: {attachment}")));
+        assert!(output.contains(&format!("evaluating in the context of {attachment}")));
+        assert!(output.contains("evaluated 1 + 1"));
+        assert!(output.contains("no attachment at index 5"));
+        assert!(output.contains("unknown command :nope"));
+    }
+}
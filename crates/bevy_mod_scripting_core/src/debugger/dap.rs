@@ -0,0 +1,482 @@
+//! A minimal [debug adapter protocol](https://microsoft.github.io/debug-adapter-protocol/) server for the [`ScriptDebugger`].
+//!
+//! Supports the subset of requests needed for breakpoints, stepping and variable inspection.
+//! All scripts are exposed as a single thread, since only one script call can be paused at a time.
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, ToSocketAddrs},
+    sync::{
+        Arc,
+        atomic::{AtomicI64, Ordering},
+        mpsc::{Sender, channel},
+    },
+    thread::{self, JoinHandle},
+};
+
+use bevy_log::{error, info};
+use parking_lot::Mutex;
+use serde_json::{Value, json};
+
+use super::{
+    DebugStackFrame, DebugVariable, DebuggerClient, DebuggerEvent, DebuggerRequest, ResumeMode,
+    ScriptDebugger,
+};
+
+/// The id of the single thread reported to clients
+const THREAD_ID: i64 = 1;
+
+/// Reads a single `Content-Length` framed message, returns `None` once the stream is closed.
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some(length) = line.strip_prefix("Content-Length:") {
+            content_length = Some(
+                length
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            );
+        }
+    }
+
+    let mut body = vec![0; content_length.unwrap_or_default()];
+    reader.read_exact(&mut body)?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(io::Error::from)
+}
+
+/// Writes a single `Content-Length` framed message.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = serde_json::to_vec(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n", body.len())?;
+    writer.write_all(&body)?;
+    writer.flush()
+}
+
+/// Listens for clients on the given TCP address on a background thread, serving one client at a time.
+pub fn listen_tcp(
+    debugger: ScriptDebugger,
+    address: impl ToSocketAddrs,
+) -> io::Result<JoinHandle<()>> {
+    let listener = TcpListener::bind(address)?;
+    if let Ok(address) = listener.local_addr() {
+        info!("Script debugger listening on {address}");
+    }
+    thread::Builder::new()
+        .name("bms_debug_adapter".into())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(err) => {
+                        error!("Script debugger failed to accept client: {err}");
+                        continue;
+                    }
+                };
+                match stream.try_clone() {
+                    Ok(reader) => {
+                        run_session(debugger.clone(), BufReader::new(reader), Box::new(stream))
+                    }
+                    Err(err) => error!("Script debugger failed to set up client stream: {err}"),
+                }
+            }
+        })
+}
+
+/// Serves a single client over stdin and stdout on a background thread.
+///
+/// Nothing else should be writing to stdout while the session is active.
+pub fn serve_stdio(debugger: ScriptDebugger) -> JoinHandle<()> {
+    thread::spawn(move || run_session(debugger, io::stdin().lock(), Box::new(io::stdout())))
+}
+
+/// Runs a debug session on the current thread until the client disconnects or the stream closes.
+///
+/// Detaches the debugger and resumes any paused script once the session ends.
+pub fn run_session(
+    debugger: ScriptDebugger,
+    mut reader: impl BufRead,
+    writer: Box<dyn Write + Send>,
+) {
+    let DebuggerClient {
+        debugger,
+        requests,
+        events,
+    } = debugger.attach();
+    let output = Arc::new(Output {
+        writer: Mutex::new(writer),
+        seq: AtomicI64::new(1),
+    });
+
+    let event_output = output.clone();
+    let event_thread = thread::spawn(move || {
+        for event in events.iter() {
+            event_output.forward(event);
+        }
+    });
+
+    let session = Session {
+        debugger: debugger.clone(),
+        requests,
+        output,
+    };
+
+    loop {
+        match read_message(&mut reader) {
+            Ok(Some(message)) => {
+                if message["type"] == "request" && !session.handle(&message) {
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(err) => {
+                error!("Script debugger failed to read client message: {err}");
+                break;
+            }
+        }
+    }
+
+    // unblock any paused script before tearing the session down
+    let _ = session
+        .requests
+        .send(DebuggerRequest::Resume(ResumeMode::Continue));
+    debugger.detach();
+    let _ = event_thread.join();
+}
+
+struct Output {
+    writer: Mutex<Box<dyn Write + Send>>,
+    seq: AtomicI64,
+}
+
+impl Output {
+    fn send(&self, mut message: Value) {
+        message["seq"] = self.seq.fetch_add(1, Ordering::SeqCst).into();
+        if let Err(err) = write_message(&mut *self.writer.lock(), &message) {
+            error!("Script debugger failed to write message: {err}");
+        }
+    }
+
+    fn event(&self, event: &str, body: Value) {
+        self.send(json!({
+            "type": "event",
+            "event": event,
+            "body": body,
+        }));
+    }
+
+    fn respond(&self, request: &Value, result: Result<Value, String>) {
+        let mut response = json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": result.is_ok(),
+        });
+        match result {
+            Ok(body) => response["body"] = body,
+            Err(message) => response["message"] = message.into(),
+        }
+        self.send(response);
+    }
+
+    fn forward(&self, event: DebuggerEvent) {
+        match event {
+            DebuggerEvent::Stopped {
+                reason,
+                location,
+                callback,
+            } => self.event(
+                "stopped",
+                json!({
+                    "reason": reason.as_str(),
+                    "description": format!("{callback} at {}:{}", location.source, location.line),
+                    "threadId": THREAD_ID,
+                    "allThreadsStopped": true,
+                }),
+            ),
+            DebuggerEvent::Continued => self.event(
+                "continued",
+                json!({
+                    "threadId": THREAD_ID,
+                    "allThreadsContinued": true,
+                }),
+            ),
+        }
+    }
+}
+
+struct Session {
+    debugger: ScriptDebugger,
+    requests: Sender<DebuggerRequest>,
+    output: Arc<Output>,
+}
+
+impl Session {
+    /// Handles a single request, returns false if the session should end
+    fn handle(&self, request: &Value) -> bool {
+        let arguments = &request["arguments"];
+        let result = match request["command"].as_str().unwrap_or_default() {
+            "initialize" => {
+                self.output.respond(
+                    request,
+                    Ok(json!({
+                        "supportsConfigurationDoneRequest": true,
+                    })),
+                );
+                self.output.event("initialized", Value::Null);
+                return true;
+            }
+            "disconnect" => {
+                self.output.respond(request, Ok(Value::Null));
+                return false;
+            }
+            "launch" | "attach" | "configurationDone" => Ok(Value::Null),
+            "setBreakpoints" => Ok(self.set_breakpoints(arguments)),
+            "threads" => Ok(json!({
+                "threads": [{ "id": THREAD_ID, "name": "scripts" }],
+            })),
+            "stackTrace" => self.stack_trace(),
+            "scopes" => self.scopes(arguments),
+            "variables" => self.variables(arguments),
+            "pause" => {
+                self.debugger.request_pause();
+                Ok(Value::Null)
+            }
+            "continue" => self
+                .resume(ResumeMode::Continue)
+                .map(|_| json!({ "allThreadsContinued": true })),
+            "next" => self.resume(ResumeMode::StepOver),
+            "stepIn" => self.resume(ResumeMode::StepIn),
+            "stepOut" => self.resume(ResumeMode::StepOut),
+            other => Err(format!("Unsupported request: {other}")),
+        };
+        self.output.respond(request, result);
+        true
+    }
+
+    /// Sends a request to the paused script and waits for the reply
+    fn paused_request<T>(
+        &self,
+        make_request: impl FnOnce(Sender<T>) -> DebuggerRequest,
+    ) -> Result<T, String> {
+        if !self.debugger.is_paused() {
+            return Err("No script is currently paused".to_owned());
+        }
+        let (reply, response) = channel();
+        self.requests
+            .send(make_request(reply))
+            .map_err(|_| "The debugger session has ended".to_owned())?;
+        response
+            .recv()
+            .map_err(|_| "The script resumed before answering".to_owned())
+    }
+
+    fn resume(&self, mode: ResumeMode) -> Result<Value, String> {
+        if self.debugger.is_paused() {
+            self.requests
+                .send(DebuggerRequest::Resume(mode))
+                .map_err(|_| "The debugger session has ended".to_owned())?;
+        }
+        Ok(Value::Null)
+    }
+
+    fn set_breakpoints(&self, arguments: &Value) -> Value {
+        let source = &arguments["source"];
+        let path = source["path"]
+            .as_str()
+            .or_else(|| source["name"].as_str())
+            .unwrap_or_default();
+        let lines = match arguments["breakpoints"].as_array() {
+            Some(breakpoints) => breakpoints
+                .iter()
+                .filter_map(|b| b["line"].as_u64())
+                .collect::<Vec<_>>(),
+            None => arguments["lines"]
+                .as_array()
+                .map(|lines| lines.iter().filter_map(Value::as_u64).collect())
+                .unwrap_or_default(),
+        };
+        let lines = lines.into_iter().map(|l| l as u32).collect::<Vec<_>>();
+        self.debugger.set_breakpoints(path, lines.iter().copied());
+
+        json!({
+            "breakpoints": lines
+                .iter()
+                .map(|line| json!({ "verified": true, "line": line }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn stack_trace(&self) -> Result<Value, String> {
+        let frames = self.paused_request(|reply| DebuggerRequest::StackTrace { reply })?;
+        Ok(json!({
+            "stackFrames": frames.iter().enumerate().map(stack_frame_json).collect::<Vec<_>>(),
+            "totalFrames": frames.len(),
+        }))
+    }
+
+    fn scopes(&self, arguments: &Value) -> Result<Value, String> {
+        let frame = arguments["frameId"].as_u64().unwrap_or_default() as usize;
+        let scopes = self.paused_request(|reply| DebuggerRequest::Scopes { frame, reply })?;
+        Ok(json!({
+            "scopes": scopes
+                .iter()
+                .map(|scope| json!({
+                    "name": scope.name,
+                    "variablesReference": scope.variables_reference,
+                    "expensive": false,
+                }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+
+    fn variables(&self, arguments: &Value) -> Result<Value, String> {
+        let reference = arguments["variablesReference"].as_u64().unwrap_or_default() as usize;
+        let variables =
+            self.paused_request(|reply| DebuggerRequest::Variables { reference, reply })?;
+        Ok(json!({
+            "variables": variables.iter().map(variable_json).collect::<Vec<_>>(),
+        }))
+    }
+}
+
+fn stack_frame_json((id, frame): (usize, &DebugStackFrame)) -> Value {
+    let mut value = json!({
+        "id": id,
+        "name": frame.name,
+        "line": frame.line,
+        "column": 1,
+    });
+    if let Some(source) = &frame.source {
+        value["source"] = json!({
+            "name": source.rsplit('/').next().unwrap_or(source),
+            "path": source,
+        });
+    }
+    value
+}
+
+fn variable_json(variable: &DebugVariable) -> Value {
+    let mut value = json!({
+        "name": variable.name,
+        "value": variable.value,
+        "variablesReference": variable.variables_reference,
+    });
+    if let Some(type_name) = &variable.type_name {
+        value["type"] = type_name.as_str().into();
+    }
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn framed(messages: &[Value]) -> Vec<u8> {
+        let mut buffer = Vec::default();
+        for message in messages {
+            write_message(&mut buffer, message).unwrap();
+        }
+        buffer
+    }
+
+    fn read_all(bytes: Vec<u8>) -> Vec<Value> {
+        let mut reader = Cursor::new(bytes);
+        let mut messages = Vec::default();
+        while let Some(message) = read_message(&mut reader).unwrap() {
+            messages.push(message);
+        }
+        messages
+    }
+
+    #[test]
+    fn messages_round_trip() {
+        let messages = vec![
+            json!({ "seq": 1, "type": "request" }),
+            json!({ "a": [1, 2] }),
+        ];
+        assert_eq!(read_all(framed(&messages)), messages);
+    }
+
+    #[test]
+    fn session_handles_configuration_requests() {
+        let debugger = ScriptDebugger::default();
+        let input = framed(&[
+            json!({ "seq": 1, "type": "request", "command": "initialize", "arguments": {} }),
+            json!({
+                "seq": 2,
+                "type": "request",
+                "command": "setBreakpoints",
+                "arguments": {
+                    "source": { "path": "/home/me/game/assets/scripts/game.lua" },
+                    "breakpoints": [{ "line": 4 }],
+                },
+            }),
+            json!({ "seq": 3, "type": "request", "command": "stackTrace", "arguments": {} }),
+            json!({ "seq": 4, "type": "request", "command": "disconnect" }),
+        ]);
+        let output = SharedBuffer::default();
+
+        run_session(
+            debugger.clone(),
+            Cursor::new(input),
+            Box::new(output.clone()),
+        );
+
+        let messages = read_all(output.0.lock().clone());
+        let commands = messages
+            .iter()
+            .map(|m| {
+                m["command"]
+                    .as_str()
+                    .or_else(|| m["event"].as_str())
+                    .unwrap_or_default()
+                    .to_owned()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            commands,
+            vec![
+                "initialize",
+                "initialized",
+                "setBreakpoints",
+                "stackTrace",
+                "disconnect"
+            ]
+        );
+        assert_eq!(messages[2]["body"]["breakpoints"][0]["line"], 4);
+        // nothing is paused, so the stack cannot be inspected
+        assert_eq!(messages[3]["success"], false);
+        // the session cleans up after itself
+        assert!(!debugger.is_attached());
+        assert!(!debugger.has_breakpoint("scripts/game.lua", 4));
+    }
+}
@@ -0,0 +1,831 @@
+//! An interactive, language agnostic script debugger.
+//!
+//! The [`ScriptDebugger`] resource keeps track of breakpoints and stepping state, language integrations report
+//! every line they execute via [`ScriptDebugger::on_line`], and a front-end (like the [`dap`] server) drives the debugger
+//! via a [`DebuggerClient`].
+//!
+//! Only script calls issued from the callback event handler are debuggable, when a breakpoint is hit the calling thread
+//! is blocked inside the language hook until the client resumes execution.
+use std::{
+    cell::RefCell,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{Receiver, Sender, channel},
+    },
+};
+
+use bevy_app::Plugin;
+use bevy_ecs::resource::Resource;
+use bevy_log::error;
+use bevy_mod_scripting_bindings::{ReferencePart, ReflectReference, ScriptValue};
+use bevy_mod_scripting_display::WithTypeInfo;
+use bevy_mod_scripting_script::ScriptAttachment;
+use bevy_mod_scripting_world::{ThreadWorldContainer, WorldGuard};
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::{ReflectRef, enums::VariantType};
+use parking_lot::{Mutex, RwLock};
+
+use crate::event::CallbackLabel;
+
+pub mod dap;
+
+thread_local! {
+    static ACTIVE_CALL: RefCell<Option<ActiveCall>> = const { RefCell::new(None) };
+}
+
+/// The script call currently executing on this thread
+#[derive(Clone)]
+struct ActiveCall {
+    debugger: ScriptDebugger,
+    attachment: ScriptAttachment,
+    label: CallbackLabel,
+}
+
+/// How the debugger should behave when the next line is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepMode {
+    /// Run until a breakpoint is hit
+    #[default]
+    Continue,
+    /// Stop at the next line executed by any script
+    Pause,
+    /// Stop at the next line, including lines inside called functions
+    StepIn,
+    /// Stop at the next line at or above the given call depth
+    StepOver {
+        /// The call depth at which the step was requested
+        depth: usize,
+    },
+    /// Stop at the next line above the given call depth
+    StepOut {
+        /// The call depth at which the step was requested
+        depth: usize,
+    },
+}
+
+/// The way a client wants to resume execution after a stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeMode {
+    /// Run until the next breakpoint
+    Continue,
+    /// Step into the next line
+    StepIn,
+    /// Step over the current line
+    StepOver,
+    /// Step out of the current function
+    StepOut,
+}
+
+/// The reason execution was stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// A breakpoint was hit
+    Breakpoint,
+    /// A step request completed
+    Step,
+    /// The client requested a pause
+    Pause,
+}
+
+impl StopReason {
+    /// The name of the stop reason as used by the debug adapter protocol
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StopReason::Breakpoint => "breakpoint",
+            StopReason::Step => "step",
+            StopReason::Pause => "pause",
+        }
+    }
+}
+
+/// A location reached by a script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugLocation {
+    /// The source of the script, usually its asset path
+    pub source: String,
+    /// The 1-based line number
+    pub line: u32,
+    /// The depth of the script call stack, with the entry callback at depth 1
+    pub depth: usize,
+}
+
+/// A single frame in the script call stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugStackFrame {
+    /// The name of the function executing in this frame
+    pub name: String,
+    /// The source of the function, defaults to the source of the stopped location if not known
+    pub source: Option<String>,
+    /// The 1-based line number the frame is at
+    pub line: u32,
+}
+
+/// A named group of variables visible from a stack frame.
+#[derive(Debug, Clone)]
+pub struct DebugScope {
+    /// The name of the scope, i.e. `Locals` or `Globals`
+    pub name: String,
+    /// The variables in the scope
+    pub variables: Vec<(String, ScriptValue)>,
+}
+
+/// A variable as displayed to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugVariable {
+    /// The name of the variable
+    pub name: String,
+    /// The displayed value of the variable
+    pub value: String,
+    /// The type of the variable if known
+    pub type_name: Option<String>,
+    /// If non-zero, the variable can be expanded by issuing a [`DebuggerRequest::Variables`] request with this reference
+    pub variables_reference: usize,
+}
+
+/// Implemented by language integrations to expose the state of a paused script.
+pub trait ScriptInspector {
+    /// Returns the call stack, with the innermost frame first
+    fn stack_frames(&self) -> Vec<DebugStackFrame>;
+
+    /// Returns the scopes visible from the given frame
+    fn scopes(&self, frame: usize) -> Vec<DebugScope>;
+}
+
+/// Requests a client can send to a paused script.
+#[derive(Debug)]
+pub enum DebuggerRequest {
+    /// Retrieve the call stack of the paused script
+    StackTrace {
+        /// Where to send the frames
+        reply: Sender<Vec<DebugStackFrame>>,
+    },
+    /// Retrieve the scopes of the given frame, each scope can be expanded via [`DebuggerRequest::Variables`]
+    Scopes {
+        /// The index of the frame as returned by [`DebuggerRequest::StackTrace`]
+        frame: usize,
+        /// Where to send the scopes
+        reply: Sender<Vec<DebugVariable>>,
+    },
+    /// Expand a variable reference
+    Variables {
+        /// The reference to expand
+        reference: usize,
+        /// Where to send the child variables
+        reply: Sender<Vec<DebugVariable>>,
+    },
+    /// Resume execution
+    Resume(ResumeMode),
+}
+
+/// Events sent by the debugger to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebuggerEvent {
+    /// A script stopped executing
+    Stopped {
+        /// Why the script was stopped
+        reason: StopReason,
+        /// Where the script was stopped
+        location: DebugLocation,
+        /// The callback the script was executing
+        callback: String,
+    },
+    /// A stopped script resumed execution
+    Continued,
+}
+
+/// The script side of a debugger session
+struct SessionChannels {
+    requests: Receiver<DebuggerRequest>,
+    events: Sender<DebuggerEvent>,
+}
+
+#[derive(Default)]
+struct DebuggerInner {
+    attached: AtomicBool,
+    paused: AtomicBool,
+    /// breakpoint lines keyed by the source path as given by the client
+    breakpoints: RwLock<HashMap<String, HashSet<u32>>>,
+    step: Mutex<StepMode>,
+    session: Mutex<Option<SessionChannels>>,
+}
+
+/// The client side of a debugger session, created via [`ScriptDebugger::attach`].
+pub struct DebuggerClient {
+    /// The debugger this client is attached to
+    pub debugger: ScriptDebugger,
+    /// Send requests to a paused script
+    pub requests: Sender<DebuggerRequest>,
+    /// Receive events from scripts
+    pub events: Receiver<DebuggerEvent>,
+}
+
+/// The script debugger resource.
+///
+/// Cloning the debugger is cheap and all clones share the same state.
+#[derive(Resource, Clone, Default)]
+pub struct ScriptDebugger {
+    inner: Arc<DebuggerInner>,
+}
+
+impl ScriptDebugger {
+    /// Attaches a new client to the debugger, replacing any previous session.
+    pub fn attach(&self) -> DebuggerClient {
+        let (request_sender, requests) = channel();
+        let (events, event_receiver) = channel();
+        *self.inner.session.lock() = Some(SessionChannels { requests, events });
+        *self.inner.step.lock() = StepMode::Continue;
+        self.inner.attached.store(true, Ordering::SeqCst);
+        DebuggerClient {
+            debugger: self.clone(),
+            requests: request_sender,
+            events: event_receiver,
+        }
+    }
+
+    /// Detaches the current client, clearing all breakpoints.
+    ///
+    /// A paused script must be resumed before detaching, otherwise this call blocks until it is.
+    pub fn detach(&self) {
+        self.inner.attached.store(false, Ordering::SeqCst);
+        *self.inner.session.lock() = None;
+        *self.inner.step.lock() = StepMode::Continue;
+        self.inner.breakpoints.write().clear();
+    }
+
+    /// Returns true if a client is attached
+    pub fn is_attached(&self) -> bool {
+        self.inner.attached.load(Ordering::SeqCst)
+    }
+
+    /// Returns true if a script is currently stopped
+    pub fn is_paused(&self) -> bool {
+        self.inner.paused.load(Ordering::SeqCst)
+    }
+
+    /// Replaces the breakpoints for the given source with the given lines.
+    ///
+    /// The source can be either the asset path of the script or any path ending with it,
+    /// for example an absolute path to the script inside the assets folder.
+    /// Paths containing an `assets` folder only match the asset path relative to that folder.
+    pub fn set_breakpoints(&self, source: impl Into<String>, lines: impl IntoIterator<Item = u32>) {
+        let source = normalize_source(&source.into());
+        let lines = lines.into_iter().collect::<HashSet<_>>();
+        let mut breakpoints = self.inner.breakpoints.write();
+        if lines.is_empty() {
+            breakpoints.remove(&source);
+        } else {
+            breakpoints.insert(source, lines);
+        }
+    }
+
+    /// Returns true if a breakpoint is set at the given script source and line
+    pub fn has_breakpoint(&self, source: &str, line: u32) -> bool {
+        let source = normalize_source(source);
+        self.inner
+            .breakpoints
+            .read()
+            .iter()
+            .any(|(path, lines)| lines.contains(&line) && source_matches(path, &source))
+    }
+
+    /// Requests that the next line executed by any debuggable script call stops.
+    pub fn request_pause(&self) {
+        *self.inner.step.lock() = StepMode::Pause;
+    }
+
+    /// The current stepping state
+    pub fn step_mode(&self) -> StepMode {
+        *self.inner.step.lock()
+    }
+
+    /// Decides if execution should stop at the given location.
+    pub fn should_stop(&self, location: &DebugLocation) -> Option<StopReason> {
+        if !self.is_attached() {
+            return None;
+        }
+        let stepped = match self.step_mode() {
+            StepMode::Continue => false,
+            StepMode::Pause => return Some(StopReason::Pause),
+            StepMode::StepIn => true,
+            StepMode::StepOver { depth } => location.depth <= depth,
+            StepMode::StepOut { depth } => location.depth < depth,
+        };
+        if stepped {
+            Some(StopReason::Step)
+        } else {
+            self.has_breakpoint(&location.source, location.line)
+                .then_some(StopReason::Breakpoint)
+        }
+    }
+
+    /// Marks the start of a debuggable script call on this thread, the call ends when the returned guard is dropped.
+    ///
+    /// Called by the event handler around each callback it dispatches.
+    pub fn enter_call(
+        &self,
+        attachment: &ScriptAttachment,
+        label: &CallbackLabel,
+    ) -> ActiveCallGuard {
+        let previous = ACTIVE_CALL.with(|active| {
+            active.borrow_mut().replace(ActiveCall {
+                debugger: self.clone(),
+                attachment: attachment.clone(),
+                label: label.clone(),
+            })
+        });
+        ActiveCallGuard { previous }
+    }
+
+    /// Returns true if a debuggable script call with an attached client is executing on this thread.
+    ///
+    /// Language hooks can use this as a cheap check before gathering any line information.
+    pub fn is_debugging_current_call() -> bool {
+        ACTIVE_CALL.with(|active| {
+            active
+                .borrow()
+                .as_ref()
+                .is_some_and(|call| call.debugger.is_attached())
+        })
+    }
+
+    /// Called by language integrations whenever the current script call reaches a new line.
+    ///
+    /// If the location should stop, blocks the current thread until the client resumes execution.
+    /// The source defaults to the asset path of the script being called if not provided.
+    pub fn on_line(source: Option<&str>, line: u32, depth: usize, inspector: &dyn ScriptInspector) {
+        let Some(call) = ACTIVE_CALL.with(|active| active.borrow().clone()) else {
+            return;
+        };
+        let location = DebugLocation {
+            source: source
+                .map(ToOwned::to_owned)
+                .unwrap_or_else(|| attachment_source(&call.attachment)),
+            line,
+            depth,
+        };
+        if let Some(reason) = call.debugger.should_stop(&location) {
+            call.debugger
+                .pause(reason, location, call.label.to_string(), inspector);
+        }
+    }
+
+    /// Blocks until the client resumes execution, answering any inspection requests in the meantime.
+    fn pause(
+        &self,
+        reason: StopReason,
+        location: DebugLocation,
+        callback: String,
+        inspector: &dyn ScriptInspector,
+    ) {
+        let mut session = self.inner.session.lock();
+        let Some(channels) = session.as_ref() else {
+            return;
+        };
+
+        if channels
+            .events
+            .send(DebuggerEvent::Stopped {
+                reason,
+                location: location.clone(),
+                callback,
+            })
+            .is_err()
+        {
+            return;
+        }
+
+        self.inner.paused.store(true, Ordering::SeqCst);
+        let world = ThreadWorldContainer.try_get_context().ok().map(|c| c.world);
+        let mut variables = VariableStore::default();
+        let resume = loop {
+            match channels.requests.recv() {
+                Ok(DebuggerRequest::StackTrace { reply }) => {
+                    let mut frames = inspector.stack_frames();
+                    for frame in frames.iter_mut().filter(|f| f.source.is_none()) {
+                        frame.source = Some(location.source.clone());
+                    }
+                    let _ = reply.send(frames);
+                }
+                Ok(DebuggerRequest::Scopes { frame, reply }) => {
+                    let _ = reply.send(variables.scopes(inspector.scopes(frame)));
+                }
+                Ok(DebuggerRequest::Variables { reference, reply }) => {
+                    let _ = reply.send(variables.expand(reference, world.as_ref()));
+                }
+                Ok(DebuggerRequest::Resume(mode)) => break Some(mode),
+                Err(_) => break None,
+            }
+        };
+        self.inner.paused.store(false, Ordering::SeqCst);
+
+        match resume {
+            Some(mode) => {
+                *self.inner.step.lock() = match mode {
+                    ResumeMode::Continue => StepMode::Continue,
+                    ResumeMode::StepIn => StepMode::StepIn,
+                    ResumeMode::StepOver => StepMode::StepOver {
+                        depth: location.depth,
+                    },
+                    ResumeMode::StepOut => StepMode::StepOut {
+                        depth: location.depth,
+                    },
+                };
+                let _ = channels.events.send(DebuggerEvent::Continued);
+            }
+            None => {
+                // the client went away without detaching
+                *session = None;
+                self.inner.attached.store(false, Ordering::SeqCst);
+                *self.inner.step.lock() = StepMode::Continue;
+            }
+        }
+    }
+}
+
+/// Ends the debuggable script call when dropped, see [`ScriptDebugger::enter_call`].
+pub struct ActiveCallGuard {
+    previous: Option<ActiveCall>,
+}
+
+impl Drop for ActiveCallGuard {
+    fn drop(&mut self) {
+        let ended = ACTIVE_CALL
+            .with(|active| std::mem::replace(&mut *active.borrow_mut(), self.previous.take()));
+        // stepping over or out of the end of a callback resumes normal execution
+        if let Some(call) = ended {
+            let mut step = call.debugger.inner.step.lock();
+            if matches!(*step, StepMode::StepOver { .. } | StepMode::StepOut { .. }) {
+                *step = StepMode::Continue;
+            }
+        }
+    }
+}
+
+fn attachment_source(attachment: &ScriptAttachment) -> String {
+    let script = attachment.script();
+    script
+        .path()
+        .map(|p| p.to_string())
+        .unwrap_or_else(|| format!("{:?}", script.id()))
+}
+
+fn normalize_source(source: &str) -> String {
+    source.replace('\\', "/")
+}
+
+/// Checks if a breakpoint source, which might be an absolute path, refers to the given script source.
+fn source_matches(breakpoint_source: &str, script_source: &str) -> bool {
+    if breakpoint_source == script_source {
+        return true;
+    }
+    match breakpoint_source.rsplit_once("/assets/") {
+        Some((_, asset_path)) => asset_path == script_source,
+        None => breakpoint_source
+            .strip_suffix(script_source)
+            .is_some_and(|prefix| prefix.ends_with('/')),
+    }
+}
+
+enum VariableNode {
+    Scope(Vec<(String, ScriptValue)>),
+    Value(ScriptValue),
+}
+
+/// Keeps track of expandable variables for the duration of a single stop.
+#[derive(Default)]
+struct VariableStore {
+    nodes: Vec<VariableNode>,
+}
+
+impl VariableStore {
+    fn push(&mut self, node: VariableNode) -> usize {
+        self.nodes.push(node);
+        self.nodes.len()
+    }
+
+    fn scopes(&mut self, scopes: Vec<DebugScope>) -> Vec<DebugVariable> {
+        scopes
+            .into_iter()
+            .map(|scope| DebugVariable {
+                name: scope.name,
+                value: String::new(),
+                type_name: None,
+                variables_reference: self.push(VariableNode::Scope(scope.variables)),
+            })
+            .collect()
+    }
+
+    fn expand(&mut self, reference: usize, world: Option<&WorldGuard>) -> Vec<DebugVariable> {
+        let children = match reference.checked_sub(1).and_then(|i| self.nodes.get(i)) {
+            Some(VariableNode::Scope(variables)) => variables.clone(),
+            Some(VariableNode::Value(value)) => value_children(value, world),
+            None => return Vec::default(),
+        };
+        children
+            .into_iter()
+            .map(|(name, value)| self.describe(name, value, world))
+            .collect()
+    }
+
+    fn describe(
+        &mut self,
+        name: String,
+        value: ScriptValue,
+        world: Option<&WorldGuard>,
+    ) -> DebugVariable {
+        let (value_string, type_name) = match world {
+            Some(world) => (
+                WithTypeInfo::new_with_info(&value, world).to_string(),
+                match &value {
+                    ScriptValue::Reference(r) => r
+                        .with_reflect(world.clone(), |v| v.reflect_type_path().to_owned())
+                        .ok(),
+                    v => Some(v.type_name()),
+                },
+            ),
+            None => (
+                WithTypeInfo::new(&value).to_string(),
+                Some(value.type_name()),
+            ),
+        };
+        let expandable = match &value {
+            ScriptValue::List(l) => !l.is_empty(),
+            ScriptValue::Map(m) => !m.is_empty(),
+            ScriptValue::Tuple(t) => !t.0.is_empty(),
+            ScriptValue::Reference(_) => world.is_some(),
+            _ => false,
+        };
+        DebugVariable {
+            name,
+            value: value_string,
+            type_name,
+            variables_reference: if expandable {
+                self.push(VariableNode::Value(value))
+            } else {
+                0
+            },
+        }
+    }
+}
+
+fn value_children(value: &ScriptValue, world: Option<&WorldGuard>) -> Vec<(String, ScriptValue)> {
+    match value {
+        ScriptValue::List(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("[{i}]"), v.clone()))
+            .collect(),
+        ScriptValue::Tuple(items) => items
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), v.clone()))
+            .collect(),
+        ScriptValue::Map(map) => {
+            let mut entries = map
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            entries
+        }
+        ScriptValue::Reference(reference) => match world {
+            Some(world) => reflect_children(reference, world.clone()).unwrap_or_else(|err| {
+                error!(
+                    "Could not expand reference in debugger: {}",
+                    WithTypeInfo::new_with_info(&err, world)
+                );
+                Vec::default()
+            }),
+            None => Vec::default(),
+        },
+        _ => Vec::default(),
+    }
+}
+
+/// Creates references to each of the reflected children of the value behind the reference.
+fn reflect_children(
+    reference: &ReflectReference,
+    world: WorldGuard,
+) -> Result<Vec<(String, ScriptValue)>, bevy_mod_scripting_bindings::InteropError> {
+    let parts = reference.with_reflect(world, |value| {
+        let indexed = |len: usize| {
+            (0..len)
+                .map(|i| {
+                    (
+                        format!("[{i}]"),
+                        ReferencePart::IntegerAccess(i as i64, false),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        match value.reflect_ref() {
+            ReflectRef::Struct(s) => (0..s.field_len())
+                .filter_map(|i| s.name_at(i))
+                .map(|name| {
+                    (
+                        name.to_owned(),
+                        ReferencePart::StringAccess(name.to_owned().into()),
+                    )
+                })
+                .collect(),
+            ReflectRef::TupleStruct(t) => indexed(t.field_len()),
+            ReflectRef::Tuple(t) => indexed(t.field_len()),
+            ReflectRef::List(l) => indexed(l.len()),
+            ReflectRef::Array(a) => indexed(a.len()),
+            ReflectRef::Enum(e) => match e.variant_type() {
+                VariantType::Struct => (0..e.field_len())
+                    .filter_map(|i| e.name_at(i))
+                    .map(|name| {
+                        (
+                            name.to_owned(),
+                            ReferencePart::StringAccess(name.to_owned().into()),
+                        )
+                    })
+                    .collect(),
+                VariantType::Tuple => indexed(e.field_len()),
+                VariantType::Unit => Vec::default(),
+            },
+            ReflectRef::Map(m) => m
+                .iter()
+                .filter_map(|(key, _)| {
+                    let part =
+                        ReferencePart::MapAccess(key.reflect_clone().ok()?.into_partial_reflect());
+                    Some((format!("[{key:?}]"), part))
+                })
+                .collect(),
+            _ => Vec::default(),
+        }
+    })?;
+
+    Ok(parts
+        .into_iter()
+        .map(|(name, part)| {
+            let mut child = reference.clone();
+            child.push_path(part);
+            (name, ScriptValue::Reference(child))
+        })
+        .collect())
+}
+
+/// The transport used to expose the debugger to external clients.
+#[derive(Debug, Clone, Default)]
+pub enum DebuggerTransport {
+    /// Do not start a server, clients are attached manually via [`ScriptDebugger::attach`]
+    #[default]
+    None,
+    /// Listen for debug adapter protocol clients on the given TCP address, i.e. `127.0.0.1:4711`
+    Tcp(String),
+    /// Speak the debug adapter protocol over the process's stdin and stdout
+    Stdio,
+}
+
+/// Adds the [`ScriptDebugger`] resource, enabling debug hooks in newly loaded script contexts.
+///
+/// Language hooks are only installed in contexts loaded while this plugin is present, and add some overhead to every line executed.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptDebuggerPlugin {
+    /// The transport to expose the debugger over
+    pub transport: DebuggerTransport,
+}
+
+impl ScriptDebuggerPlugin {
+    /// Creates a plugin listening for debug adapter protocol clients on the given TCP address
+    pub fn tcp(address: impl Into<String>) -> Self {
+        Self {
+            transport: DebuggerTransport::Tcp(address.into()),
+        }
+    }
+
+    /// Creates a plugin speaking the debug adapter protocol over stdin and stdout
+    pub fn stdio() -> Self {
+        Self {
+            transport: DebuggerTransport::Stdio,
+        }
+    }
+}
+
+impl Plugin for ScriptDebuggerPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        let debugger = app
+            .world_mut()
+            .get_resource_or_init::<ScriptDebugger>()
+            .clone();
+
+        match &self.transport {
+            DebuggerTransport::None => {}
+            DebuggerTransport::Tcp(address) => {
+                if let Err(err) = dap::listen_tcp(debugger, address.as_str()) {
+                    error!("Failed to start script debugger on {address}: {err}");
+                }
+            }
+            DebuggerTransport::Stdio => {
+                dap::serve_stdio(debugger);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn location(source: &str, line: u32, depth: usize) -> DebugLocation {
+        DebugLocation {
+            source: source.to_owned(),
+            line,
+            depth,
+        }
+    }
+
+    #[test]
+    fn breakpoints_match_asset_paths_and_absolute_paths() {
+        let debugger = ScriptDebugger::default();
+        debugger.set_breakpoints("C:\\game\\assets\\scripts\\game.lua", [3]);
+        debugger.set_breakpoints("scripts/other.lua", [5, 6]);
+
+        assert!(debugger.has_breakpoint("scripts/game.lua", 3));
+        assert!(!debugger.has_breakpoint("scripts/game.lua", 4));
+        assert!(!debugger.has_breakpoint("game.lua", 3));
+        assert!(!debugger.has_breakpoint("ts/game.lua", 3));
+        assert!(debugger.has_breakpoint("scripts/other.lua", 6));
+
+        debugger.set_breakpoints("scripts/other.lua", []);
+        assert!(!debugger.has_breakpoint("scripts/other.lua", 6));
+    }
+
+    #[test]
+    fn does_not_stop_when_detached() {
+        let debugger = ScriptDebugger::default();
+        debugger.set_breakpoints("script.lua", [1]);
+        assert_eq!(debugger.should_stop(&location("script.lua", 1, 1)), None);
+
+        let _client = debugger.attach();
+        assert_eq!(
+            debugger.should_stop(&location("script.lua", 1, 1)),
+            Some(StopReason::Breakpoint)
+        );
+    }
+
+    #[test]
+    fn stepping_respects_call_depth() {
+        let debugger = ScriptDebugger::default();
+        let _client = debugger.attach();
+
+        *debugger.inner.step.lock() = StepMode::StepOver { depth: 2 };
+        assert_eq!(debugger.should_stop(&location("s", 1, 3)), None);
+        assert_eq!(
+            debugger.should_stop(&location("s", 1, 2)),
+            Some(StopReason::Step)
+        );
+
+        *debugger.inner.step.lock() = StepMode::StepOut { depth: 2 };
+        assert_eq!(debugger.should_stop(&location("s", 1, 2)), None);
+        assert_eq!(
+            debugger.should_stop(&location("s", 1, 1)),
+            Some(StopReason::Step)
+        );
+
+        *debugger.inner.step.lock() = StepMode::StepIn;
+        assert_eq!(
+            debugger.should_stop(&location("s", 1, 5)),
+            Some(StopReason::Step)
+        );
+
+        debugger.request_pause();
+        assert_eq!(
+            debugger.should_stop(&location("s", 1, 5)),
+            Some(StopReason::Pause)
+        );
+    }
+
+    #[test]
+    fn variables_expand_containers() {
+        let mut store = VariableStore::default();
+        let scopes = store.scopes(vec![DebugScope {
+            name: "Locals".into(),
+            variables: vec![
+                ("a".into(), ScriptValue::Integer(1)),
+                (
+                    "b".into(),
+                    ScriptValue::List(vec![ScriptValue::Bool(true)].into()),
+                ),
+            ],
+        }]);
+        assert_eq!(scopes.len(), 1);
+
+        let locals = store.expand(scopes[0].variables_reference, None);
+        assert_eq!(locals[0].name, "a");
+        assert_eq!(locals[0].value, "1");
+        assert_eq!(locals[0].variables_reference, 0);
+        assert_ne!(locals[1].variables_reference, 0);
+
+        let list = store.expand(locals[1].variables_reference, None);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].name, "[0]");
+        assert_eq!(list[0].value, "true");
+
+        assert!(store.expand(999, None).is_empty());
+    }
+}
@@ -1,6 +1,8 @@
 use bevy_ecs::world::World;
+use bevy_mod_scripting_bindings::ReflectScriptAttributes;
 use bevy_mod_scripting_derive::{
-    ArgMeta, FromScript, GetTypeDependencies, IntoScript, TypedThrough, script_bindings,
+    ArgMeta, FromScript, GetTypeDependencies, IntoScript, ScriptAttributes, ScriptEvent,
+    TypedThrough, script_bindings,
 };
 use bevy_reflect::{Reflect, TypeRegistry, Typed};
 
@@ -13,6 +15,54 @@ use bevy_reflect::{Reflect, TypeRegistry, Typed};
 #[arg_meta(bms_bindings_path = "bevy_mod_scripting_bindings")]
 pub struct MyThing(usize);
 
+/// Fired when a thing is hit
+#[derive(Clone, ScriptEvent)]
+#[script_event(
+    bms_core_path = "crate",
+    bms_bindings_path = "bevy_mod_scripting_bindings"
+)]
+pub struct OnThingHit {
+    /// the thing that was hit
+    #[script_event(reflect)]
+    thing: MyThing,
+    /// the damage dealt
+    damage: u32,
+    #[script_event(skip)]
+    _ignored: bool,
+    #[script_event(recipients)]
+    recipients: crate::event::Recipients,
+}
+
+#[derive(Reflect, ScriptAttributes)]
+#[reflect(ScriptAttributes)]
+#[script(
+    bms_bindings_path = "bevy_mod_scripting_bindings",
+    rename = "Player",
+    deprecated = "use Other instead"
+)]
+pub struct PlayerState {
+    pub health: u32,
+    #[script(hidden)]
+    pub internal_id: u32,
+    #[script(rename = "name", deprecated)]
+    pub display_name: String,
+}
+
+#[derive(Reflect, ScriptAttributes)]
+#[reflect(ScriptAttributes)]
+#[script(bms_bindings_path = "bevy_mod_scripting_bindings")]
+pub enum PlayerAction {
+    Idle,
+    #[script(hidden)]
+    Debug,
+    #[script(rename = "Attack")]
+    Hit(u32, #[script(hidden)] u32),
+    Move {
+        #[script(deprecated = "use direction instead")]
+        speed: f32,
+    },
+}
+
 #[script_bindings(remote, bms_bindings_path = "bevy_mod_scripting_bindings")]
 impl MyThing {
     pub fn test(thing: MyThing) -> MyThing {
@@ -24,13 +74,18 @@ mod tests {
 
     use bevy_ecs::world::World;
     use bevy_mod_scripting_bindings::{
-        AppScriptFunctionRegistry, FunctionCallContext, GetTypeDependencies, ReflectReference,
-        ThroughTypeInfo, TypedThrough, WorldExtensions,
+        AppScriptFunctionRegistry, FunctionCallContext, GetScriptAttributes, GetTypeDependencies,
+        ReflectReference, ReflectScriptAttributes, ScriptAttributes, ThroughTypeInfo, TypedThrough,
+        WorldExtensions,
     };
     use bevy_mod_scripting_world::WorldAccessGuard;
     use bevy_reflect::TypeRegistry;
 
-    use crate::derive_tests::{MyThing, register_functions};
+    use crate::{
+        derive_tests::{MyThing, OnThingHit, PlayerAction, PlayerState, register_functions},
+        event::{IntoCallbackLabel, Recipients},
+        script_event::ScriptEvent,
+    };
 
     #[test]
     pub fn typed_through_represents_self() {
@@ -86,4 +141,123 @@ mod tests {
             assert_eq!(out.0, 42)
         });
     }
+
+    #[test]
+    pub fn script_event_converts_fields_into_callback_args() {
+        assert_eq!(OnThingHit::into_callback_label().as_ref(), "on_thing_hit");
+
+        let info = OnThingHit::callback_info();
+        assert_eq!(
+            info.documentation.as_deref(),
+            Some("Fired when a thing is hit")
+        );
+        let arguments = info
+            .arguments
+            .iter()
+            .map(|arg| (arg.name.as_ref(), arg.documentation.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            arguments,
+            vec![
+                ("thing", Some("the thing that was hit")),
+                ("damage", Some("the damage dealt"))
+            ]
+        );
+
+        let event = OnThingHit {
+            thing: MyThing(1),
+            damage: 5,
+            _ignored: true,
+            recipients: Recipients::AllContexts,
+        };
+        assert!(matches!(event.recipients(), Recipients::AllContexts));
+
+        let mut world = World::new();
+        let cache = WorldAccessGuard::setup_cache(&world, Default::default());
+        WorldAccessGuard::with_static_guard(&mut world, cache, |world| {
+            let args = event.into_script_args(world.clone()).unwrap();
+            assert_eq!(args.len(), 2);
+            assert!(matches!(
+                args[0],
+                bevy_mod_scripting_bindings::ScriptValue::Reference(_)
+            ));
+            assert!(matches!(
+                args[1],
+                bevy_mod_scripting_bindings::ScriptValue::Integer(5)
+            ));
+        });
+    }
+
+    #[test]
+    pub fn script_attributes_cover_struct_fields() {
+        assert_eq!(
+            PlayerState::script_attributes(),
+            ReflectScriptAttributes {
+                type_attributes: ScriptAttributes {
+                    hidden: false,
+                    rename: Some("Player"),
+                    deprecated: Some("use Other instead"),
+                },
+                members: vec![
+                    (
+                        "internal_id",
+                        ScriptAttributes {
+                            hidden: true,
+                            ..Default::default()
+                        }
+                    ),
+                    (
+                        "display_name",
+                        ScriptAttributes {
+                            rename: Some("name"),
+                            deprecated: Some(""),
+                            ..Default::default()
+                        }
+                    ),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    pub fn script_attributes_cover_enum_variants_and_their_fields() {
+        let attributes = PlayerAction::script_attributes();
+        assert_eq!(attributes.type_attributes, ScriptAttributes::default());
+        assert_eq!(
+            attributes.members,
+            vec![
+                (
+                    "Debug",
+                    ScriptAttributes {
+                        hidden: true,
+                        ..Default::default()
+                    }
+                ),
+                (
+                    "Hit",
+                    ScriptAttributes {
+                        rename: Some("Attack"),
+                        ..Default::default()
+                    }
+                ),
+                (
+                    "Hit.1",
+                    ScriptAttributes {
+                        hidden: true,
+                        ..Default::default()
+                    }
+                ),
+                (
+                    "Move.speed",
+                    ScriptAttributes {
+                        deprecated: Some("use direction instead"),
+                        ..Default::default()
+                    }
+                ),
+            ]
+        );
+        // members without attributes fall back to the defaults
+        assert_eq!(attributes.member("Idle"), ScriptAttributes::default());
+        assert_eq!(attributes.member("Hit.0"), ScriptAttributes::default());
+    }
 }
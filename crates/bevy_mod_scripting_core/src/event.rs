@@ -9,8 +9,9 @@ use bevy_mod_scripting_script::ScriptAttachment;
 
 use crate::{
     IntoScriptPluginParams,
+    callback_registry::ScriptCallbackInfo,
     error::ScriptError,
-    script::{Context, ScriptContexts, ScriptId},
+    script::{Context, ScriptContexts, ScriptId, sort_recipients},
 };
 
 /// An error coming from a script
@@ -84,7 +85,10 @@ impl CallbackLabel {
 }
 
 #[macro_export]
-/// Creates a set of callback labels
+/// Creates a set of callback labels.
+///
+/// Each label gets a `callback_info` function describing the callback using its doc comments,
+/// which can be extended with arguments and return values and registered in the [`crate::callback_registry::AppScriptCallbackRegistry`].
 macro_rules! callback_labels {
     ($($(#[doc = $doc:expr])* $name:ident => $label:expr),* $(,)?) => {
 
@@ -97,6 +101,21 @@ macro_rules! callback_labels {
                 fn into_callback_label() -> $crate::event::CallbackLabel {
                     $label.into()
                 }
+
+                fn callback_info() -> $crate::callback_registry::ScriptCallbackInfo {
+                    let info = $crate::callback_registry::ScriptCallbackInfo::of::<Self>();
+                    let documentation: &[&str] = &[$($doc),*];
+                    if documentation.is_empty() {
+                        return info;
+                    }
+                    info.with_documentation(
+                        documentation
+                            .iter()
+                            .map(|line| line.trim())
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    )
+                }
             }
         )*
     };
@@ -116,6 +135,16 @@ callback_labels!(
 pub trait IntoCallbackLabel {
     /// Converts the type into a callback label
     fn into_callback_label() -> CallbackLabel;
+
+    /// Describes the callback for the purposes of documentation.
+    ///
+    /// Registered by the [`event_handler`](crate::handler::event_handler) for this label, unless the callback is documented already.
+    fn callback_info() -> ScriptCallbackInfo
+    where
+        Self: Sized,
+    {
+        ScriptCallbackInfo::of::<Self>()
+    }
 }
 
 impl<T: IntoCallbackLabel> From<T> for CallbackLabel {
@@ -153,6 +182,9 @@ impl std::fmt::Display for CallbackLabel {
 pub enum Recipients {
     /// The event needs to be handled by all scripts, if multiple scripts share a context, the event will be sent once per script in the context.
     /// Scripts currently loading or unloading will NOT receive this event after/before they are loaded/unloaded.
+    ///
+    /// Scripts receive the event in order of their [`crate::script::ScriptPriorities`], and can stop it from reaching
+    /// lower priority scripts by returning [`crate::script::StopPropagation`].
     AllScripts,
     /// The event is to be handled by all unique contexts, i.e. if two scripts share the same context, the event will be sent only once per the context.
     /// Contexts currently loading or unloading will NOT receive this event after/before they are loaded/unloaded.
@@ -166,10 +198,22 @@ pub enum Recipients {
 }
 
 impl Recipients {
-    /// Retrieves all the recipients of the event based on existing scripts
+    /// Retrieves all the recipients of the event based on existing scripts.
+    ///
+    /// Recipients are sorted by entity and then by script asset, see [`sort_recipients`].
     pub fn get_recipients<P: IntoScriptPluginParams>(
         &self,
         script_context: ScriptContexts<P>,
+    ) -> Vec<(ScriptAttachment, Context<P>)> {
+        let mut recipients = self.get_unsorted_recipients(script_context);
+        sort_recipients(&mut recipients);
+        recipients
+    }
+
+    /// Retrieves all the recipients of the event based on existing scripts, in no particular order.
+    pub(crate) fn get_unsorted_recipients<P: IntoScriptPluginParams>(
+        &self,
+        script_context: ScriptContexts<P>,
     ) -> Vec<(ScriptAttachment, Context<P>)> {
         let script_context = script_context.read();
         match self {
@@ -12,13 +12,16 @@ use bevy_mod_scripting_world::{WorldAccessGuard, WorldGuard};
 
 use crate::{
     IntoScriptPluginParams,
+    callback_registry::AppScriptCallbackRegistry,
     callbacks::ScriptCallbacks,
+    debugger::ScriptDebugger,
     error::ScriptError,
     event::{
         CallbackLabel, IntoCallbackLabel, Recipients, ScriptCallbackEvent,
         ScriptCallbackResponseEvent, ScriptErrorEvent,
     },
-    script::ScriptContexts,
+    profiler::ScriptProfiler,
+    script::{ScriptContexts, ScriptPriorities, StopPropagation},
 };
 use {
     bevy_ecs::{
@@ -83,17 +86,31 @@ impl<P: IntoScriptPluginParams> ScriptingHandler<P> for P {
 
 /// Passes events with the specified label to the script callback with the same name and runs the callback.
 ///
+/// On its first run the callback is documented in the [`AppScriptCallbackRegistry`], unless it is documented already.
+///
 /// If any of the resources required for the handler are missing, the system will log this issue and do nothing.
 #[allow(deprecated)]
 pub fn event_handler<L: IntoCallbackLabel, P: IntoScriptPluginParams>(
     world: &mut World,
-    state: &mut SystemState<Local<MessageCursor<ScriptCallbackEvent>>>,
+    state: &mut SystemState<(Local<MessageCursor<ScriptCallbackEvent>>, Local<bool>)>,
 ) -> bevy_ecs::error::Result {
     // we wrap the inner event handler, so that we can guarantee that the handler context is released statically
     {
+        let callback_registry = world
+            .get_resource_or_init::<AppScriptCallbackRegistry>()
+            .clone();
+        let (event_cursor, mut documented) = state.get_mut(world)?;
+        if !*documented {
+            callback_registry
+                .write()
+                .register_if_missing(L::callback_info());
+            *documented = true;
+        }
         let script_context = world.get_resource_or_init::<ScriptContexts<P>>().clone();
         let script_callbacks = world.get_resource_or_init::<ScriptCallbacks<P>>().clone();
-        let event_cursor = state.get_mut(world)?;
+        let debugger = world.get_resource::<ScriptDebugger>().cloned();
+        let profiler = world.get_resource::<ScriptProfiler>().cloned();
+        let priorities = world.get_resource_or_init::<ScriptPriorities>().clone();
         let cache = WorldAccessGuard::setup_cache(world, CurrentScriptAttachment::default());
         let guard = WorldAccessGuard::new_exclusive(world, cache);
         event_handler_inner::<P>(
@@ -101,6 +118,9 @@ pub fn event_handler<L: IntoCallbackLabel, P: IntoScriptPluginParams>(
             event_cursor,
             script_context,
             script_callbacks,
+            debugger,
+            profiler,
+            priorities,
             guard,
         );
         Ok(())
@@ -114,6 +134,9 @@ pub(crate) fn event_handler_inner<P: IntoScriptPluginParams>(
     mut event_cursor: Local<MessageCursor<ScriptCallbackEvent>>,
     script_context: ScriptContexts<P>,
     script_callbacks: ScriptCallbacks<P>,
+    debugger: Option<ScriptDebugger>,
+    profiler: Option<ScriptProfiler>,
+    priorities: ScriptPriorities,
     guard: WorldAccessGuard,
 ) {
     let mut errors = Vec::default();
@@ -142,7 +165,10 @@ pub(crate) fn event_handler_inner<P: IntoScriptPluginParams>(
     for event in events.into_iter().filter(|e| {
         e.label == callback_label && e.language.as_ref().is_none_or(|l| l == &P::LANGUAGE)
     }) {
-        let recipients = event.recipients.get_recipients(script_context.clone());
+        let mut recipients = event
+            .recipients
+            .get_unsorted_recipients(script_context.clone());
+        priorities.sort(&mut recipients);
         let highly_specific = matches!(
             event.recipients,
             Recipients::ScriptEntity(_, _) | Recipients::StaticScript(_)
@@ -167,6 +193,12 @@ pub(crate) fn event_handler_inner<P: IntoScriptPluginParams>(
             };
             let mut ctxt = ctxt.lock();
 
+            // scripts can only be paused by the debugger while it's tracking the call
+            let debugged_call = debugger
+                .as_ref()
+                .map(|debugger| debugger.enter_call(&attachment, &callback_label));
+            let started = profiler.as_ref().map(ScriptProfiler::start_call);
+
             let call_result = P::handle(
                 event.args.clone(),
                 &attachment,
@@ -183,6 +215,16 @@ pub(crate) fn event_handler_inner<P: IntoScriptPluginParams>(
                     .with_language(P::LANGUAGE)
             });
 
+            if let (Some(profiler), Some(started)) = (&profiler, started) {
+                profiler.record_callback(
+                    &attachment,
+                    &callback_label,
+                    started.elapsed(),
+                    call_result.is_err(),
+                );
+            }
+
+            drop(debugged_call);
             drop(ctxt);
 
             if event.trigger_response {
@@ -196,7 +238,13 @@ pub(crate) fn event_handler_inner<P: IntoScriptPluginParams>(
                     ),
                 );
             }
+            let stop_propagation = call_result
+                .as_ref()
+                .is_ok_and(|value| StopPropagation::is_sentinel(value, guard.clone()));
             collect_errors(call_result, &mut errors);
+            if stop_propagation {
+                break;
+            }
         }
     }
 
@@ -282,3 +330,120 @@ pub fn script_error_logger(
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use bevy_app::{App, Plugin, Update};
+    use bevy_asset::{AssetPlugin, Handle};
+    use bevy_ecs::entity::Entity;
+    use bevy_mod_scripting_bindings::{ReflectAllocator, ReflectReference};
+    use parking_lot::Mutex;
+    use test_utils::make_test_plugin;
+
+    use super::*;
+    use crate::{
+        BMSScriptingInfrastructurePlugin, callback_labels,
+        config::{GetPluginThreadConfig, ScriptingPluginConfiguration},
+    };
+
+    make_test_plugin!(crate);
+
+    struct OnTest;
+
+    impl IntoCallbackLabel for OnTest {
+        fn into_callback_label() -> CallbackLabel {
+            "on_test".into()
+        }
+    }
+
+    #[test]
+    fn stop_propagation_skips_lower_priority_recipients() {
+        let mut app = App::new();
+        app.add_plugins((
+            AssetPlugin::default(),
+            TestPlugin::default(),
+            BMSScriptingInfrastructurePlugin::default(),
+        ));
+        app.add_systems(Update, event_handler::<OnTest, TestPlugin>);
+        app.finish();
+        app.cleanup();
+
+        let world = app.world_mut();
+        let contexts = world.resource::<ScriptContexts<TestPlugin>>().clone();
+        let callbacks = world.resource::<ScriptCallbacks<TestPlugin>>().clone();
+        let priorities = world.resource::<ScriptPriorities>().clone();
+        let sentinel = ScriptValue::Reference(ReflectReference::new_allocated(
+            StopPropagation,
+            &mut ReflectAllocator::default(),
+        ));
+
+        let received = Arc::new(Mutex::new(Vec::default()));
+        for (script, priority) in [(1u128, 0), (2, 10), (3, 5)] {
+            let attachment = ScriptAttachment::StaticScript(Handle::Uuid(
+                uuid::Uuid::from_u128(script),
+                Default::default(),
+            ));
+            contexts
+                .write()
+                .insert(
+                    attachment.clone(),
+                    Arc::new(Mutex::new(TestContext::default())).into(),
+                )
+                .unwrap();
+            priorities.set(attachment.clone(), priority);
+
+            let received = received.clone();
+            let sentinel = sentinel.clone();
+            callbacks.callbacks.write().insert(
+                (attachment, OnTest::into_callback_label().to_string()),
+                Arc::new(move |_, _, _| {
+                    received.lock().push(script);
+                    Ok(if script == 3 {
+                        sentinel.clone()
+                    } else {
+                        ScriptValue::Unit
+                    })
+                }),
+            );
+        }
+
+        world.write_message(ScriptCallbackEvent::new_for_all_scripts(
+            OnTest::into_callback_label(),
+            vec![],
+        ));
+        app.update();
+
+        assert_eq!(*received.lock(), vec![2, 3]);
+    }
+
+    callback_labels!(
+        /// Fired whenever something documented happens
+        OnDocumented => "on_documented",
+    );
+
+    #[test]
+    fn event_handler_documents_its_callback() {
+        let mut app = App::new();
+        app.add_plugins((
+            AssetPlugin::default(),
+            TestPlugin::default(),
+            BMSScriptingInfrastructurePlugin::default(),
+        ));
+        app.add_systems(Update, event_handler::<OnDocumented, TestPlugin>);
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        let registry = app.world().resource::<AppScriptCallbackRegistry>().clone();
+        let registry = registry.read();
+        let info = registry
+            .get(&OnDocumented::into_callback_label())
+            .expect("callback should be documented by its handler");
+        assert_eq!(
+            info.documentation.as_deref(),
+            Some("Fired whenever something documented happens")
+        );
+    }
+}
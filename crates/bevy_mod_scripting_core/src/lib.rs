@@ -18,24 +18,37 @@ use bevy_ecs::{
     reflect::{AppTypeRegistry, ReflectComponent},
     schedule::SystemSet,
 };
-use bevy_log::error;
+use bevy_log::{error, warn};
 use bevy_mod_scripting_asset::{Language, LanguageExtensions, ScriptAsset, ScriptAssetLoader};
 
 use bevy_mod_scripting_bindings::{
-    AppReflectAllocator, AppScheduleRegistry, AppScriptFunctionRegistry,
-    DummyScriptFunctionRegistry, DynamicScriptComponentPlugin, MarkAsCore, ReflectReference,
-    ScriptTypeRegistration, ScriptValue, garbage_collector,
+    AppReflectAllocator, AppScheduleRegistry, AppScriptFunctionRegistry, AppScriptGlobalsRegistry,
+    BigInt, DummyScriptFunctionRegistry, DynamicScriptComponentPlugin, ErrorReturnMode,
+    ErrorReturnModes, IntegerOverflowPolicy, Json, MarkAsCore, ReflectReference, Ron, ScriptResult,
+    ScriptTypeRegistration, ScriptValue, V, WeakReflectReference, garbage_collector,
+    register_enum_variant_constructors,
 };
+use callback_registry::AppScriptCallbackRegistry;
+use console::EvalFn;
 use context::{Context, ContextInitializer, ContextPreHandlingInitializer};
-use event::{ScriptCallbackEvent, ScriptCallbackResponseEvent};
+use event::{
+    IntoCallbackLabel, OnScriptLoaded, OnScriptReloaded, OnScriptUnloaded, ScriptCallbackEvent,
+    ScriptCallbackResponseEvent,
+};
 use handler::HandlerFn;
 use runtime::{Runtime, RuntimeInitializer};
-use script::{ContextPolicy, ScriptComponent, ScriptContexts};
+use script::{
+    ContextPolicy, ScriptComponent, ScriptContexts, ScriptPriorities, ScriptPriority,
+    StopPropagation,
+};
 
+pub mod callback_registry;
 pub mod callbacks;
 pub mod commands;
 pub mod config;
+pub mod console;
 pub mod context;
+pub mod debugger;
 #[cfg(test)]
 mod derive_tests;
 
@@ -44,8 +57,10 @@ pub mod event;
 pub mod extractors;
 pub mod handler;
 pub mod pipeline;
+pub mod profiler;
 pub mod runtime;
 pub mod script;
+pub mod script_event;
 pub mod script_system;
 #[derive(SystemSet, Hash, Debug, Eq, PartialEq, Clone)]
 /// Labels for various BMS systems
@@ -82,6 +97,13 @@ pub trait IntoScriptPluginParams: 'static + GetPluginThreadConfig<Self> {
 
     /// Returns the context reloader function for the plugin
     fn context_reloader() -> ContextReloadFn<Self>;
+
+    /// Returns the evaluator function for the plugin, used to evaluate arbitrary code against existing contexts
+    ///
+    /// Defaults to [`console::unsupported_evaluator`], for languages which cannot evaluate code.
+    fn evaluator() -> EvalFn<Self> {
+        console::unsupported_evaluator::<Self>
+    }
 }
 
 /// Bevy plugin enabling scripting within the bevy mod scripting framework
@@ -109,6 +131,9 @@ pub struct ScriptingPlugin<P: IntoScriptPluginParams> {
 
     /// The settings customising the processing (loading, unloading etc.) pipeline for this plugin
     pub processing_pipeline_plugin: ScriptLoadingPipeline<P>,
+
+    /// Overrides how functions returning a `Result` surface errors to scripts of this language, see [`ErrorReturnModes`].
+    pub error_return_mode: Option<ErrorReturnMode>,
 }
 
 impl<P> std::fmt::Debug for ScriptingPlugin<P>
@@ -125,6 +150,7 @@ where
                 &self.context_pre_handling_initializers,
             )
             .field("emit_responses", &self.emit_responses)
+            .field("error_return_mode", &self.error_return_mode)
             .finish()
     }
 }
@@ -140,6 +166,7 @@ impl<P: IntoScriptPluginParams> Default for ScriptingPlugin<P> {
             context_pre_handling_initializers: Default::default(),
             emit_responses: false,
             processing_pipeline_plugin: Default::default(),
+            error_return_mode: None,
         }
     }
 }
@@ -170,6 +197,13 @@ impl<P: IntoScriptPluginParams> Plugin for ScriptingPlugin<P> {
 
         P::set_world_local_config(app.world().id(), config);
 
+        if let Some(mode) = self.error_return_mode {
+            app.world_mut()
+                .get_resource_or_init::<ErrorReturnModes>()
+                .per_language
+                .insert(P::LANGUAGE, mode);
+        }
+
         app.insert_resource(ScriptContexts::<P>::new(self.context_policy.clone()));
         app.init_resource::<ScriptComponentsChangeCache>();
         app.register_asset_loader(ScriptAssetLoader::new(config.language_extensions));
@@ -256,6 +290,10 @@ pub trait ConfigureScriptPlugin {
 
     /// Sets the script pipeline settings plugin
     fn set_pipeline_settings(self, pipeline: ScriptLoadingPipeline<Self::P>) -> Self;
+
+    /// Sets how functions returning a `Result` surface errors to scripts of this language,
+    /// overriding the mode set on the [`BMSScriptingInfrastructurePlugin`].
+    fn set_error_return_mode(self, mode: ErrorReturnMode) -> Self;
 }
 
 impl<P: IntoScriptPluginParams + AsMut<ScriptingPlugin<P>>> ConfigureScriptPlugin for P {
@@ -306,6 +344,11 @@ impl<P: IntoScriptPluginParams + AsMut<ScriptingPlugin<P>>> ConfigureScriptPlugi
         self.as_mut().set_pipeline_settings(pipeline);
         self
     }
+
+    fn set_error_return_mode(mut self, mode: ErrorReturnMode) -> Self {
+        self.as_mut().error_return_mode = Some(mode);
+        self
+    }
 }
 
 /// Ensures all types with `ReflectComponent` type data are pre-registered with component ID's
@@ -332,6 +375,32 @@ pub struct BMSScriptingInfrastructurePlugin {
     ///
     /// see the [`crate::handler::script_error_logger`] system.
     dont_log_script_event_errors: bool,
+
+    /// Decides what happens to integers which do not fit into their target type when passed between rust and scripts.
+    ///
+    /// By default overflowing integers produce an error.
+    integer_overflow_policy: IntegerOverflowPolicy,
+
+    /// Decides how functions returning a `Result` surface errors to scripts, unless overridden per language plugin or function.
+    ///
+    /// By default errors are thrown.
+    error_return_mode: ErrorReturnMode,
+}
+
+impl BMSScriptingInfrastructurePlugin {
+    /// Sets the policy for integers which do not fit into their target type, see [`IntegerOverflowPolicy`].
+    pub fn with_integer_overflow_policy(mut self, policy: IntegerOverflowPolicy) -> Self {
+        self.integer_overflow_policy = policy;
+        self
+    }
+
+    /// Sets how functions returning a `Result` surface errors to scripts, see [`ErrorReturnMode`].
+    ///
+    /// Language plugins can override this via [`ConfigureScriptPlugin::set_error_return_mode`].
+    pub fn with_error_return_mode(mut self, mode: ErrorReturnMode) -> Self {
+        self.error_return_mode = mode;
+        self
+    }
 }
 
 impl Plugin for BMSScriptingInfrastructurePlugin {
@@ -343,12 +412,50 @@ impl Plugin for BMSScriptingInfrastructurePlugin {
             .init_asset::<ScriptAsset>()
             .init_resource::<AppScriptFunctionRegistry>()
             .init_resource::<DummyScriptFunctionRegistry>()
+            .init_resource::<ScriptPriorities>()
+            .init_resource::<AppScriptCallbackRegistry>()
+            .insert_resource(self.integer_overflow_policy)
             .insert_resource(AppScheduleRegistry::new());
 
+        // language plugins may have been built first and registered their own modes already
+        app.world_mut()
+            .get_resource_or_init::<ErrorReturnModes>()
+            .default = self.error_return_mode;
+
+        register_core_callbacks(app);
+
         app.register_type::<ScriptAsset>();
         app.register_type::<Handle<ScriptAsset>>();
         app.register_type::<ReflectReference>();
         app.register_type_data::<Handle<ScriptAsset>, MarkAsCore>();
+        app.register_type::<ScriptPriority>();
+        app.register_type::<StopPropagation>();
+        app.register_type::<BigInt>();
+        app.register_type_data::<BigInt, MarkAsCore>();
+        app.register_type::<IntegerOverflowPolicy>();
+        app.register_type::<ErrorReturnMode>();
+        app.register_type::<ScriptResult>();
+        app.register_type_data::<ScriptResult, MarkAsCore>();
+        app.register_type::<WeakReflectReference>();
+        app.register_type_data::<WeakReflectReference, MarkAsCore>();
+        app.register_type::<Json>();
+        app.register_type_data::<Json, MarkAsCore>();
+        app.register_type::<Ron>();
+        app.register_type_data::<Ron, MarkAsCore>();
+
+        if app
+            .world_mut()
+            .get_resource_or_init::<AppScriptGlobalsRegistry>()
+            .write()
+            .register_documented(
+                "STOP_PROPAGATION",
+                |_| Ok(V::from(StopPropagation)),
+                "Return this from a callback to stop the event from reaching lower priority scripts.",
+            )
+            .is_some()
+        {
+            warn!("existing `STOP_PROPAGATION` global was replaced by the core sentinel.")
+        }
 
         app.add_systems(
             PostUpdate,
@@ -370,10 +477,24 @@ impl Plugin for BMSScriptingInfrastructurePlugin {
     fn finish(&self, app: &mut App) {
         // Pre-register component IDs.
         pre_register_components(app);
+        register_variant_constructors(app);
         DynamicScriptComponentPlugin.finish(app);
     }
 }
 
+/// Registers constructors for the variants of all reflected enums, once all types are registered
+fn register_variant_constructors(app: &mut App) {
+    let type_registry = app
+        .world_mut()
+        .get_resource_or_init::<AppTypeRegistry>()
+        .clone();
+    let mut function_registry = app
+        .world_mut()
+        .get_resource_or_init::<AppScriptFunctionRegistry>()
+        .clone();
+    register_enum_variant_constructors(&type_registry.read(), &mut function_registry.write());
+}
+
 /// Register all types that need to be accessed via reflection
 fn register_types(app: &mut App) {
     app.register_type::<ScriptValue>();
@@ -382,6 +503,29 @@ fn register_types(app: &mut App) {
     app.register_type::<ScriptComponent>();
 }
 
+/// Documents the callbacks triggered by the script lifecycle, unless documented already.
+///
+/// Called by the [`BMSScriptingInfrastructurePlugin`], exposed for apps which only need the callback registry, i.e. to generate documentation.
+pub fn register_core_callbacks(app: &mut App) {
+    let registry = app
+        .world_mut()
+        .get_resource_or_init::<AppScriptCallbackRegistry>()
+        .clone();
+    let mut registry = registry.write();
+    registry.register_if_missing(OnScriptLoaded::callback_info());
+    registry.register_if_missing(
+        OnScriptUnloaded::callback_info().with_return::<ScriptValue>(Some(
+            "State to pass to `on_script_reloaded` if the script is being reloaded",
+        )),
+    );
+    registry.register_if_missing(
+        OnScriptReloaded::callback_info().with_argument::<ScriptValue>(
+            "unload_state",
+            Some("The value returned by `on_script_unloaded` before the reload, if any"),
+        ),
+    );
+}
+
 #[cfg(test)]
 mod test {
     use bevy_asset::AssetPlugin;
@@ -408,4 +552,24 @@ mod test {
 
         assert!(app.world_mut().component_id::<Comp>().is_some());
     }
+
+    #[test]
+    fn test_core_callbacks_are_documented() {
+        let mut app = App::new();
+
+        register_core_callbacks(&mut app);
+
+        let registry = app.world().resource::<AppScriptCallbackRegistry>().read();
+        let unloaded = registry
+            .get(&OnScriptUnloaded::into_callback_label())
+            .expect("on_script_unloaded should be documented");
+        assert!(unloaded.documentation.is_some());
+        assert!(unloaded.returns.is_some());
+
+        let reloaded = registry
+            .get(&OnScriptReloaded::into_callback_label())
+            .expect("on_script_reloaded should be documented");
+        assert_eq!(reloaded.arguments.len(), 1);
+        assert!(reloaded.returns.is_none());
+    }
 }
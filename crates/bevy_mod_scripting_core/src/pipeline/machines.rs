@@ -8,7 +8,7 @@ use std::{
 use bevy_ecs::{event::Event, world::Mut};
 use bevy_log::trace;
 use bevy_mod_scripting_bindings::{
-    CurrentScriptAttachment, InteropError, ScriptValue, WorldExtensions,
+    AppScriptFunctionRegistry, CurrentScriptAttachment, InteropError, ScriptValue, WorldExtensions,
 };
 use bevy_mod_scripting_script::ScriptAttachment;
 use bevy_mod_scripting_world::{WorldAccessGuard, WorldGuard};
@@ -474,6 +474,14 @@ impl<P: IntoScriptPluginParams> MachineState<P> for UnloadingInitialized<P> {
         world: &mut World,
     ) -> Box<dyn Future<Output = Result<Box<dyn MachineState<P>>, ScriptError>> + Send + Sync> {
         let attachment = &ctxt.attachment;
+        // every way of detaching a script ends up here, so this is where its priority is forgotten
+        world
+            .get_resource_or_init::<ScriptPriorities>()
+            .remove(attachment);
+        // along with the deprecation warnings it was given, so it's warned again once reloaded
+        if let Some(functions) = world.get_resource::<AppScriptFunctionRegistry>() {
+            functions.read().warnings.forget_script(attachment);
+        }
         let contexts = world.get_resource_or_init::<ScriptContexts<P>>();
         let mut contexts_guard = contexts.write();
         let residents_len = contexts_guard.residents_len(attachment);
@@ -30,7 +30,7 @@ use crate::{
         on_script_unloaded_for_reload_pipeline_handler,
         on_script_unloaded_for_unload_pipeline_handler, process_machine_failure,
     },
-    script::ScriptContexts,
+    script::{ScriptContexts, ScriptPriorities},
 };
 
 mod hooks;
@@ -410,7 +410,7 @@ mod test {
     use bevy_asset::{AssetApp, AssetPlugin};
     use bevy_ecs::{entity::Entity, system::SystemState, world::FromWorld};
     use bevy_mod_scripting_asset::Language;
-    use bevy_mod_scripting_bindings::ScriptValue;
+    use bevy_mod_scripting_bindings::{AppScriptFunctionRegistry, ScriptValue};
     use bevy_mod_scripting_script::ScriptAttachment;
     use test_utils::make_test_plugin;
 
@@ -485,4 +485,81 @@ mod test {
         let machines = world.get_resource::<ActiveMachines<TestPlugin>>().unwrap();
         assert_eq!(machines.budget, None);
     }
+
+    #[test]
+    fn priorities_are_removed_when_scripts_are_detached() {
+        let mut app = App::default();
+        app.add_plugins((
+            AssetPlugin::default(),
+            TestPlugin::default(),
+            crate::BMSScriptingInfrastructurePlugin::default(),
+        ));
+        app.finish();
+
+        let handle = app.world().resource::<AssetServer>().add(ScriptAsset {
+            content: "".to_string().into_boxed_str().into_boxed_bytes(),
+            language: Language::Unknown,
+        });
+        let attachment = ScriptAttachment::StaticScript(handle);
+        crate::commands::AttachScript::<TestPlugin>::new(attachment.clone())
+            .with_priority(5)
+            .apply(app.world_mut());
+        app.update();
+
+        let priorities = app.world().resource::<ScriptPriorities>().clone();
+        assert!(
+            app.world()
+                .resource::<ScriptContexts<TestPlugin>>()
+                .read()
+                .contains(&attachment)
+        );
+        assert_eq!(priorities.get(&attachment), 5);
+
+        // detach without going through the `DetachScript` command
+        app.world_mut()
+            .write_message(ScriptDetachedEvent(attachment.clone()));
+        app.update();
+
+        assert!(
+            !app.world()
+                .resource::<ScriptContexts<TestPlugin>>()
+                .read()
+                .contains(&attachment)
+        );
+        assert_eq!(priorities.get(&attachment), 0);
+    }
+
+    #[test]
+    fn deprecation_warnings_are_forgotten_when_scripts_are_detached() {
+        let mut app = App::default();
+        app.add_plugins((
+            AssetPlugin::default(),
+            TestPlugin::default(),
+            crate::BMSScriptingInfrastructurePlugin::default(),
+        ));
+        app.finish();
+
+        let handle = app.world().resource::<AssetServer>().add(ScriptAsset {
+            content: "".to_string().into_boxed_str().into_boxed_bytes(),
+            language: Language::Unknown,
+        });
+        let attachment = ScriptAttachment::StaticScript(handle);
+        crate::commands::AttachScript::<TestPlugin>::new(attachment.clone()).apply(app.world_mut());
+        app.update();
+
+        let warnings = app
+            .world()
+            .resource::<AppScriptFunctionRegistry>()
+            .read()
+            .warnings
+            .clone();
+        assert!(warnings.first_for_script(&"deprecated".into(), Some(attachment.clone())));
+        assert!(!warnings.first_for_script(&"deprecated".into(), Some(attachment.clone())));
+
+        app.world_mut()
+            .write_message(ScriptDetachedEvent(attachment.clone()));
+        app.update();
+
+        assert!(warnings.is_empty());
+    }
 }
@@ -0,0 +1,413 @@
+//! Performance statistics for script callbacks and dynamic script systems.
+//!
+//! Timings are only recorded while the [`ScriptProfiler`] resource is present, see [`ScriptProfilerPlugin`].
+use std::{borrow::Cow, cell::Cell, sync::Arc, time::Duration};
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, RegisterDiagnostic,
+};
+use bevy_ecs::{
+    resource::Resource,
+    system::{Res, ResMut},
+};
+use bevy_mod_scripting_script::ScriptAttachment;
+use bevy_platform::{collections::HashMap, time::Instant};
+use parking_lot::RwLock;
+
+use crate::event::CallbackLabel;
+
+/// The path used for the total time spent in script callbacks per frame, in milliseconds
+pub const SCRIPT_CALLBACK_TIME_DIAG_PATH: DiagnosticPath =
+    DiagnosticPath::const_new("scripting_callback_time");
+
+/// The path used for the number of script callbacks invoked per frame
+pub const SCRIPT_CALLBACK_CALLS_DIAG_PATH: DiagnosticPath =
+    DiagnosticPath::const_new("scripting_callback_calls");
+
+/// The path used for the number of script callbacks and systems which errored per frame
+pub const SCRIPT_ERRORS_DIAG_PATH: DiagnosticPath = DiagnosticPath::const_new("scripting_errors");
+
+/// The path used for the total time spent in dynamic script systems per frame, in milliseconds
+pub const SCRIPT_SYSTEM_TIME_DIAG_PATH: DiagnosticPath =
+    DiagnosticPath::const_new("scripting_system_time");
+
+thread_local! {
+    /// When the last sample was taken on this thread, or when the current call started
+    static LAST_SAMPLE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Statistics for calls into a script callback or dynamic script system
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallStats {
+    /// The number of times the callback was called
+    pub calls: u64,
+    /// The number of calls which returned an error
+    pub errors: u64,
+    /// The total time spent in the callback
+    pub total: Duration,
+    /// The longest single call
+    pub max: Duration,
+}
+
+impl CallStats {
+    /// Records a single call
+    pub fn record(&mut self, duration: Duration, is_error: bool) {
+        self.calls += 1;
+        self.errors += is_error as u64;
+        self.total += duration;
+        self.max = self.max.max(duration);
+    }
+
+    /// The average duration of a call
+    pub fn mean(&self) -> Duration {
+        match u32::try_from(self.calls) {
+            Ok(0) => Duration::ZERO,
+            Ok(calls) => self.total / calls,
+            Err(_) => Duration::from_secs_f64(self.total.as_secs_f64() / self.calls as f64),
+        }
+    }
+}
+
+/// Time attributed to a script-level function by the sampling hook
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FunctionSamples {
+    /// The number of samples taken while the function was executing
+    pub samples: u64,
+    /// The time elapsed between those samples and the previous ones
+    pub total: Duration,
+}
+
+/// Measurements accumulated since the diagnostics were last updated
+#[derive(Debug, Clone, Default)]
+pub struct FrameStats {
+    /// The time spent in script callbacks
+    pub callback_time: Duration,
+    /// The number of script callbacks invoked
+    pub callback_calls: u64,
+    /// The number of script callbacks and systems which errored
+    pub errors: u64,
+    /// The time spent in dynamic script systems
+    pub system_time: Duration,
+    /// The time spent in each callback
+    pub per_callback: HashMap<CallbackLabel, Duration>,
+    /// The time spent in each dynamic script system
+    pub per_system: HashMap<Cow<'static, str>, Duration>,
+}
+
+#[derive(Default)]
+struct ProfilerData {
+    callbacks: HashMap<(ScriptAttachment, CallbackLabel), CallStats>,
+    systems: HashMap<Cow<'static, str>, CallStats>,
+    functions: HashMap<String, FunctionSamples>,
+    frame: FrameStats,
+}
+
+/// A resource recording performance statistics for script callbacks, dynamic script systems
+/// and, if sampling is enabled and supported by the language, script-level functions.
+#[derive(Resource, Clone, Default)]
+pub struct ScriptProfiler {
+    sampling_interval: Option<u32>,
+    data: Arc<RwLock<ProfilerData>>,
+}
+
+impl ScriptProfiler {
+    /// Creates a new profiler, sampling script functions every `sampling_interval` VM instructions if given.
+    pub fn new(sampling_interval: Option<u32>) -> Self {
+        Self {
+            sampling_interval: sampling_interval.filter(|i| *i > 0),
+            data: Default::default(),
+        }
+    }
+
+    /// The number of VM instructions between samples, if sampling is enabled.
+    ///
+    /// Only read when contexts are loaded, languages which do not support sampling ignore this.
+    pub fn sampling_interval(&self) -> Option<u32> {
+        self.sampling_interval
+    }
+
+    /// Marks the start of a call into a script, returning the start time.
+    ///
+    /// Samples taken during the call attribute time from this point onwards.
+    pub fn start_call(&self) -> Instant {
+        let now = Instant::now();
+        LAST_SAMPLE.set(Some(now));
+        now
+    }
+
+    /// Records a finished callback invocation
+    pub fn record_callback(
+        &self,
+        attachment: &ScriptAttachment,
+        label: &CallbackLabel,
+        duration: Duration,
+        is_error: bool,
+    ) {
+        LAST_SAMPLE.set(None);
+        let mut data = self.data.write();
+        data.callbacks
+            .entry((attachment.clone(), label.clone()))
+            .or_default()
+            .record(duration, is_error);
+
+        let frame = &mut data.frame;
+        frame.callback_time += duration;
+        frame.callback_calls += 1;
+        frame.errors += is_error as u64;
+        *frame.per_callback.entry(label.clone()).or_default() += duration;
+    }
+
+    /// Records a finished run of a dynamic script system
+    pub fn record_system(&self, name: Cow<'static, str>, duration: Duration, is_error: bool) {
+        LAST_SAMPLE.set(None);
+        let mut data = self.data.write();
+        data.systems
+            .entry(name.clone())
+            .or_default()
+            .record(duration, is_error);
+
+        let frame = &mut data.frame;
+        frame.system_time += duration;
+        frame.errors += is_error as u64;
+        *frame.per_system.entry(name).or_default() += duration;
+    }
+
+    /// Records a sample taken while the given script function was executing.
+    ///
+    /// The time since the previous sample, or since the start of the call, is attributed to the function.
+    /// Samples taken outside of a call started with [`Self::start_call`] are counted, but attribute no time.
+    pub fn record_sample(&self, function: &str) {
+        let now = Instant::now();
+        let elapsed = LAST_SAMPLE
+            .replace(Some(now))
+            .map(|last| now.saturating_duration_since(last))
+            .unwrap_or_default();
+
+        let mut data = self.data.write();
+        if let Some(samples) = data.functions.get_mut(function) {
+            samples.samples += 1;
+            samples.total += elapsed;
+        } else {
+            data.functions.insert(
+                function.to_owned(),
+                FunctionSamples {
+                    samples: 1,
+                    total: elapsed,
+                },
+            );
+        }
+    }
+
+    /// Retrieves the statistics for a callback on the given attachment
+    pub fn callback_stats(
+        &self,
+        attachment: &ScriptAttachment,
+        label: &CallbackLabel,
+    ) -> Option<CallStats> {
+        self.data
+            .read()
+            .callbacks
+            .get(&(attachment.clone(), label.clone()))
+            .copied()
+    }
+
+    /// Retrieves the statistics for the dynamic script system with the given name
+    pub fn system_stats(&self, name: &str) -> Option<CallStats> {
+        self.data.read().systems.get(name).copied()
+    }
+
+    /// Retrieves the statistics for all callbacks, sorted by total time spent, descending
+    pub fn all_callback_stats(&self) -> Vec<((ScriptAttachment, CallbackLabel), CallStats)> {
+        let mut stats = self
+            .data
+            .read()
+            .callbacks
+            .iter()
+            .map(|(k, v)| (k.clone(), *v))
+            .collect::<Vec<_>>();
+        stats.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.total));
+        stats
+    }
+
+    /// Retrieves the statistics for all dynamic script systems, sorted by total time spent, descending
+    pub fn all_system_stats(&self) -> Vec<(Cow<'static, str>, CallStats)> {
+        let mut stats = self
+            .data
+            .read()
+            .systems
+            .iter()
+            .map(|(k, v)| (k.clone(), *v))
+            .collect::<Vec<_>>();
+        stats.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.total));
+        stats
+    }
+
+    /// Retrieves the time attributed to each sampled script function, sorted by total time, descending
+    pub fn function_samples(&self) -> Vec<(String, FunctionSamples)> {
+        let mut samples = self
+            .data
+            .read()
+            .functions
+            .iter()
+            .map(|(k, v)| (k.clone(), *v))
+            .collect::<Vec<_>>();
+        samples.sort_by_key(|(_, sample)| std::cmp::Reverse(sample.total));
+        samples
+    }
+
+    /// Takes the measurements accumulated since the last call
+    pub fn take_frame_stats(&self) -> FrameStats {
+        std::mem::take(&mut self.data.write().frame)
+    }
+
+    /// Clears all recorded statistics
+    pub fn reset(&self) {
+        *self.data.write() = ProfilerData::default();
+    }
+}
+
+fn callback_diagnostic_path(label: &CallbackLabel) -> DiagnosticPath {
+    DiagnosticPath::from_components(["scripting_callback_time", label.as_ref()])
+}
+
+fn system_diagnostic_path(name: &str) -> DiagnosticPath {
+    // system names are chosen by scripts and may not be valid path components
+    let name = name.replace('/', "_");
+    let name = if name.is_empty() { "unnamed" } else { &name };
+    DiagnosticPath::from_components(["scripting_system_time", name])
+}
+
+/// Publishes the measurements recorded by the [`ScriptProfiler`] as diagnostics.
+///
+/// Per callback and per system timings are registered as diagnostics the first time they are seen.
+pub fn measure_script_performance(
+    profiler: Res<ScriptProfiler>,
+    mut store: ResMut<DiagnosticsStore>,
+    mut diagnostics: Diagnostics,
+) {
+    let frame = profiler.take_frame_stats();
+    diagnostics.add_measurement(&SCRIPT_CALLBACK_TIME_DIAG_PATH, || {
+        frame.callback_time.as_secs_f64() * 1000.0
+    });
+    diagnostics.add_measurement(&SCRIPT_CALLBACK_CALLS_DIAG_PATH, || {
+        frame.callback_calls as f64
+    });
+    diagnostics.add_measurement(&SCRIPT_ERRORS_DIAG_PATH, || frame.errors as f64);
+    diagnostics.add_measurement(&SCRIPT_SYSTEM_TIME_DIAG_PATH, || {
+        frame.system_time.as_secs_f64() * 1000.0
+    });
+
+    let per_callback = frame
+        .per_callback
+        .iter()
+        .map(|(label, time)| (callback_diagnostic_path(label), *time));
+    let per_system = frame
+        .per_system
+        .iter()
+        .map(|(name, time)| (system_diagnostic_path(name), *time));
+    for (path, time) in per_callback.chain(per_system) {
+        if store.get(&path).is_none() {
+            store.add(Diagnostic::new(path.clone()).with_suffix("ms"));
+        }
+        diagnostics.add_measurement(&path, || time.as_secs_f64() * 1000.0);
+    }
+}
+
+/// A plugin adding the [`ScriptProfiler`] resource and registering its diagnostics.
+///
+/// Add it alongside the `DiagnosticsPlugin` and a diagnostics consumer, i.e. the `LogDiagnosticsPlugin`.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptProfilerPlugin {
+    /// If set, languages which support it will sample the executing script function every this many VM instructions.
+    ///
+    /// Sampling is fairly expensive and should be kept to development builds.
+    pub sampling_interval: Option<u32>,
+}
+
+impl ScriptProfilerPlugin {
+    /// Enables sampling of script functions every `interval` VM instructions
+    pub fn with_sampling(mut self, interval: u32) -> Self {
+        self.sampling_interval = Some(interval);
+        self
+    }
+}
+
+impl Plugin for ScriptProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ScriptProfiler::new(self.sampling_interval))
+            .register_diagnostic(Diagnostic::new(SCRIPT_CALLBACK_TIME_DIAG_PATH).with_suffix("ms"))
+            .register_diagnostic(
+                Diagnostic::new(SCRIPT_CALLBACK_CALLS_DIAG_PATH).with_suffix(" calls"),
+            )
+            .register_diagnostic(Diagnostic::new(SCRIPT_ERRORS_DIAG_PATH).with_suffix(" errors"))
+            .register_diagnostic(Diagnostic::new(SCRIPT_SYSTEM_TIME_DIAG_PATH).with_suffix("ms"))
+            .add_systems(PostUpdate, measure_script_performance);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy_asset::Handle;
+
+    use super::*;
+
+    #[test]
+    fn call_stats_track_counts_and_durations() {
+        let mut stats = CallStats::default();
+        assert_eq!(stats.mean(), Duration::ZERO);
+
+        stats.record(Duration::from_millis(2), false);
+        stats.record(Duration::from_millis(4), true);
+
+        assert_eq!(stats.calls, 2);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.total, Duration::from_millis(6));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        assert_eq!(stats.mean(), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn profiler_records_per_key_and_per_frame() {
+        let profiler = ScriptProfiler::default();
+        let attachment = ScriptAttachment::StaticScript(Handle::default());
+        let label = CallbackLabel::from("on_update");
+
+        profiler.record_callback(&attachment, &label, Duration::from_millis(1), false);
+        profiler.record_callback(&attachment, &label, Duration::from_millis(3), true);
+        profiler.record_system("my_system".into(), Duration::from_millis(5), false);
+
+        let stats = profiler.callback_stats(&attachment, &label).unwrap();
+        assert_eq!(stats.calls, 2);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.max, Duration::from_millis(3));
+        assert_eq!(profiler.system_stats("my_system").unwrap().calls, 1);
+
+        let frame = profiler.take_frame_stats();
+        assert_eq!(frame.callback_calls, 2);
+        assert_eq!(frame.errors, 1);
+        assert_eq!(frame.callback_time, Duration::from_millis(4));
+        assert_eq!(frame.system_time, Duration::from_millis(5));
+        assert_eq!(frame.per_callback[&label], Duration::from_millis(4));
+
+        // frame stats are reset, totals are not
+        assert_eq!(profiler.take_frame_stats().callback_calls, 0);
+        assert_eq!(profiler.all_callback_stats().len(), 1);
+    }
+
+    #[test]
+    fn samples_are_attributed_to_functions() {
+        let profiler = ScriptProfiler::new(Some(100));
+        profiler.start_call();
+        profiler.record_sample("update");
+        profiler.record_sample("update");
+        profiler.record_sample("helper");
+
+        let samples = profiler.function_samples();
+        let update = samples.iter().find(|(name, _)| name == "update").unwrap();
+        assert_eq!(update.1.samples, 2);
+        assert_eq!(samples.len(), 2);
+
+        assert_eq!(ScriptProfiler::new(Some(0)).sampling_interval(), None);
+    }
+}
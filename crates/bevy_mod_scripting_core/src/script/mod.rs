@@ -16,6 +16,7 @@ use ::{
 };
 
 mod context_key;
+mod priority;
 mod script_context;
 use bevy_ecs::{
     component::Component,
@@ -30,6 +31,7 @@ use bevy_log::trace;
 use bevy_mod_scripting_asset::ScriptAsset;
 use bevy_mod_scripting_script::ScriptAttachment;
 pub use context_key::*;
+pub use priority::*;
 pub use script_context::*;
 
 /// A unique identifier for a script, by default corresponds to the path of the asset excluding the asset source.
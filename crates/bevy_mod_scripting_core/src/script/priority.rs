@@ -0,0 +1,193 @@
+//! Priorities determining the order in which scripts receive callbacks
+
+use std::{any::TypeId, cmp::Reverse, collections::HashMap, sync::Arc};
+
+use ::{
+    bevy_ecs::{
+        component::Component, entity::EntityHashMap, lifecycle::HookContext,
+        prelude::ReflectComponent, resource::Resource, world::DeferredWorld,
+    },
+    bevy_reflect::Reflect,
+};
+use bevy_mod_scripting_bindings::{ReflectReference, ScriptValue};
+use bevy_mod_scripting_script::ScriptAttachment;
+use bevy_mod_scripting_world::WorldGuard;
+use parking_lot::RwLock;
+
+/// The sentinel value scripts can return from a callback to stop the event from being dispatched to any lower priority scripts.
+///
+/// Exposed to scripts via the `STOP_PROPAGATION` global.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StopPropagation;
+
+impl StopPropagation {
+    /// Returns true if the value returned by a callback is the [`StopPropagation`] sentinel
+    pub fn is_sentinel(value: &ScriptValue, world: WorldGuard) -> bool {
+        match value {
+            ScriptValue::Reference(reference) => Self::is_sentinel_reference(reference, world),
+            _ => false,
+        }
+    }
+
+    fn is_sentinel_reference(reference: &ReflectReference, world: WorldGuard) -> bool {
+        reference
+            .tail_type_id(world)
+            .is_ok_and(|type_id| type_id == Some(TypeId::of::<StopPropagation>()))
+    }
+}
+
+/// A component setting the callback priority of all scripts attached to the entity via its [`super::ScriptComponent`].
+///
+/// Scripts with a higher priority receive callbacks first, scripts without a priority have a priority of `0`.
+/// Priorities set for a specific attachment, via [`ScriptPriorities::set`], take precedence.
+#[derive(Component, Reflect, Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+#[component(on_insert=Self::on_insert, on_remove=Self::on_remove)]
+pub struct ScriptPriority(pub i32);
+
+impl ScriptPriority {
+    /// the lifecycle hook called when the priority is inserted, records the priority in [`ScriptPriorities`]
+    pub fn on_insert(world: DeferredWorld, context: HookContext) {
+        let Some(priority) = world.get::<ScriptPriority>(context.entity).copied() else {
+            return;
+        };
+        if let Some(priorities) = world.get_resource::<ScriptPriorities>() {
+            priorities
+                .inner
+                .write()
+                .entities
+                .insert(context.entity, priority.0);
+        }
+    }
+
+    /// the lifecycle hook called when the priority is removed, clears the priority from [`ScriptPriorities`]
+    pub fn on_remove(world: DeferredWorld, context: HookContext) {
+        if let Some(priorities) = world.get_resource::<ScriptPriorities>() {
+            priorities.inner.write().entities.remove(&context.entity);
+        }
+    }
+}
+
+#[derive(Default)]
+struct ScriptPrioritiesInner {
+    attachments: HashMap<ScriptAttachment, i32>,
+    entities: EntityHashMap<i32>,
+}
+
+/// A resource tracking the callback priority of each script attachment.
+///
+/// When an event is dispatched to multiple scripts, they receive it in order of descending priority,
+/// scripts with equal priority are ordered by entity and then by script asset.
+#[derive(Resource, Clone, Default)]
+pub struct ScriptPriorities {
+    inner: Arc<RwLock<ScriptPrioritiesInner>>,
+}
+
+impl ScriptPriorities {
+    /// Sets the priority of the given attachment, overriding any [`ScriptPriority`] on its entity
+    pub fn set(&self, attachment: ScriptAttachment, priority: i32) {
+        self.inner.write().attachments.insert(attachment, priority);
+    }
+
+    /// Removes the priority set for the given attachment via [`Self::set`]
+    pub fn remove(&self, attachment: &ScriptAttachment) {
+        self.inner.write().attachments.remove(attachment);
+    }
+
+    /// Retrieves the priority of the given attachment, `0` if none was set
+    pub fn get(&self, attachment: &ScriptAttachment) -> i32 {
+        let inner = self.inner.read();
+        inner
+            .attachments
+            .get(attachment)
+            .or_else(|| {
+                attachment
+                    .entity()
+                    .and_then(|entity| inner.entities.get(&entity))
+            })
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sorts the recipients of a callback in the order they should receive it.
+    ///
+    /// Recipients with equal priority are ordered as in [`sort_recipients`].
+    pub fn sort<T>(&self, recipients: &mut [(ScriptAttachment, T)]) {
+        recipients.sort_by_cached_key(|(attachment, _)| {
+            (
+                Reverse(self.get(attachment)),
+                attachment.entity(),
+                attachment.script().id(),
+            )
+        });
+    }
+}
+
+/// Sorts recipients into a deterministic order, by entity and then by script asset.
+///
+/// Static scripts come before entity scripts.
+pub fn sort_recipients<T>(recipients: &mut [(ScriptAttachment, T)]) {
+    recipients.sort_by(|(a, _), (b, _)| {
+        a.entity()
+            .cmp(&b.entity())
+            .then_with(|| a.script().id().cmp(&b.script().id()))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_ecs::{entity::Entity, world::World};
+
+    use super::*;
+
+    fn handle(n: u128) -> Handle<bevy_mod_scripting_asset::ScriptAsset> {
+        Handle::Uuid(uuid::Uuid::from_u128(n), Default::default())
+    }
+
+    #[test]
+    fn recipients_are_sorted_by_priority_then_deterministically() {
+        let priorities = ScriptPriorities::default();
+        let entity = Entity::from_raw_u32(1).unwrap();
+
+        let low = ScriptAttachment::StaticScript(handle(1));
+        let high = ScriptAttachment::EntityScript(entity, handle(2));
+        let default_a = ScriptAttachment::StaticScript(handle(3));
+        let default_b = ScriptAttachment::EntityScript(entity, handle(4));
+        priorities.set(low.clone(), -1);
+        priorities.set(high.clone(), 10);
+
+        let mut recipients = vec![
+            (default_b.clone(), ()),
+            (low.clone(), ()),
+            (default_a.clone(), ()),
+            (high.clone(), ()),
+        ];
+        priorities.sort(&mut recipients);
+
+        let order = recipients.into_iter().map(|(a, _)| a).collect::<Vec<_>>();
+        assert_eq!(order, vec![high, default_a, default_b, low]);
+    }
+
+    #[test]
+    fn entity_priority_is_tracked_via_component() {
+        let mut world = World::new();
+        world.init_resource::<ScriptPriorities>();
+        let entity = world.spawn(ScriptPriority(5)).id();
+        let attachment = ScriptAttachment::EntityScript(entity, handle(1));
+
+        let priorities = world.resource::<ScriptPriorities>().clone();
+        assert_eq!(priorities.get(&attachment), 5);
+
+        priorities.set(attachment.clone(), 7);
+        assert_eq!(priorities.get(&attachment), 7);
+        priorities.remove(&attachment);
+
+        world.entity_mut(entity).remove::<ScriptPriority>();
+        assert_eq!(priorities.get(&attachment), 0);
+        assert_eq!(
+            priorities.get(&ScriptAttachment::StaticScript(Handle::default())),
+            0
+        );
+    }
+}
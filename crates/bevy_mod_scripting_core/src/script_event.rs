@@ -0,0 +1,170 @@
+//! Typed script callback events.
+//!
+//! A [`ScriptEvent`] is a rust message which is forwarded to scripts as a callback, with its fields as the callback arguments.
+//! Usually implemented via the `ScriptEvent` derive macro.
+
+use std::marker::PhantomData;
+
+use ::{
+    bevy_app::{App, Plugin, Update},
+    bevy_ecs::{
+        intern::Interned,
+        message::{Message, MessageCursor, Messages},
+        resource::Resource,
+        schedule::{IntoScheduleConfigs, ScheduleLabel},
+        system::Local,
+        world::{Mut, World},
+    },
+    bevy_log::error,
+};
+use bevy_mod_scripting_bindings::{
+    CurrentScriptAttachment, InteropError, ScriptValue, WorldExtensions,
+};
+use bevy_mod_scripting_display::WithTypeInfo;
+use bevy_mod_scripting_world::{WorldAccessGuard, WorldGuard};
+
+use crate::{
+    IntoScriptPluginParams,
+    callback_registry::AppScriptCallbackRegistry,
+    error::ScriptError,
+    event::{IntoCallbackLabel, Recipients, ScriptCallbackEvent},
+    handler::{event_handler, send_script_errors},
+};
+
+/// A message which is forwarded to scripts as a callback.
+///
+/// Can be derived via the `ScriptEvent` derive macro:
+/// ```rust,ignore
+/// #[derive(Message, ScriptEvent, Clone)]
+/// #[script_event(label = "on_hit")]
+/// /// Called when an entity is hit
+/// struct OnHit {
+///     /// the attacking entity
+///     #[script_event(reflect)]
+///     attacker: Entity,
+///     /// the damage dealt
+///     damage: u32,
+/// }
+/// ```
+pub trait ScriptEvent: IntoCallbackLabel + Sized + Send + Sync + 'static {
+    /// Converts the event into the arguments passed to the callback, in order
+    fn into_script_args(self, world: WorldGuard) -> Result<Vec<ScriptValue>, InteropError>;
+
+    /// The scripts which should receive the callback, by default all scripts
+    fn recipients(&self) -> Recipients {
+        Recipients::AllScripts
+    }
+}
+
+/// Converts all [`ScriptEvent`] messages of type `E` into [`ScriptCallbackEvent`]s.
+///
+/// Any errors in converting the arguments are sent as script errors.
+pub fn forward_script_events<E: ScriptEvent + Message + Clone>(
+    world: &mut World,
+    mut cursor: Local<MessageCursor<E>>,
+) {
+    let Some(messages) = world.get_resource::<Messages<E>>() else {
+        return;
+    };
+    let events = cursor.read(messages).cloned().collect::<Vec<_>>();
+    if events.is_empty() {
+        return;
+    }
+
+    let cache = WorldAccessGuard::setup_cache(world, CurrentScriptAttachment::default());
+    let guard = WorldAccessGuard::new_exclusive(world, cache);
+    let mut callbacks = Vec::with_capacity(events.len());
+    let mut errors = Vec::default();
+    for event in events {
+        let recipients = event.recipients();
+        match event.into_script_args(guard.clone()) {
+            Ok(args) => callbacks.push(ScriptCallbackEvent::new(
+                E::into_callback_label(),
+                args,
+                recipients,
+                None,
+            )),
+            Err(err) => errors.push(ScriptError::from(err).with_context(format!(
+                "converting arguments for callback: {}",
+                E::into_callback_label()
+            ))),
+        }
+    }
+
+    let result = guard.with_resource_mut(|mut events: Mut<Messages<ScriptCallbackEvent>>| {
+        events.write_batch(callbacks);
+    });
+    if let Err(err) = result {
+        error!(
+            "Failed to forward script events: {}",
+            WithTypeInfo::new_with_info(&err, &guard)
+        );
+    }
+    send_script_errors(guard, &errors);
+}
+
+/// Forwards [`ScriptEvent`]s of type `E` to scripts of the language `P`.
+///
+/// Adds the `E` message, a system forwarding it as a [`ScriptCallbackEvent`] and an [`event_handler`] for its label,
+/// and documents the callback in the [`AppScriptCallbackRegistry`].
+///
+/// Can be added once per language, the message will be forwarded once.
+pub struct ScriptEventPlugin<E, P> {
+    schedule: Interned<dyn ScheduleLabel>,
+    _ph: PhantomData<fn(E, P)>,
+}
+
+impl<E, P> Default for ScriptEventPlugin<E, P> {
+    fn default() -> Self {
+        Self {
+            schedule: Update.intern(),
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<E, P> ScriptEventPlugin<E, P> {
+    /// Creates a new plugin running in the [`Update`] schedule
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the schedule in which the events are forwarded and handled
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+/// Marks that events of type `E` are already being forwarded
+#[derive(Resource)]
+struct ForwardingScriptEvent<E: 'static>(PhantomData<fn(E)>);
+
+impl<E: ScriptEvent + Message + Clone, P: IntoScriptPluginParams> Plugin
+    for ScriptEventPlugin<E, P>
+{
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<ForwardingScriptEvent<E>>() {
+            app.insert_resource(ForwardingScriptEvent::<E>(PhantomData))
+                .add_message::<E>()
+                .add_systems(self.schedule, forward_script_events::<E>);
+
+            let previous = app
+                .world_mut()
+                .get_resource_or_init::<AppScriptCallbackRegistry>()
+                .write()
+                .register(E::callback_info());
+            if previous.is_some() {
+                bevy_log::warn!(
+                    "Callback '{}' was documented more than once, the latest registration will be used",
+                    E::into_callback_label()
+                );
+            }
+        }
+
+        app.add_systems(
+            self.schedule,
+            event_handler::<E, P>.after(forward_script_events::<E>),
+        );
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::{
     IntoScriptPluginParams, callbacks::ScriptCallbacks, event::CallbackLabel,
-    extractors::get_all_access_ids, handler::ScriptingHandler, script::ScriptContexts,
+    extractors::get_all_access_ids, handler::ScriptingHandler, profiler::ScriptProfiler,
+    script::ScriptContexts,
 };
 
 use ::{
@@ -26,8 +27,9 @@ use bevy_ecs::{
 use bevy_log::{debug, error, warn_once};
 use bevy_mod_scripting_bindings::{
     AppReflectAllocator, AppScheduleRegistry, AppScriptComponentRegistry,
-    AppScriptFunctionRegistry, CurrentScriptAttachment, InteropError, IntoScript, ReflectReference,
-    ScriptQueryBuilder, ScriptQueryResult, ScriptResourceRegistration, V, WorldExtensions,
+    AppScriptFunctionRegistry, CurrentScriptAttachment, ErrorReturnModes, IntegerOverflowPolicy,
+    InteropError, IntoScript, ReflectReference, ScriptQueryBuilder, ScriptQueryResult,
+    ScriptResourceRegistration, V, WorldExtensions,
 };
 use bevy_mod_scripting_script::ScriptAttachment;
 use bevy_mod_scripting_world::{AccessByteSet, WorldAccessGuard, WorldGuard};
@@ -185,11 +187,14 @@ struct ScriptSystemState<P: IntoScriptPluginParams> {
     schedule_registry: AppScheduleRegistry,
     component_registry: AppScriptComponentRegistry,
     allocator: AppReflectAllocator,
+    integer_overflow_policy: IntegerOverflowPolicy,
+    error_return_modes: ErrorReturnModes,
     subset: AccessByteSet,
     callback_label: CallbackLabel,
     system_params: Vec<ScriptSystemParam>,
     script_contexts: ScriptContexts<P>,
     script_callbacks: ScriptCallbacks<P>,
+    profiler: Option<ScriptProfiler>,
     initialization_errors: Result<(), Vec<SystemParamValidationError>>,
 }
 
@@ -287,6 +292,8 @@ impl<P: IntoScriptPluginParams> System for DynamicScriptSystem<P> {
             state.function_registry.clone(),
             state.schedule_registry.clone(),
             state.component_registry.clone(),
+            state.integer_overflow_policy,
+            state.error_return_modes.clone(),
         );
         let guard = if self.exclusive {
             // safety: we are an exclusive system, therefore the cell allows us to do this
@@ -358,6 +365,7 @@ impl<P: IntoScriptPluginParams> System for DynamicScriptSystem<P> {
             };
 
             let mut context = context.lock();
+            let started = state.profiler.as_ref().map(ScriptProfiler::start_call);
             let result = P::handle(
                 payload,
                 &self.target_attachment,
@@ -367,6 +375,9 @@ impl<P: IntoScriptPluginParams> System for DynamicScriptSystem<P> {
                 guard.clone(),
             );
             drop(context);
+            if let (Some(profiler), Some(started)) = (&state.profiler, started) {
+                profiler.record_system(self.name.clone(), started.elapsed(), result.is_err());
+            }
             // TODO: Emit error events via commands, maybe accumulate in state
             // instead and use apply.
             match result {
@@ -470,6 +481,14 @@ impl<P: IntoScriptPluginParams> System for DynamicScriptSystem<P> {
                 .clone(),
             schedule_registry: world.get_resource_or_init::<AppScheduleRegistry>().clone(),
             allocator: world.get_resource_or_init::<AppReflectAllocator>().clone(),
+            integer_overflow_policy: world
+                .get_resource::<IntegerOverflowPolicy>()
+                .copied()
+                .unwrap_or_default(),
+            error_return_modes: world
+                .get_resource::<ErrorReturnModes>()
+                .cloned()
+                .unwrap_or_default(),
             component_registry: world
                 .get_resource_or_init::<AppScriptComponentRegistry>()
                 .clone(),
@@ -478,6 +497,7 @@ impl<P: IntoScriptPluginParams> System for DynamicScriptSystem<P> {
             system_params,
             script_contexts: world.get_resource_or_init::<ScriptContexts<P>>().clone(),
             script_callbacks: world.get_resource_or_init::<ScriptCallbacks<P>>().clone(),
+            profiler: world.get_resource::<ScriptProfiler>().cloned(),
             initialization_errors,
         });
 
@@ -3,7 +3,9 @@ mod debug_with_type_info;
 mod from_script;
 mod get_type_dependencies;
 mod into_script;
+mod script_attributes;
 mod script_bindings;
+mod script_event;
 mod script_globals;
 mod typed_through;
 
@@ -14,7 +16,8 @@ use syn::{Ident, ImplItemFn, ItemImpl};
 pub use self::{
     arg_meta::arg_meta, debug_with_type_info::debug_with_type_info, from_script::from_script,
     get_type_dependencies::get_type_dependencies, into_script::into_script,
-    script_bindings::script_bindings, script_globals::script_globals, typed_through::typed_through,
+    script_attributes::script_attributes, script_bindings::script_bindings,
+    script_event::script_event, script_globals::script_globals, typed_through::typed_through,
 };
 
 #[allow(dead_code)]
@@ -0,0 +1,157 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::derive::SharedArgs;
+
+#[derive(Default)]
+struct ItemArgs {
+    hidden: bool,
+    rename: Option<syn::LitStr>,
+    deprecated: Option<syn::LitStr>,
+}
+
+impl ItemArgs {
+    /// Parses the `script` attributes of a type, field or variant.
+    ///
+    /// Shared arguments are only accepted if `shared_args` is provided, i.e. on the type itself.
+    fn parse(
+        attrs: &[syn::Attribute],
+        mut shared_args: Option<&mut SharedArgs>,
+    ) -> syn::Result<Self> {
+        let mut args = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("script") {
+                attr.parse_nested_meta(|meta| {
+                    if let Some(shared_args) = shared_args.as_deref_mut()
+                        && shared_args.apply_nested_meta(&meta)?
+                    {
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("hidden") {
+                        args.hidden = true;
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("rename") {
+                        args.rename = Some(meta.value()?.parse()?);
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("deprecated") {
+                        // the message is optional
+                        args.deprecated = Some(if meta.input.peek(syn::Token![=]) {
+                            meta.value()?.parse()?
+                        } else {
+                            syn::LitStr::new("", Span::call_site())
+                        });
+                        return Ok(());
+                    }
+
+                    Err(meta.error("Unknown argument to script"))
+                })?;
+            }
+        }
+        Ok(args)
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.hidden && self.rename.is_none() && self.deprecated.is_none()
+    }
+
+    fn to_script_attributes(&self, bms_bindings_path: &syn::Path) -> TokenStream {
+        let hidden = self.hidden;
+        let rename = optional_str(&self.rename);
+        let deprecated = optional_str(&self.deprecated);
+        quote! {
+            #bms_bindings_path::ScriptAttributes {
+                hidden: #hidden,
+                rename: #rename,
+                deprecated: #deprecated,
+            }
+        }
+    }
+}
+
+fn optional_str(lit: &Option<syn::LitStr>) -> TokenStream {
+    match lit {
+        Some(lit) => quote! { ::core::option::Option::Some(#lit) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
+/// Collects the attributes of each field which has any, keyed by `prefix` followed by the field name or index
+fn field_members(
+    fields: &syn::Fields,
+    prefix: &str,
+    members: &mut Vec<(String, ItemArgs)>,
+) -> syn::Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        let args = ItemArgs::parse(&field.attrs, None)?;
+        if args.is_empty() {
+            continue;
+        }
+        let name = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => i.to_string(),
+        };
+        members.push((format!("{prefix}{name}"), args));
+    }
+    Ok(())
+}
+
+pub fn script_attributes(input: TokenStream) -> TokenStream {
+    let derive_input = match syn::parse2::<DeriveInput>(input) {
+        Ok(derive_input) => derive_input,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let mut shared_args = SharedArgs::default();
+    let type_args = match ItemArgs::parse(&derive_input.attrs, Some(&mut shared_args)) {
+        Ok(args) => args,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let mut members = Vec::new();
+    let result = match &derive_input.data {
+        syn::Data::Struct(data) => field_members(&data.fields, "", &mut members),
+        syn::Data::Enum(data) => data.variants.iter().try_for_each(|variant| {
+            let variant_name = variant.ident.to_string();
+            let args = ItemArgs::parse(&variant.attrs, None)?;
+            if !args.is_empty() {
+                members.push((variant_name.clone(), args));
+            }
+            field_members(&variant.fields, &format!("{variant_name}."), &mut members)
+        }),
+        syn::Data::Union(_) => Err(syn::Error::new(
+            derive_input.ident.span(),
+            "ScriptAttributes cannot be derived for unions",
+        )),
+    };
+    if let Err(error) = result {
+        return error.to_compile_error();
+    }
+
+    let bms_bindings_path = &shared_args.bms_bindings_path;
+    let type_attributes = type_args.to_script_attributes(bms_bindings_path);
+    let members = members.iter().map(|(path, args)| {
+        let path = syn::LitStr::new(path, Span::call_site());
+        let attributes = args.to_script_attributes(bms_bindings_path);
+        quote! { (#path, #attributes) }
+    });
+
+    let ident = &derive_input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #bms_bindings_path::GetScriptAttributes for #ident #ty_generics #where_clause {
+            fn script_attributes() -> #bms_bindings_path::ReflectScriptAttributes {
+                #bms_bindings_path::ReflectScriptAttributes {
+                    type_attributes: #type_attributes,
+                    members: ::std::vec![#(#members),*],
+                }
+            }
+        }
+    }
+}
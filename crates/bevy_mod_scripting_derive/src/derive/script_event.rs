@@ -0,0 +1,218 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DeriveInput, spanned::Spanned};
+
+use crate::derive::{SharedArgs, parse_docstring};
+
+struct Args {
+    shared_args: SharedArgs,
+    label: Option<syn::LitStr>,
+}
+
+impl Args {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut shared_args = SharedArgs::default();
+        let mut label = None;
+
+        for attr in attrs {
+            if attr.path().is_ident("script_event") {
+                attr.parse_nested_meta(|meta| {
+                    if shared_args.apply_nested_meta(&meta)? {
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("label") {
+                        label = Some(meta.value()?.parse()?);
+                        return Ok(());
+                    }
+
+                    Err(meta.error("Unknown argument to script_event"))
+                })?;
+            }
+        }
+
+        Ok(Self { shared_args, label })
+    }
+}
+
+#[derive(Default)]
+struct FieldArgs {
+    skip: bool,
+    recipients: bool,
+    reflect: bool,
+}
+
+impl FieldArgs {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut args = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("script_event") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("skip") {
+                        args.skip = true;
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("recipients") {
+                        args.recipients = true;
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("reflect") {
+                        args.reflect = true;
+                        return Ok(());
+                    }
+
+                    Err(meta.error("Unknown field argument to script_event"))
+                })?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Converts a type identifier in `PascalCase` into a `snake_case` callback label.
+fn to_snake_case(ident: &str) -> String {
+    let chars = ident.chars().collect::<Vec<_>>();
+    let mut out = String::with_capacity(ident.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+pub fn script_event(input: TokenStream) -> TokenStream {
+    let derive_input = match syn::parse2::<DeriveInput>(input) {
+        Ok(derive_input) => derive_input,
+        Err(err) => return err.to_compile_error(),
+    };
+    let args = match Args::parse(&derive_input.attrs) {
+        Ok(args) => args,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let syn::Data::Struct(data) = &derive_input.data else {
+        return syn::Error::new(
+            derive_input.ident.span(),
+            "ScriptEvent can only be derived for structs",
+        )
+        .to_compile_error();
+    };
+
+    let bms_bindings_path = &args.shared_args.bms_bindings_path;
+    let bms_core_path = &args.shared_args.bms_core_path;
+
+    let mut arguments = Vec::new();
+    let mut argument_docs = Vec::new();
+    let mut recipients = None;
+    for (i, field) in data.fields.iter().enumerate() {
+        let field_args = match FieldArgs::parse(&field.attrs) {
+            Ok(field_args) => field_args,
+            Err(error) => return error.to_compile_error(),
+        };
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        };
+
+        if field_args.recipients {
+            if recipients.is_some() {
+                return syn::Error::new(field.span(), "Only one field can be marked as recipients")
+                    .to_compile_error();
+            }
+            recipients = Some(member);
+            continue;
+        }
+
+        if field_args.skip {
+            continue;
+        }
+
+        let name = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => format!("arg{i}"),
+        };
+        let name = syn::LitStr::new(&name, Span::call_site());
+        let ty = &field.ty;
+        let (ty, value) = match field_args.reflect {
+            true => (
+                quote! { #bms_bindings_path::V<#ty> },
+                quote! { #bms_bindings_path::V(self.#member) },
+            ),
+            false => (quote! { #ty }, quote! { self.#member }),
+        };
+        let docs = match parse_docstring(field.attrs.iter()) {
+            Some(docs) => quote! { Some(#docs) },
+            None => quote! { None },
+        };
+
+        argument_docs.push(quote! {
+            .with_argument::<#ty>(#name, #docs)
+        });
+        arguments.push(quote! {
+            #bms_bindings_path::IntoScript::into_script(#value, world.clone())?
+        });
+    }
+
+    let label = args.label.unwrap_or_else(|| {
+        syn::LitStr::new(
+            &to_snake_case(&derive_input.ident.to_string()),
+            derive_input.ident.span(),
+        )
+    });
+
+    let recipients = recipients.map(|member| {
+        quote! {
+            fn recipients(&self) -> #bms_core_path::event::Recipients {
+                ::std::clone::Clone::clone(&self.#member)
+            }
+        }
+    });
+
+    let docs = parse_docstring(derive_input.attrs.iter()).map(|docs| {
+        quote! {
+            .with_documentation(#docs)
+        }
+    });
+
+    let ident = &derive_input.ident;
+    let world = match arguments.is_empty() {
+        true => format_ident!("_world"),
+        false => format_ident!("world"),
+    };
+    let (impl_generics, type_generics, where_clause) = derive_input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #bms_core_path::event::IntoCallbackLabel for #ident #type_generics #where_clause {
+            fn into_callback_label() -> #bms_core_path::event::CallbackLabel {
+                #label.into()
+            }
+
+            fn callback_info() -> #bms_core_path::callback_registry::ScriptCallbackInfo {
+                #bms_core_path::callback_registry::ScriptCallbackInfo::of::<Self>()
+                    #docs
+                    #(#argument_docs)*
+            }
+        }
+
+        impl #impl_generics #bms_core_path::script_event::ScriptEvent for #ident #type_generics #where_clause {
+            fn into_script_args(
+                self,
+                #world: #bms_bindings_path::WorldGuard,
+            ) -> Result<::std::vec::Vec<#bms_bindings_path::ScriptValue>, #bms_bindings_path::InteropError> {
+                Ok(::std::vec![#(#arguments),*])
+            }
+
+            #recipients
+        }
+    }
+}
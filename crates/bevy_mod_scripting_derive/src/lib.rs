@@ -26,6 +26,41 @@ pub fn from_script(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive::from_script(input.into()).into()
 }
 
+/// Derive macro for implementing `ScriptEvent` and `IntoCallbackLabel` on a struct, turning it into a typed script callback.
+///
+/// Each field is converted via `IntoScript` and passed to the callback as an argument, in declaration order.
+/// The struct and field docstrings are used to document the callback.
+///
+/// Arguments:
+/// - `label`: the callback label, i.e. the name of the script function called. Defaults to the struct name in `snake_case`
+/// - `bms_core_path`: If set the path to override bms core imports, normally only used internally
+/// - `bms_bindings_path`: If set the path to override bms bindings imports, normally only used internally
+///
+/// Field arguments:
+/// - `skip`: the field is not passed to the callback
+/// - `reflect`: the field is passed as a reflect reference via the `V` wrapper, useful for types such as `Entity` which do not implement `IntoScript`
+/// - `recipients`: the field, of type `Recipients`, determines which scripts receive the callback. By default all scripts do
+#[proc_macro_derive(ScriptEvent, attributes(script_event))]
+pub fn script_event(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::script_event(input.into()).into()
+}
+
+/// Derive macro for implementing `GetScriptAttributes`, which controls how a type is presented in generated documentation and declaration files.
+///
+/// The attributes are picked up from the type registry, so the type also needs `#[reflect(ScriptAttributes)]`.
+///
+/// Arguments, accepted on the type, its fields and enum variants:
+/// - `hidden`: the item is left out of generated outputs, along with anything referring to a hidden type
+/// - `rename`: the name the item is documented under instead of its rust name
+/// - `deprecated`: the item is documented as deprecated, optionally with a message i.e. `deprecated = "use X instead"`
+///
+/// Type only arguments:
+/// - `bms_bindings_path`: If set the path to override bms bindings imports, normally only used internally
+#[proc_macro_derive(ScriptAttributes, attributes(script))]
+pub fn script_attributes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::script_attributes(input.into()).into()
+}
+
 /// Derive macro for generating script bindings from an impl block.
 ///
 /// Generates a registration function with visibility determined by the highest visibility in the impl block.
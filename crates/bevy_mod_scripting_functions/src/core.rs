@@ -10,84 +10,158 @@ use bevy_app::App;
 use bevy_asset::{AssetServer, Handle};
 use bevy_ecs::{entity::Entity, prelude::AppTypeRegistry, schedule::Schedules, world::World};
 use bevy_mod_scripting_bindings::{
-    DynamicScriptFunction, DynamicScriptFunctionMut, FunctionInfo, GlobalNamespace, InteropError,
-    PartialReflectExt, ReflectReference, ScriptComponentRegistration, ScriptQueryBuilder,
-    ScriptQueryResult, ScriptResourceRegistration, ScriptTypeRegistration, Union, VariadicTuple,
+    BigInt, DynamicScriptFunction, DynamicScriptFunctionMut, ErrorReturnMode, FunctionInfo,
+    GlobalNamespace, InteropError, Json, PartialReflectExt, ReferencePart, ReflectReference, Ron,
+    ScriptComponentRegistration, ScriptQueryBuilder, ScriptQueryResult, ScriptResourceRegistration,
+    ScriptResult, ScriptTypeRegistration, SetOperation, Union, VariadicTuple, WeakReflectReference,
     WorldExtensions,
     function::{
         from::{R, V},
         from_ref::FromScriptRef,
         into_ref::IntoScriptRef,
+        namespace::NamespaceBuilder,
         script_function::{FunctionCallContext, ScriptFunctionMut},
     },
+    reflect_into_script_value,
     script_value::ScriptValue,
+    script_value_into_reflect,
 };
 use bevy_mod_scripting_core::script_system::{ManageScriptSystems, ScriptSystemBuilder};
 use bevy_mod_scripting_derive::script_bindings;
 use bevy_mod_scripting_display::{OrFakeId, WithTypeInfo};
-use bevy_reflect::PartialReflect;
+use bevy_reflect::{PartialReflect, ReflectRef, enums::VariantType};
 use bevy_system_reflection::{ReflectSchedule, ReflectSystem};
 
-#[allow(unused_variables, reason = "feature flags")]
+/// Registers all the bevy bindings enabled via feature flags
 pub fn register_bevy_bindings(app: &mut App) {
+    for (_, register) in bevy_bindings() {
+        register(app);
+    }
+}
+
+/// The bevy bindings enabled via feature flags, alongside the name of the feature enabling each.
+///
+/// Useful for registering only a subset of the available bindings, i.e. when generating LAD files for a specific set of crates.
+#[allow(unused_mut, clippy::vec_init_then_push)]
+pub fn bevy_bindings() -> Vec<(&'static str, fn(&mut App))> {
+    let mut bindings: Vec<(&'static str, fn(&mut App))> = Vec::new();
     #[cfg(feature = "bevy_a11y")]
-    app.add_plugins(bevy_a11y_bms_bindings::BevyA11YScriptingPlugin);
+    bindings.push(("bevy_a11y", |app: &mut App| {
+        app.add_plugins(bevy_a11y_bms_bindings::BevyA11YScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_animation")]
-    app.add_plugins(bevy_animation_bms_bindings::BevyAnimationScriptingPlugin);
+    bindings.push(("bevy_animation", |app: &mut App| {
+        app.add_plugins(bevy_animation_bms_bindings::BevyAnimationScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_asset")]
-    app.add_plugins(bevy_asset_bms_bindings::BevyAssetScriptingPlugin);
+    bindings.push(("bevy_asset", |app: &mut App| {
+        app.add_plugins(bevy_asset_bms_bindings::BevyAssetScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_camera")]
-    app.add_plugins(bevy_camera_bms_bindings::BevyCameraScriptingPlugin);
+    bindings.push(("bevy_camera", |app: &mut App| {
+        app.add_plugins(bevy_camera_bms_bindings::BevyCameraScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_color")]
-    app.add_plugins(bevy_color_bms_bindings::BevyColorScriptingPlugin);
+    bindings.push(("bevy_color", |app: &mut App| {
+        app.add_plugins(bevy_color_bms_bindings::BevyColorScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_core_pipeline")]
-    app.add_plugins(bevy_core_pipeline_bms_bindings::BevyCorePipelineScriptingPlugin);
+    bindings.push(("bevy_core_pipeline", |app: &mut App| {
+        app.add_plugins(bevy_core_pipeline_bms_bindings::BevyCorePipelineScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_ecs")]
-    app.add_plugins(bevy_ecs_bms_bindings::BevyEcsScriptingPlugin);
+    bindings.push(("bevy_ecs", |app: &mut App| {
+        app.add_plugins(bevy_ecs_bms_bindings::BevyEcsScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_gizmos")]
-    app.add_plugins(bevy_gizmos_bms_bindings::BevyGizmosScriptingPlugin);
+    bindings.push(("bevy_gizmos", |app: &mut App| {
+        app.add_plugins(bevy_gizmos_bms_bindings::BevyGizmosScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_gltf")]
-    app.add_plugins(bevy_gltf_bms_bindings::BevyGltfScriptingPlugin);
+    bindings.push(("bevy_gltf", |app: &mut App| {
+        app.add_plugins(bevy_gltf_bms_bindings::BevyGltfScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_image")]
-    app.add_plugins(bevy_image_bms_bindings::BevyImageScriptingPlugin);
+    bindings.push(("bevy_image", |app: &mut App| {
+        app.add_plugins(bevy_image_bms_bindings::BevyImageScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_input")]
-    app.add_plugins(bevy_input_bms_bindings::BevyInputScriptingPlugin);
+    bindings.push(("bevy_input", |app: &mut App| {
+        app.add_plugins(bevy_input_bms_bindings::BevyInputScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_input_focus")]
-    app.add_plugins(bevy_input_focus_bms_bindings::BevyInputFocusScriptingPlugin);
+    bindings.push(("bevy_input_focus", |app: &mut App| {
+        app.add_plugins(bevy_input_focus_bms_bindings::BevyInputFocusScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_light")]
-    app.add_plugins(bevy_light_bms_bindings::BevyLightScriptingPlugin);
+    bindings.push(("bevy_light", |app: &mut App| {
+        app.add_plugins(bevy_light_bms_bindings::BevyLightScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_math")]
-    app.add_plugins(bevy_math_bms_bindings::BevyMathScriptingPlugin);
+    bindings.push(("bevy_math", |app: &mut App| {
+        app.add_plugins(bevy_math_bms_bindings::BevyMathScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_mesh")]
-    app.add_plugins(bevy_mesh_bms_bindings::BevyMeshScriptingPlugin);
+    bindings.push(("bevy_mesh", |app: &mut App| {
+        app.add_plugins(bevy_mesh_bms_bindings::BevyMeshScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_pbr")]
-    app.add_plugins(bevy_pbr_bms_bindings::BevyPbrScriptingPlugin);
+    bindings.push(("bevy_pbr", |app: &mut App| {
+        app.add_plugins(bevy_pbr_bms_bindings::BevyPbrScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_picking")]
-    app.add_plugins(bevy_picking_bms_bindings::BevyPickingScriptingPlugin);
+    bindings.push(("bevy_picking", |app: &mut App| {
+        app.add_plugins(bevy_picking_bms_bindings::BevyPickingScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_post_process")]
-    app.add_plugins(bevy_post_process_bms_bindings::BevyPostProcessScriptingPlugin);
+    bindings.push(("bevy_post_process", |app: &mut App| {
+        app.add_plugins(bevy_post_process_bms_bindings::BevyPostProcessScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_reflect")]
-    app.add_plugins(bevy_reflect_bms_bindings::BevyReflectScriptingPlugin);
+    bindings.push(("bevy_reflect", |app: &mut App| {
+        app.add_plugins(bevy_reflect_bms_bindings::BevyReflectScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_render")]
-    app.add_plugins(bevy_render_bms_bindings::BevyRenderScriptingPlugin);
+    bindings.push(("bevy_render", |app: &mut App| {
+        app.add_plugins(bevy_render_bms_bindings::BevyRenderScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_scene")]
-    app.add_plugins(bevy_scene_bms_bindings::BevySceneScriptingPlugin);
+    bindings.push(("bevy_scene", |app: &mut App| {
+        app.add_plugins(bevy_scene_bms_bindings::BevySceneScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_sprite")]
-    app.add_plugins(bevy_sprite_bms_bindings::BevySpriteScriptingPlugin);
+    bindings.push(("bevy_sprite", |app: &mut App| {
+        app.add_plugins(bevy_sprite_bms_bindings::BevySpriteScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_sprite_render")]
-    app.add_plugins(bevy_sprite_renderer_bms_bindings::BevySpriteRendererScriptingPlugin);
+    bindings.push(("bevy_sprite_render", |app: &mut App| {
+        app.add_plugins(bevy_sprite_renderer_bms_bindings::BevySpriteRendererScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_text")]
-    app.add_plugins(bevy_text_bms_bindings::BevyTextScriptingPlugin);
+    bindings.push(("bevy_text", |app: &mut App| {
+        app.add_plugins(bevy_text_bms_bindings::BevyTextScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_time")]
-    app.add_plugins(bevy_time_bms_bindings::BevyTimeScriptingPlugin);
+    bindings.push(("bevy_time", |app: &mut App| {
+        app.add_plugins(bevy_time_bms_bindings::BevyTimeScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_transform")]
-    app.add_plugins(bevy_transform_bms_bindings::BevyTransformScriptingPlugin);
+    bindings.push(("bevy_transform", |app: &mut App| {
+        app.add_plugins(bevy_transform_bms_bindings::BevyTransformScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_ui")]
-    app.add_plugins(bevy_ui_bms_bindings::BevyUiScriptingPlugin);
+    bindings.push(("bevy_ui", |app: &mut App| {
+        app.add_plugins(bevy_ui_bms_bindings::BevyUiScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_ui_render")]
-    app.add_plugins(bevy_ui_renderer_bms_bindings::BevyUiRendererScriptingPlugin);
+    bindings.push(("bevy_ui_render", |app: &mut App| {
+        app.add_plugins(bevy_ui_renderer_bms_bindings::BevyUiRendererScriptingPlugin);
+    }));
     #[cfg(feature = "bevy_ui_widgets")]
-    app.add_plugins(bevy_ui_widgets_bms_bindings::BevyUiWidgetsScriptingPlugin);
+    bindings.push(("bevy_ui_widgets", |app: &mut App| {
+        app.add_plugins(bevy_ui_widgets_bms_bindings::BevyUiWidgetsScriptingPlugin);
+    }));
+    bindings
 }
 
 #[script_bindings(
@@ -580,6 +654,154 @@ impl ReflectReference {
         reference.variant_name(world)
     }
 
+    /// Checks if the value the reference points to still exists.
+    ///
+    /// References to garbage collected values, components of despawned entities or removed resources are not valid.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The reference to check.
+    /// Returns:
+    /// * `is_valid`: `true` if the reference can be accessed.
+    fn is_valid(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+    ) -> Result<bool, InteropError> {
+        profiling::function_scope!("is_valid");
+        let world = ctxt.world()?;
+        Ok(reference.is_valid(world))
+    }
+
+    /// Creates a weak reference to the same value, which does not keep allocated values alive.
+    ///
+    /// Arguments:
+    /// * `reference`: The reference to downgrade.
+    /// Returns:
+    /// * `weak_reference`: The weak reference, which can be upgraded back while the value is alive.
+    fn downgrade(reference: ReflectReference) -> V<WeakReflectReference> {
+        profiling::function_scope!("downgrade");
+        V(reference.downgrade())
+    }
+
+    /// Checks if the reference is an enum currently set to the given variant.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The reference to check.
+    /// * `name`: The name of the variant to check for.
+    /// Returns:
+    /// * `is_variant`: `true` if the reference is an enum with the given variant, `false` otherwise.
+    fn is_variant(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+        name: String,
+    ) -> Result<bool, InteropError> {
+        profiling::function_scope!("is_variant");
+        let world = ctxt.world()?;
+        Ok(reference
+            .variant_name(world)?
+            .is_some_and(|variant| variant == name))
+    }
+
+    /// Retrieves references to the fields of the current variant, if the reference is an enum.
+    ///
+    /// Struct variants return a map of field names to references, tuple variants return a list of references, and unit variants return an empty list.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The reference to get the variant fields of.
+    /// Returns:
+    /// * `fields`: The fields of the variant, if the reference is an enum.
+    fn variant_fields(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+    ) -> Result<Option<ScriptValue>, InteropError> {
+        profiling::function_scope!("variant_fields");
+        let world = ctxt.world()?;
+        let fields = reference.with_reflect(world.clone(), |s| {
+            s.reflect_ref().as_enum().ok().map(|enum_ref| {
+                let parts = (0..enum_ref.field_len())
+                    .map(|idx| match enum_ref.name_at(idx) {
+                        Some(name) => ReferencePart::StringAccess(name.to_owned().into()),
+                        None => ReferencePart::IntegerAccess(idx as i64, false),
+                    })
+                    .collect::<Vec<_>>();
+                (enum_ref.variant_type() == VariantType::Struct, parts)
+            })
+        })?;
+
+        let Some((is_struct, parts)) = fields else {
+            return Ok(None);
+        };
+
+        let mut named = HashMap::<String, ScriptValue>::default();
+        let mut positional = VecDeque::with_capacity(parts.len());
+        for part in parts {
+            let key = match &part {
+                ReferencePart::StringAccess(name) => Some(name.to_string()),
+                _ => None,
+            };
+            let mut field = reference.clone();
+            field.push_path(part);
+            let field = ReflectReference::into_script_ref(field, world.clone())?;
+            match key {
+                Some(key) => {
+                    named.insert(key, field);
+                }
+                None => positional.push_back(field),
+            }
+        }
+
+        Ok(Some(if is_struct {
+            ScriptValue::Map(named)
+        } else {
+            ScriptValue::List(positional)
+        }))
+    }
+
+    /// Copies the value behind the reference into plain script values, i.e. tables in Lua or maps in Rhai.
+    ///
+    /// Structs and maps become maps, lists, sets and tuples become lists, and enums become maps with a `variant` key.
+    /// Reading the copy does not go through the reference again, which makes it useful for bulk reads and snapshots.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The reference to convert.
+    /// Returns:
+    /// * `value`: The converted value.
+    fn to_table(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+    ) -> Result<ScriptValue, InteropError> {
+        profiling::function_scope!("to_table");
+        let world = ctxt.world()?;
+        let one_indexed = ctxt.convert_to_0_indexed();
+        reference.with_reflect(world.clone(), |r| {
+            reflect_into_script_value(r, one_indexed, world)
+        })?
+    }
+
+    /// Copies the value behind the reference into plain script values.
+    ///
+    /// Identical to `to_table`, but reads better for values which are not converted into tables, such as primitives.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The reference to convert.
+    /// Returns:
+    /// * `value`: The converted value.
+    fn to_value(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+    ) -> Result<ScriptValue, InteropError> {
+        profiling::function_scope!("to_value");
+        let world = ctxt.world()?;
+        let one_indexed = ctxt.convert_to_0_indexed();
+        reference.with_reflect(world.clone(), |r| {
+            reflect_into_script_value(r, one_indexed, world)
+        })?
+    }
+
     /// Displays this reference and its contents if possible.
     ///
     /// This is useful for debugging and logging.
@@ -706,6 +928,8 @@ impl ReflectReference {
 
     /// Inserts the value into the reference at the specified index, if the reference is an appropriate container type.
     ///
+    /// For sets, the key is the value to insert and the value argument can be omitted.
+    ///
     /// Arguments:
     /// * `ctxt`: The function call context.
     /// * `reference`: The reference to insert the value into.
@@ -717,7 +941,7 @@ impl ReflectReference {
         ctxt: FunctionCallContext,
         reference: ReflectReference,
         key: ScriptValue,
-        value: ScriptValue,
+        value: Option<ScriptValue>,
     ) -> Result<(), InteropError> {
         profiling::function_scope!("insert");
         let world = ctxt.world()?;
@@ -731,6 +955,14 @@ impl ReflectReference {
 
         let mut key = <Box<dyn PartialReflect>>::from_script_ref(key_type_id, key, world.clone())?;
 
+        let is_set = reference.with_reflect(world.clone(), |r| {
+            matches!(r.reflect_ref(), ReflectRef::Set(_))
+        })?;
+        if is_set {
+            return reference.with_reflect_mut(world, |s| s.try_push_boxed(key))?;
+        }
+        let value = value.unwrap_or(ScriptValue::Unit);
+
         if ctxt.convert_to_0_indexed() {
             key.convert_to_0_indexed_key();
         }
@@ -826,6 +1058,9 @@ impl ReflectReference {
     ///
     /// The iterator function should be called until it returns `nil` to signal the end of the iteration.
     ///
+    /// Maps return their values and sets return their elements, in the order of the container at the time `iter` was called,
+    /// which means ordered maps such as `BTreeMap` are iterated in key order.
+    ///
     /// Arguments:
     /// * `ctxt`: The function call context.
     /// * `reference`: The reference to iterate over.
@@ -838,7 +1073,7 @@ impl ReflectReference {
         profiling::function_scope!("iter");
         let world = ctxt.world()?;
         let mut len = reference.len(world.clone())?.unwrap_or_default();
-        let mut infinite_iter = reference.into_iter_infinite();
+        let mut infinite_iter = reference.into_iter_infinite_keyed(world.clone())?;
         let iter_function = move || {
             // world is not thread safe, we can't capture it in the closure
             // or it will also be non-thread safe
@@ -858,6 +1093,119 @@ impl ReflectReference {
         Ok(iter_function.into_dynamic_script_function_mut())
     }
 
+    /// Checks if the key is present in a map, or the value is present in a set.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The map or set to check.
+    /// * `key`: The key or value to look for.
+    /// Returns:
+    /// * `contains`: Whether the key or value is present.
+    fn contains(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+        key: ScriptValue,
+    ) -> Result<bool, InteropError> {
+        profiling::function_scope!("contains");
+        let world = ctxt.world()?;
+        let key_type_id = reference.key_type_id(world.clone())?.ok_or_else(|| {
+            InteropError::unsupported_operation(
+                reference.tail_type_id(world.clone()).unwrap_or_default(),
+                Some(Box::new(key.clone())),
+                "Could not get key type id. Are you trying to look up elements in a type that's not a map or set?".to_owned(),
+            )
+        })?;
+        let key = <Box<dyn PartialReflect>>::from_script_ref(key_type_id, key, world.clone())?;
+        reference.with_reflect(world, |s| s.try_contains(key.as_ref()))?
+    }
+
+    /// Retrieves copies of the keys of a map, or the elements of a set, in iteration order.
+    ///
+    /// Ordered maps such as `BTreeMap` return their keys in order.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The map or set to get the keys of.
+    /// Returns:
+    /// * `keys`: The keys of the container.
+    fn keys(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+    ) -> Result<Vec<ScriptValue>, InteropError> {
+        profiling::function_scope!("keys");
+        let world = ctxt.world()?;
+        let keys = reference
+            .with_reflect(world.clone(), |s| s.container_keys())?
+            .ok_or_else(|| {
+                InteropError::unsupported_operation(
+                    reference.tail_type_id(world.clone()).unwrap_or_default(),
+                    None,
+                    "keys".to_owned(),
+                )
+            })?;
+        keys.into_iter()
+            .map(|key| {
+                let reference = {
+                    let allocator = world.allocator();
+                    let mut allocator = allocator.write();
+                    ReflectReference::new_allocated_boxed_parial_reflect(key, &mut allocator)?
+                };
+                ReflectReference::into_script_ref(reference, world.clone())
+            })
+            .collect()
+    }
+
+    /// Creates a new set containing the elements present in either set.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The left hand side set.
+    /// * `other`: The right hand side set, either a reference or a list of values.
+    /// Returns:
+    /// * `union`: A new set with the elements of both sets.
+    fn union(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+        other: ScriptValue,
+    ) -> Result<ReflectReference, InteropError> {
+        profiling::function_scope!("union");
+        combine_sets(ctxt, reference, other, SetOperation::Union)
+    }
+
+    /// Creates a new set containing the elements present in both sets.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The left hand side set.
+    /// * `other`: The right hand side set, either a reference or a list of values.
+    /// Returns:
+    /// * `intersection`: A new set with the elements present in both sets.
+    fn intersection(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+        other: ScriptValue,
+    ) -> Result<ReflectReference, InteropError> {
+        profiling::function_scope!("intersection");
+        combine_sets(ctxt, reference, other, SetOperation::Intersection)
+    }
+
+    /// Creates a new set containing the elements of the left set which are not present in the right set.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `reference`: The left hand side set.
+    /// * `other`: The right hand side set, either a reference or a list of values.
+    /// Returns:
+    /// * `difference`: A new set with the elements only present in the left set.
+    fn difference(
+        ctxt: FunctionCallContext,
+        reference: ReflectReference,
+        other: ScriptValue,
+    ) -> Result<ReflectReference, InteropError> {
+        profiling::function_scope!("difference");
+        combine_sets(ctxt, reference, other, SetOperation::Difference)
+    }
+
     /// Lists the functions available on the reference.
     ///
     /// Arguments:
@@ -882,6 +1230,46 @@ impl ReflectReference {
     }
 }
 
+/// Combines a set with another set of the same type into a newly allocated set
+fn combine_sets(
+    ctxt: FunctionCallContext,
+    reference: ReflectReference,
+    other: ScriptValue,
+    operation: SetOperation,
+) -> Result<ReflectReference, InteropError> {
+    let world = ctxt.world()?;
+    let set_type_id = reference.tail_type_id(world.clone())?.ok_or_else(|| {
+        InteropError::unsupported_operation(
+            None,
+            Some(Box::new(other.clone())),
+            format!("{operation:?}"),
+        )
+    })?;
+    let other = <Box<dyn PartialReflect>>::from_script_ref(set_type_id, other, world.clone())?;
+    let mut result = reference.with_reflect(world.clone(), |s| {
+        <dyn PartialReflect>::from_reflect_or_clone(s, world.clone())
+    })??;
+    result.try_set_operation(other.as_ref(), operation)?;
+    let allocator = world.allocator();
+    let mut allocator = allocator.write();
+    ReflectReference::new_allocated_boxed_parial_reflect(result, &mut allocator)
+}
+
+/// Builds a value of the given type from plain script values and allocates it
+fn construct_from_table(
+    ctxt: FunctionCallContext,
+    type_id: std::any::TypeId,
+    table: ScriptValue,
+) -> Result<ReflectReference, InteropError> {
+    let world = ctxt.world()?;
+    let value =
+        script_value_into_reflect(table, type_id, ctxt.convert_to_0_indexed(), world.clone())?;
+    let value = <dyn PartialReflect>::from_reflect_or_clone(value.as_ref(), world.clone())?;
+    let allocator = world.allocator();
+    let mut allocator = allocator.write();
+    ReflectReference::new_allocated_boxed_parial_reflect(value, &mut allocator)
+}
+
 #[script_bindings(
     remote,
     bms_bindings_path = "bevy_mod_scripting_bindings",
@@ -910,6 +1298,25 @@ impl ScriptTypeRegistration {
         profiling::function_scope!("short_name");
         registration.short_name().to_string()
     }
+
+    /// Builds a value of this type from plain script values, as produced by `to_table`.
+    ///
+    /// Nested tables are converted into nested values, see `construct` for the expected format of structs and enums.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `registration`: The type to build.
+    /// * `table`: The values to build the type from.
+    /// Returns:
+    /// * `value`: A reference to the built value.
+    fn from_table(
+        ctxt: FunctionCallContext,
+        registration: R<ScriptTypeRegistration>,
+        table: ScriptValue,
+    ) -> Result<ReflectReference, InteropError> {
+        profiling::function_scope!("from_table");
+        construct_from_table(ctxt, registration.type_id(), table)
+    }
 }
 
 #[script_bindings(
@@ -940,6 +1347,25 @@ impl ScriptComponentRegistration {
         profiling::function_scope!("short_name");
         registration.type_registration().short_name()
     }
+
+    /// Builds a value of this type from plain script values, as produced by `to_table`.
+    ///
+    /// Nested tables are converted into nested values, see `construct` for the expected format of structs and enums.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `registration`: The type to build.
+    /// * `table`: The values to build the type from.
+    /// Returns:
+    /// * `value`: A reference to the built value.
+    fn from_table(
+        ctxt: FunctionCallContext,
+        registration: R<ScriptComponentRegistration>,
+        table: ScriptValue,
+    ) -> Result<ReflectReference, InteropError> {
+        profiling::function_scope!("from_table");
+        construct_from_table(ctxt, registration.type_registration().type_id(), table)
+    }
 }
 
 #[script_bindings(
@@ -970,6 +1396,25 @@ impl ScriptResourceRegistration {
         profiling::function_scope!("short_name");
         registration.type_registration().short_name()
     }
+
+    /// Builds a value of this type from plain script values, as produced by `to_table`.
+    ///
+    /// Nested tables are converted into nested values, see `construct` for the expected format of structs and enums.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `registration`: The type to build.
+    /// * `table`: The values to build the type from.
+    /// Returns:
+    /// * `value`: A reference to the built value.
+    fn from_table(
+        ctxt: FunctionCallContext,
+        registration: R<ScriptResourceRegistration>,
+        table: ScriptValue,
+    ) -> Result<ReflectReference, InteropError> {
+        profiling::function_scope!("from_table");
+        construct_from_table(ctxt, registration.type_registration().type_id(), table)
+    }
 }
 
 #[script_bindings(
@@ -1335,6 +1780,418 @@ impl Handle<ScriptAsset> {
     }
 }
 
+/// Creates the error returned when big integer arithmetic overflows
+fn big_int_overflow(expression: String) -> InteropError {
+    InteropError::integer_overflow(expression, std::any::TypeId::of::<BigInt>())
+}
+
+#[script_bindings(
+    remote,
+    bms_bindings_path = "bevy_mod_scripting_bindings",
+    name = "big_int_functions",
+    core
+)]
+impl BigInt {
+    /// Creates a new big integer from an integer, a decimal string or another big integer.
+    ///
+    /// Arguments:
+    /// * `value`: The value to convert.
+    /// Returns:
+    /// * `big_int`: The new big integer.
+    fn new(value: BigInt) -> BigInt {
+        profiling::function_scope!("new");
+        value
+    }
+
+    /// Converts the big integer into a script integer, failing if it does not fit.
+    ///
+    /// Arguments:
+    /// * `value`: The big integer to convert.
+    /// Returns:
+    /// * `integer`: The integer value.
+    fn to_integer(value: BigInt) -> Result<i64, InteropError> {
+        profiling::function_scope!("to_integer");
+        i64::try_from(value.0)
+            .map_err(|_| InteropError::integer_overflow(value, std::any::TypeId::of::<i64>()))
+    }
+
+    /// Adds two big integers.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `sum`: The sum of both values.
+    fn add(a: BigInt, b: BigInt) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("add");
+        a.0.checked_add(b.0)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} + {b}")))
+    }
+
+    /// Subtracts two big integers.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `difference`: The difference of both values.
+    fn sub(a: BigInt, b: BigInt) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("sub");
+        a.0.checked_sub(b.0)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} - {b}")))
+    }
+
+    /// Multiplies two big integers.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `product`: The product of both values.
+    fn mul(a: BigInt, b: BigInt) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("mul");
+        a.0.checked_mul(b.0)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} * {b}")))
+    }
+
+    /// Divides two big integers, rounding towards zero.
+    ///
+    /// Arguments:
+    /// * `a`: The dividend.
+    /// * `b`: The divisor.
+    /// Returns:
+    /// * `quotient`: The quotient of both values.
+    fn div(a: BigInt, b: BigInt) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("div");
+        if b.0 == 0 {
+            return Err(InteropError::str(
+                "Attempted to divide a big integer by zero",
+            ));
+        }
+        a.0.checked_div(b.0)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} / {b}")))
+    }
+
+    /// Calculates the remainder of dividing two big integers.
+    ///
+    /// Arguments:
+    /// * `a`: The dividend.
+    /// * `b`: The divisor.
+    /// Returns:
+    /// * `remainder`: The remainder of the division.
+    fn rem(a: BigInt, b: BigInt) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("rem");
+        if b.0 == 0 {
+            return Err(InteropError::str(
+                "Attempted to divide a big integer by zero",
+            ));
+        }
+        a.0.checked_rem(b.0)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} % {b}")))
+    }
+
+    /// Negates a big integer.
+    ///
+    /// Arguments:
+    /// * `a`: The value to negate.
+    /// Returns:
+    /// * `negated`: The negated value.
+    fn neg(a: BigInt) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("neg");
+        a.0.checked_neg()
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("-{a}")))
+    }
+
+    /// Raises a big integer to a power.
+    ///
+    /// Arguments:
+    /// * `a`: The base.
+    /// * `exponent`: The exponent.
+    /// Returns:
+    /// * `power`: The base raised to the exponent.
+    fn pow(a: BigInt, exponent: u32) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("pow");
+        a.0.checked_pow(exponent)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} ^ {exponent}")))
+    }
+
+    /// Checks if two big integers are equal.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `equal`: Whether both values are equal.
+    fn eq(a: BigInt, b: BigInt) -> bool {
+        profiling::function_scope!("eq");
+        a == b
+    }
+
+    /// Checks if a big integer is smaller than another.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `less_than`: Whether `a` is smaller than `b`.
+    fn lt(a: BigInt, b: BigInt) -> bool {
+        profiling::function_scope!("lt");
+        a < b
+    }
+
+    /// Checks if a big integer is smaller than or equal to another.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `less_or_equal`: Whether `a` is smaller than or equal to `b`.
+    fn le(a: BigInt, b: BigInt) -> bool {
+        profiling::function_scope!("le");
+        a <= b
+    }
+
+    /// Checks if a big integer is greater than another.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `greater_than`: Whether `a` is greater than `b`.
+    fn gt(a: BigInt, b: BigInt) -> bool {
+        profiling::function_scope!("gt");
+        a > b
+    }
+
+    /// Checks if a big integer is greater than or equal to another.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `greater_or_equal`: Whether `a` is greater than or equal to `b`.
+    fn ge(a: BigInt, b: BigInt) -> bool {
+        profiling::function_scope!("ge");
+        a >= b
+    }
+
+    /// Divides two big integers, rounding towards negative infinity.
+    ///
+    /// Arguments:
+    /// * `a`: The dividend.
+    /// * `b`: The divisor.
+    /// Returns:
+    /// * `quotient`: The floored quotient of both values.
+    fn idiv(a: BigInt, b: BigInt) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("idiv");
+        if b.0 == 0 {
+            return Err(InteropError::str(
+                "Attempted to divide a big integer by zero",
+            ));
+        }
+        let quotient =
+            a.0.checked_div(b.0)
+                .ok_or_else(|| big_int_overflow(format!("{a} // {b}")))?;
+        if a.0 % b.0 != 0 && ((a.0 < 0) != (b.0 < 0)) {
+            Ok(BigInt(quotient - 1))
+        } else {
+            Ok(BigInt(quotient))
+        }
+    }
+
+    /// Calculates the bitwise and of two big integers.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `result`: The bitwise and of both values.
+    fn bitand(a: BigInt, b: BigInt) -> BigInt {
+        profiling::function_scope!("bitand");
+        BigInt(a.0 & b.0)
+    }
+
+    /// Calculates the bitwise or of two big integers.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `result`: The bitwise or of both values.
+    fn bitor(a: BigInt, b: BigInt) -> BigInt {
+        profiling::function_scope!("bitor");
+        BigInt(a.0 | b.0)
+    }
+
+    /// Calculates the bitwise exclusive or of two big integers.
+    ///
+    /// Arguments:
+    /// * `a`: The left hand side.
+    /// * `b`: The right hand side.
+    /// Returns:
+    /// * `result`: The bitwise exclusive or of both values.
+    fn bitxor(a: BigInt, b: BigInt) -> BigInt {
+        profiling::function_scope!("bitxor");
+        BigInt(a.0 ^ b.0)
+    }
+
+    /// Shifts the bits of a big integer to the left.
+    ///
+    /// Arguments:
+    /// * `a`: The value to shift.
+    /// * `bits`: The number of bits to shift by.
+    /// Returns:
+    /// * `result`: The shifted value.
+    fn shl(a: BigInt, bits: u32) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("shl");
+        a.0.checked_shl(bits)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} << {bits}")))
+    }
+
+    /// Shifts the bits of a big integer to the right, preserving the sign.
+    ///
+    /// Arguments:
+    /// * `a`: The value to shift.
+    /// * `bits`: The number of bits to shift by.
+    /// Returns:
+    /// * `result`: The shifted value.
+    fn shr(a: BigInt, bits: u32) -> Result<BigInt, InteropError> {
+        profiling::function_scope!("shr");
+        a.0.checked_shr(bits)
+            .map(BigInt)
+            .ok_or_else(|| big_int_overflow(format!("{a} >> {bits}")))
+    }
+
+    /// Inverts the bits of a big integer.
+    ///
+    /// Arguments:
+    /// * `a`: The value to invert.
+    /// Returns:
+    /// * `result`: The inverted value.
+    fn not(a: BigInt) -> BigInt {
+        profiling::function_scope!("not");
+        BigInt(!a.0)
+    }
+
+    /// Displays the big integer in decimal notation.
+    ///
+    /// Arguments:
+    /// * `a`: The value to display.
+    /// Returns:
+    /// * `display`: The decimal representation.
+    fn display(a: BigInt) -> String {
+        profiling::function_scope!("display");
+        a.to_string()
+    }
+}
+
+#[script_bindings(
+    remote,
+    bms_bindings_path = "bevy_mod_scripting_bindings",
+    name = "script_result_functions",
+    core
+)]
+impl ScriptResult {
+    /// Checks if the result holds a value.
+    ///
+    /// Arguments:
+    /// * `result`: The result to check.
+    /// Returns:
+    /// * `is_ok`: `true` if the call succeeded.
+    fn is_ok(result: R<ScriptResult>) -> bool {
+        profiling::function_scope!("is_ok");
+        result.0.0.is_ok()
+    }
+
+    /// Checks if the result holds an error.
+    ///
+    /// Arguments:
+    /// * `result`: The result to check.
+    /// Returns:
+    /// * `is_err`: `true` if the call failed.
+    fn is_err(result: R<ScriptResult>) -> bool {
+        profiling::function_scope!("is_err");
+        result.0.0.is_err()
+    }
+
+    /// Retrieves the value of the result, throwing the error if the call failed.
+    ///
+    /// Arguments:
+    /// * `result`: The result to unwrap.
+    /// Returns:
+    /// * `value`: The value returned by the call.
+    fn unwrap(result: R<ScriptResult>) -> Result<ScriptValue, InteropError> {
+        profiling::function_scope!("unwrap");
+        result.0.0.clone()
+    }
+
+    /// Retrieves the value of the result, or the given default if the call failed.
+    ///
+    /// Arguments:
+    /// * `result`: The result to unwrap.
+    /// * `default`: The value to return if the call failed.
+    /// Returns:
+    /// * `value`: The value returned by the call or the default.
+    fn unwrap_or(result: R<ScriptResult>, default: ScriptValue) -> ScriptValue {
+        profiling::function_scope!("unwrap_or");
+        result.0.0.clone().unwrap_or(default)
+    }
+
+    /// Retrieves the error message of the result, if the call failed.
+    ///
+    /// Arguments:
+    /// * `result`: The result to retrieve the error from.
+    /// Returns:
+    /// * `error`: The error message, if the call failed.
+    fn err(result: R<ScriptResult>) -> Option<String> {
+        profiling::function_scope!("err");
+        result.0.0.as_ref().err().map(ToString::to_string)
+    }
+}
+
+#[script_bindings(
+    remote,
+    bms_bindings_path = "bevy_mod_scripting_bindings",
+    name = "weak_reflect_reference_functions",
+    core
+)]
+impl WeakReflectReference {
+    /// Retrieves a strong reference to the value, if it was not garbage collected yet.
+    ///
+    /// Arguments:
+    /// * `weak_reference`: The weak reference to upgrade.
+    /// Returns:
+    /// * `reference`: The strong reference, or nothing if the value no longer exists.
+    fn upgrade(weak_reference: R<WeakReflectReference>) -> Option<ReflectReference> {
+        profiling::function_scope!("upgrade");
+        weak_reference.upgrade()
+    }
+
+    /// Checks if the weak reference can be upgraded to a valid reference.
+    ///
+    /// Arguments:
+    /// * `ctxt`: The function call context.
+    /// * `weak_reference`: The weak reference to check.
+    /// Returns:
+    /// * `is_valid`: `true` if the value still exists.
+    fn is_valid(
+        ctxt: FunctionCallContext,
+        weak_reference: R<WeakReflectReference>,
+    ) -> Result<bool, InteropError> {
+        profiling::function_scope!("is_valid");
+        let world = ctxt.world()?;
+        Ok(weak_reference.is_valid(world))
+    }
+}
+
 /// globals which are being registered at lower level within each language plugin.
 #[script_bindings(
     remote,
@@ -1376,6 +2233,88 @@ impl GlobalNamespace {
     }
 }
 
+#[script_bindings(
+    remote,
+    bms_bindings_path = "bevy_mod_scripting_bindings",
+    name = "json_functions",
+    core
+)]
+impl Json {
+    /// Encodes a value into a JSON string.
+    ///
+    /// References are encoded together with the path of their type, so they can be decoded back into the same type.
+    ///
+    /// Arguments:
+    /// * `value`: The value to encode.
+    /// * `pretty`: Whether to pretty print the output, defaults to `false`.
+    /// Returns:
+    /// * `json`: The encoded JSON string.
+    fn encode(
+        ctxt: FunctionCallContext,
+        value: ScriptValue,
+        pretty: Option<bool>,
+    ) -> Result<String, InteropError> {
+        profiling::function_scope!("encode");
+        let world = ctxt.world()?;
+        Json::encode(&value, pretty.unwrap_or_default(), world)
+    }
+
+    /// Decodes a value from a JSON string.
+    ///
+    /// Objects with a single key equal to the path of a registered type are decoded into a reference to a value of that type.
+    ///
+    /// Arguments:
+    /// * `json`: The JSON string to decode.
+    /// Returns:
+    /// * `value`: The decoded value.
+    fn decode(ctxt: FunctionCallContext, json: String) -> Result<ScriptValue, InteropError> {
+        profiling::function_scope!("decode");
+        let world = ctxt.world()?;
+        Json::decode(&json, world)
+    }
+}
+
+#[script_bindings(
+    remote,
+    bms_bindings_path = "bevy_mod_scripting_bindings",
+    name = "ron_functions",
+    core
+)]
+impl Ron {
+    /// Encodes a value into a RON string.
+    ///
+    /// References are encoded together with the path of their type, so they can be decoded back into the same type.
+    ///
+    /// Arguments:
+    /// * `value`: The value to encode.
+    /// * `pretty`: Whether to pretty print the output, defaults to `false`.
+    /// Returns:
+    /// * `ron`: The encoded RON string.
+    fn encode(
+        ctxt: FunctionCallContext,
+        value: ScriptValue,
+        pretty: Option<bool>,
+    ) -> Result<String, InteropError> {
+        profiling::function_scope!("encode");
+        let world = ctxt.world()?;
+        Ron::encode(&value, pretty.unwrap_or_default(), world)
+    }
+
+    /// Decodes a value from a RON string.
+    ///
+    /// Maps with a single key equal to the path of a registered type are decoded into a reference to a value of that type.
+    ///
+    /// Arguments:
+    /// * `ron`: The RON string to decode.
+    /// Returns:
+    /// * `value`: The decoded value.
+    fn decode(ctxt: FunctionCallContext, ron: String) -> Result<ScriptValue, InteropError> {
+        profiling::function_scope!("decode");
+        let world = ctxt.world()?;
+        Ron::decode(&ron, world)
+    }
+}
+
 /// Globals registered by us
 #[script_bindings(
     remote,
@@ -1519,6 +2458,15 @@ pub fn register_core_functions(app: &mut App) {
 
         register_script_handle_functions(world);
 
+        register_big_int_functions(world);
+        register_script_result_functions(world);
+        // unwrapping a result should throw regardless of the error mode, otherwise results would nest
+        NamespaceBuilder::<ScriptResult>::new_unregistered(world)
+            .set_error_mode("unwrap", ErrorReturnMode::Throw);
+        register_weak_reflect_reference_functions(world);
+        register_json_functions(world);
+        register_ron_functions(world);
+
         register_global_namespace_functions(world);
         register_global_namespace_dummy_functions(world);
     }
@@ -0,0 +1,447 @@
+use indexmap::IndexMap;
+use ladfile::{
+    LadFieldOrVariableKind, LadFile, LadTypeDefinition, LadTypeId, LadTypeLayout, LadVariant,
+    ReflectionPrimitiveKind,
+};
+use serde_json::{Map, Value, json};
+
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The key `construct` payloads name the variant of an enum under
+const CONSTRUCT_VARIANT_KEY: &str = "variant";
+
+/// The format of the values a schema describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaFormat {
+    /// The format types are serialized in by bevy's reflection machinery, i.e. the format used by scene files:
+    /// - structs are objects with one property per field
+    /// - tuple structs are fixed length arrays, unless they contain a single field in which case they are represented by that field
+    /// - unit enum variants are strings containing the variant name, other variants are objects with a single property named after the variant
+    Reflection,
+    /// The payload format accepted by the `construct` script function:
+    /// - structs are objects with one property per field
+    /// - tuple structs are objects keyed by the 1-based position of each field
+    /// - enums are objects with a `variant` property containing the variant name, alongside the fields of that variant
+    Construct,
+}
+
+impl SchemaFormat {
+    /// All the formats schemas are generated in
+    pub const ALL: [SchemaFormat; 2] = [SchemaFormat::Reflection, SchemaFormat::Construct];
+
+    /// The name of the schema file generated in this format for a type with the given identifier
+    pub fn file_name(self, identifier: &str) -> String {
+        match self {
+            SchemaFormat::Reflection => format!("{identifier}.schema.json"),
+            SchemaFormat::Construct => format!("{identifier}.construct.schema.json"),
+        }
+    }
+}
+
+/// Converts every non-primitive type in the LAD file into standalone JSON schema documents, one for each [`SchemaFormat`].
+///
+/// Returns the file name of each schema alongside the schema itself, types refer to each other via relative `$ref`s to the schemas of the same format.
+pub fn convert_ladfile_to_json_schemas(ladfile: &LadFile) -> Vec<(String, Value)> {
+    // TODO: support generics, currently bevy doesn't let you track back generic instantiations to their definition
+    let mut identifiers = IndexMap::<&LadTypeId, &str>::new();
+    for (type_id, lad_type) in ladfile.types.iter() {
+        if ladfile.primitive_kind(type_id).is_some() {
+            continue;
+        }
+        if identifiers.values().any(|i| *i == lad_type.identifier) {
+            log::warn!(
+                "Type {type_id} shares the identifier {} with another type, ignoring.",
+                lad_type.identifier
+            );
+            continue;
+        }
+        identifiers.insert(type_id, &lad_type.identifier);
+    }
+
+    SchemaFormat::ALL
+        .into_iter()
+        .flat_map(|format| {
+            let converter = SchemaConverter {
+                ladfile,
+                identifiers: &identifiers,
+                format,
+            };
+            identifiers
+                .iter()
+                .filter_map(|(type_id, identifier)| {
+                    let lad_type = ladfile.types.get(*type_id)?;
+                    let file_name = format.file_name(identifier);
+                    let schema = converter.type_schema(lad_type, &file_name);
+                    Some((file_name, schema))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+struct SchemaConverter<'a> {
+    ladfile: &'a LadFile,
+    /// The identifier of each type which has a schema
+    identifiers: &'a IndexMap<&'a LadTypeId, &'a str>,
+    /// The format of the generated schemas
+    format: SchemaFormat,
+}
+
+impl SchemaConverter<'_> {
+    /// Creates the root schema document of a type
+    fn type_schema(&self, lad_type: &LadTypeDefinition, file_name: &str) -> Value {
+        let mut schema = Map::new();
+        schema.insert("$schema".into(), SCHEMA_DIALECT.into());
+        schema.insert("$id".into(), file_name.into());
+        schema.insert("$comment".into(), lad_type.path.as_str().into());
+        schema.insert("title".into(), lad_type.identifier.as_str().into());
+        annotate(
+            &mut schema,
+            lad_type.documentation.as_deref(),
+            lad_type.metadata.deprecated.as_deref(),
+        );
+
+        match (&lad_type.layout, self.format) {
+            // the layout is not known, anything goes
+            (LadTypeLayout::Opaque, _) => {}
+            (LadTypeLayout::MonoVariant(variant), SchemaFormat::Reflection) => {
+                if let Value::Object(shape) = self.variant_payload_schema(variant) {
+                    schema.extend(shape);
+                }
+            }
+            (LadTypeLayout::MonoVariant(variant), SchemaFormat::Construct) => {
+                schema.extend(self.construct_payload_schema(variant, None));
+            }
+            (LadTypeLayout::Enum(variants), format) => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| match format {
+                        SchemaFormat::Reflection => self.enum_variant_schema(variant),
+                        SchemaFormat::Construct => self.construct_enum_variant_schema(variant),
+                    })
+                    .collect::<Vec<_>>();
+                schema.insert("oneOf".into(), variants.into());
+            }
+        }
+
+        Value::Object(schema)
+    }
+
+    /// Creates the reflection schema of an enum variant, which is externally tagged by the variant name
+    fn enum_variant_schema(&self, variant: &LadVariant) -> Value {
+        let name = variant.name();
+        let mut schema = match variant {
+            LadVariant::Unit { .. } => json!({
+                "title": name,
+                "const": name,
+            }),
+            LadVariant::Struct { .. } | LadVariant::TupleStruct { .. } => json!({
+                "title": name,
+                "type": "object",
+                "properties": {
+                    name: self.variant_payload_schema(variant),
+                },
+                "required": [name],
+                "additionalProperties": false,
+            }),
+        };
+        if let Value::Object(schema) = &mut schema {
+            annotate(schema, variant.documentation(), variant.deprecated());
+        }
+        schema
+    }
+
+    /// Creates the `construct` payload schema of an enum variant, tagged by a `variant` property
+    fn construct_enum_variant_schema(&self, variant: &LadVariant) -> Value {
+        let mut schema = Map::new();
+        schema.insert("title".into(), variant.name().into());
+        schema.extend(self.construct_payload_schema(variant, Some(variant.name())));
+        annotate(&mut schema, variant.documentation(), variant.deprecated());
+        Value::Object(schema)
+    }
+
+    /// Creates the `construct` payload schema of a struct or enum variant, with named fields keyed by name and tuple fields keyed by their 1-based position
+    fn construct_payload_schema(
+        &self,
+        variant: &LadVariant,
+        tag: Option<&str>,
+    ) -> Map<String, Value> {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        if let Some(tag) = tag {
+            properties.insert(CONSTRUCT_VARIANT_KEY.into(), json!({ "const": tag }));
+            required.push(Value::from(CONSTRUCT_VARIANT_KEY));
+        }
+
+        let fields = match variant {
+            LadVariant::Struct { fields, .. } => fields
+                .iter()
+                .map(|f| {
+                    (
+                        f.name.clone(),
+                        &f.type_,
+                        f.documentation.as_deref(),
+                        f.deprecated.as_deref(),
+                    )
+                })
+                .collect(),
+            LadVariant::TupleStruct { fields, .. } => fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    (
+                        (i + 1).to_string(),
+                        &f.type_,
+                        f.documentation.as_deref(),
+                        f.deprecated.as_deref(),
+                    )
+                })
+                .collect(),
+            LadVariant::Unit { .. } => Vec::new(),
+        };
+        for (key, kind, documentation, deprecated) in fields {
+            // optional fields can be left out
+            if !matches!(kind, LadFieldOrVariableKind::Option(_)) {
+                required.push(Value::from(key.as_str()));
+            }
+            properties.insert(key, self.field_schema(kind, documentation, deprecated));
+        }
+
+        let mut schema = Map::new();
+        schema.insert("type".into(), "object".into());
+        schema.insert("properties".into(), properties.into());
+        if !required.is_empty() {
+            schema.insert("required".into(), required.into());
+        }
+        schema.insert("additionalProperties".into(), false.into());
+        schema
+    }
+
+    /// Creates the schema of the data contained in a struct or enum variant
+    fn variant_payload_schema(&self, variant: &LadVariant) -> Value {
+        match variant {
+            LadVariant::Struct { fields, .. } => {
+                let properties = fields
+                    .iter()
+                    .map(|f| {
+                        let schema = self.field_schema(
+                            &f.type_,
+                            f.documentation.as_deref(),
+                            f.deprecated.as_deref(),
+                        );
+                        (f.name.clone(), schema)
+                    })
+                    .collect::<Map<_, _>>();
+                // optional fields can be left out
+                let required = fields
+                    .iter()
+                    .filter(|f| !matches!(f.type_, LadFieldOrVariableKind::Option(_)))
+                    .map(|f| Value::from(f.name.as_str()))
+                    .collect::<Vec<_>>();
+
+                let mut schema = Map::new();
+                schema.insert("type".into(), "object".into());
+                schema.insert("properties".into(), properties.into());
+                if !required.is_empty() {
+                    schema.insert("required".into(), required.into());
+                }
+                schema.insert("additionalProperties".into(), false.into());
+                Value::Object(schema)
+            }
+            LadVariant::TupleStruct { fields, .. } => match fields.as_slice() {
+                // newtypes are represented by their only field
+                [field] => self.kind_schema(&field.type_),
+                fields => fixed_array(
+                    fields
+                        .iter()
+                        .map(|f| {
+                            self.field_schema(
+                                &f.type_,
+                                f.documentation.as_deref(),
+                                f.deprecated.as_deref(),
+                            )
+                        })
+                        .collect(),
+                ),
+            },
+            LadVariant::Unit { .. } => json!({ "type": "null" }),
+        }
+    }
+
+    /// Creates the schema of a field, annotated with its documentation
+    fn field_schema(
+        &self,
+        kind: &LadFieldOrVariableKind,
+        documentation: Option<&str>,
+        deprecated: Option<&str>,
+    ) -> Value {
+        let mut schema = self.kind_schema(kind);
+        if let Value::Object(schema) = &mut schema {
+            annotate(schema, documentation, deprecated);
+        }
+        schema
+    }
+
+    fn kind_schema(&self, kind: &LadFieldOrVariableKind) -> Value {
+        match kind {
+            LadFieldOrVariableKind::Primitive(prim) => primitive_schema(prim),
+            LadFieldOrVariableKind::Ref(type_id)
+            | LadFieldOrVariableKind::Mut(type_id)
+            | LadFieldOrVariableKind::Val(type_id) => self.type_id_schema(type_id),
+            LadFieldOrVariableKind::Option(inner) => {
+                any_of(vec![self.kind_schema(inner), json!({ "type": "null" })])
+            }
+            LadFieldOrVariableKind::Vec(inner) => json!({
+                "type": "array",
+                "items": self.kind_schema(inner),
+            }),
+            LadFieldOrVariableKind::Array(inner, len) => json!({
+                "type": "array",
+                "items": self.kind_schema(inner),
+                "minItems": len,
+                "maxItems": len,
+            }),
+            // only string keys can be represented in json
+            LadFieldOrVariableKind::HashMap(_, value) => json!({
+                "type": "object",
+                "additionalProperties": self.kind_schema(value),
+            }),
+            LadFieldOrVariableKind::HashSet(inner) => json!({
+                "type": "array",
+                "items": self.kind_schema(inner),
+                "uniqueItems": true,
+            }),
+            LadFieldOrVariableKind::InteropResult(inner) => self.kind_schema(inner),
+            LadFieldOrVariableKind::Tuple(kinds) if kinds.is_empty() => json!({ "type": "null" }),
+            LadFieldOrVariableKind::Tuple(kinds) => {
+                fixed_array(kinds.iter().map(|k| self.kind_schema(k)).collect())
+            }
+            LadFieldOrVariableKind::UntypedTuple => json!({ "type": "array" }),
+            LadFieldOrVariableKind::Union(kinds) => {
+                any_of(kinds.iter().map(|k| self.kind_schema(k)).collect())
+            }
+            LadFieldOrVariableKind::Unknown(_) => json!({}),
+        }
+    }
+
+    fn type_id_schema(&self, type_id: &LadTypeId) -> Value {
+        if let Some(prim) = self.ladfile.primitive_kind(type_id) {
+            return primitive_schema(prim);
+        }
+        match self.identifiers.get(type_id) {
+            Some(identifier) => json!({ "$ref": self.format.file_name(identifier) }),
+            None => json!({}),
+        }
+    }
+}
+
+fn primitive_schema(primitive: &ReflectionPrimitiveKind) -> Value {
+    match primitive {
+        ReflectionPrimitiveKind::Bool => json!({ "type": "boolean" }),
+        ReflectionPrimitiveKind::I8 => integer_in_range(i8::MIN.into(), i8::MAX.into()),
+        ReflectionPrimitiveKind::I16 => integer_in_range(i16::MIN.into(), i16::MAX.into()),
+        ReflectionPrimitiveKind::I32 => integer_in_range(i32::MIN.into(), i32::MAX.into()),
+        ReflectionPrimitiveKind::U8 => integer_in_range(u8::MIN.into(), u8::MAX.into()),
+        ReflectionPrimitiveKind::U16 => integer_in_range(u16::MIN.into(), u16::MAX.into()),
+        ReflectionPrimitiveKind::U32 => integer_in_range(u32::MIN.into(), u32::MAX.into()),
+        ReflectionPrimitiveKind::Isize
+        | ReflectionPrimitiveKind::I64
+        | ReflectionPrimitiveKind::I128 => json!({ "type": "integer" }),
+        ReflectionPrimitiveKind::Usize
+        | ReflectionPrimitiveKind::U64
+        | ReflectionPrimitiveKind::U128 => json!({ "type": "integer", "minimum": 0 }),
+        ReflectionPrimitiveKind::F32 | ReflectionPrimitiveKind::F64 => {
+            json!({ "type": "number" })
+        }
+        ReflectionPrimitiveKind::Char => json!({
+            "type": "string",
+            "minLength": 1,
+            "maxLength": 1,
+        }),
+        ReflectionPrimitiveKind::Str
+        | ReflectionPrimitiveKind::String
+        | ReflectionPrimitiveKind::OsString
+        | ReflectionPrimitiveKind::PathBuf => json!({ "type": "string" }),
+        ReflectionPrimitiveKind::Bytes => json!({
+            "type": "array",
+            "items": primitive_schema(&ReflectionPrimitiveKind::U8),
+        }),
+        // these cannot be represented as data
+        ReflectionPrimitiveKind::DynamicFunction
+        | ReflectionPrimitiveKind::DynamicFunctionMut
+        | ReflectionPrimitiveKind::FunctionCallContext
+        | ReflectionPrimitiveKind::ReflectReference
+        | ReflectionPrimitiveKind::ScriptValue
+        | ReflectionPrimitiveKind::External(_) => json!({}),
+    }
+}
+
+fn integer_in_range(min: i64, max: i64) -> Value {
+    json!({
+        "type": "integer",
+        "minimum": min,
+        "maximum": max,
+    })
+}
+
+fn fixed_array(items: Vec<Value>) -> Value {
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+/// Combines the schemas into an `anyOf` schema, removing duplicates
+fn any_of(schemas: Vec<Value>) -> Value {
+    let mut unique = Vec::with_capacity(schemas.len());
+    for schema in schemas {
+        if !unique.contains(&schema) {
+            unique.push(schema);
+        }
+    }
+    // the empty schema accepts everything
+    if unique.iter().any(|s| s == &json!({})) {
+        return json!({});
+    }
+    if unique.len() == 1 {
+        return unique.swap_remove(0);
+    }
+    json!({ "anyOf": unique })
+}
+
+/// Adds the description and deprecation annotations to a schema
+fn annotate(
+    schema: &mut Map<String, Value>,
+    documentation: Option<&str>,
+    deprecated: Option<&str>,
+) {
+    let mut docs = normalize_docs(documentation.unwrap_or_default());
+    if let Some(message) = deprecated {
+        let message = normalize_docs(message);
+        let notice = match message.is_empty() {
+            true => "Deprecated.".to_owned(),
+            false => format!("Deprecated: {message}"),
+        };
+        docs = match docs.is_empty() {
+            true => notice,
+            false => format!("{docs}\n\n{notice}"),
+        };
+        schema.insert("deprecated".into(), true.into());
+    }
+    if !docs.is_empty() {
+        schema.insert("description".into(), docs.into());
+    }
+}
+
+/// Strips the leading whitespace rust doc comments usually carry
+fn normalize_docs(docs: &str) -> String {
+    docs.lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_owned()
+}
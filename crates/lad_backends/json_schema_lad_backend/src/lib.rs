@@ -0,0 +1,36 @@
+//! Logic for generating JSON schemas of reflected types from a LAD file.
+
+use std::path::Path;
+
+use anyhow::Context;
+
+use crate::convert::convert_ladfile_to_json_schemas;
+
+mod convert;
+mod plugin;
+pub use convert::SchemaFormat;
+pub use plugin::*;
+
+/// Processess a LAD file and generates JSON schema files for each type in the given directory, one per [`SchemaFormat`].
+///
+/// Schemas reference each other using relative paths, so they should be kept in the same directory.
+pub fn generate_json_schema_files(
+    ladfile: &ladfile::LadFile,
+    output_dir: &Path,
+) -> Result<(), anyhow::Error> {
+    std::fs::create_dir_all(output_dir).with_context(|| "failed to create output directories")?;
+
+    for (file_name, schema) in convert_ladfile_to_json_schemas(ladfile) {
+        let output_path = output_dir.join(file_name);
+        let mut contents = serde_json::to_string_pretty(&schema)
+            .with_context(|| format!("failed to serialize schema: {}", output_path.display()))?;
+        contents.push('\n');
+        std::fs::write(&output_path, contents).with_context(|| {
+            format!(
+                "Failed to write JSON schema to file: {}",
+                output_path.display()
+            )
+        })?;
+    }
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use ladfile::LadFilePlugin;
+
+use crate::generate_json_schema_files;
+
+/// A plugin which generates JSON schemas for every reflected type into a specified directory when run
+#[derive(Clone)]
+pub struct JsonSchemaLadPlugin {
+    /// The directory, relative to the LAD file output directory, the schemas are generated into
+    pub directory: PathBuf,
+}
+
+impl Default for JsonSchemaLadPlugin {
+    fn default() -> Self {
+        Self {
+            directory: PathBuf::from("schemas"),
+        }
+    }
+}
+
+impl LadFilePlugin for JsonSchemaLadPlugin {
+    fn run(&self, ladfile: &ladfile::LadFile, path: &Path) -> Result<(), Box<dyn Error>> {
+        generate_json_schema_files(ladfile, &path.join(&self.directory))
+            .map_err(|e| e.into_boxed_dyn_error() as Box<dyn Error>)
+    }
+
+    fn name(&self) -> &'static str {
+        "JSON schema generator"
+    }
+}
@@ -1,6 +1,9 @@
 use bevy_mod_scripting_bindings_domain::ScriptOperatorNames;
 use indexmap::IndexMap;
-use ladfile::{LadFieldOrVariableKind, LadFile, LadFunction, LadTypeId, ReflectionPrimitiveKind};
+use ladfile::{
+    LadArgument, LadCallback, LadFieldOrVariableKind, LadFile, LadFunction, LadTypeId,
+    ReflectionPrimitiveKind,
+};
 
 use crate::{
     keywords::ForbiddenKeywords,
@@ -114,6 +117,22 @@ pub fn convert_ladfile_to_lua_declaration_file(
     }
     definition_file.modules.push(globals_module);
 
+    if !ladfile.callbacks.is_empty() {
+        let mut callbacks_module = LuaModule {
+            name: "callbacks".to_string(),
+            ..Default::default()
+        };
+        for (label, callback) in ladfile.callbacks.iter() {
+            match lad_callback_to_lua_function(ladfile, label, callback) {
+                Ok(converted) => callbacks_module.functions.push(converted),
+                Err(err) => {
+                    log::warn!("Error generating callback {label}: {err}. Ignoring callback.")
+                }
+            }
+        }
+        definition_file.modules.push(callbacks_module);
+    }
+
     Ok(definition_file)
 }
 
@@ -168,7 +187,7 @@ pub fn convert_polymorphic_type_to_lua_classes<'a>(
             match &lad_type.layout {
                 ladfile::LadTypeLayout::Opaque => {}
                 ladfile::LadTypeLayout::MonoVariant(lad_variant) => match lad_variant {
-                    ladfile::LadVariant::TupleStruct { name, fields } => {
+                    ladfile::LadVariant::TupleStruct { name, fields, .. } => {
                         for (idx, field) in fields.iter().enumerate() {
                             lua_fields.push(ClassField {
                                 name: format!("[{}]", idx + 1),
@@ -187,7 +206,7 @@ pub fn convert_polymorphic_type_to_lua_classes<'a>(
                             })
                         }
                     }
-                    ladfile::LadVariant::Struct { name, fields } => {
+                    ladfile::LadVariant::Struct { name, fields, .. } => {
                         for field in fields.iter() {
                             lua_fields.push(ClassField {
                                 name: field.name.clone(),
@@ -209,13 +228,18 @@ pub fn convert_polymorphic_type_to_lua_classes<'a>(
                     }
                     ladfile::LadVariant::Unit { .. } => {}
                 },
-                ladfile::LadTypeLayout::Enum(_) => {
-                    // TODO: enums
+                ladfile::LadTypeLayout::Enum(variants) => {
+                    for variant in variants {
+                        lua_functions.push(lad_variant_to_lua_constructor(ladfile, &name, variant));
+                    }
                 }
             }
 
             for function in &lad_type.associated_functions {
-                if let Some(function) = ladfile.functions.get(function) {
+                if let Some(function) = ladfile.functions.get(function)
+                    // variant constructors are typed more precisely from the layout above
+                    && !lad_type.layout.is_enum_variant(&function.identifier)
+                {
                     let lua_function = match lad_function_to_lua_function(ladfile, function) {
                         Ok(func) => func,
                         Err(err) => {
@@ -231,7 +255,14 @@ pub fn convert_polymorphic_type_to_lua_classes<'a>(
                         }
                     };
 
-                    if function.metadata.is_operator {
+                    let has_metamethod = !matches!(
+                        ScriptOperatorNames::parse(&function.identifier),
+                        Some(
+                            ScriptOperatorNames::GreaterThanComparison
+                                | ScriptOperatorNames::GreaterThanOrEqualComparison
+                        )
+                    );
+                    if function.metadata.is_operator && has_metamethod {
                         match lua_function_to_operator(&lua_function) {
                             Some(Ok(op)) => lua_operators.push(op),
                             Some(Err(func)) => lua_functions.push(func),
@@ -274,6 +305,51 @@ pub fn convert_polymorphic_type_to_lua_classes<'a>(
     Ok(types)
 }
 
+/// converts an enum variant to the constructor function registered under the variant's name on the enum's namespace
+pub fn lad_variant_to_lua_constructor(
+    ladfile: &ladfile::LadFile,
+    enum_name: &str,
+    variant: &ladfile::LadVariant,
+) -> FunctionSignature {
+    let field_type = |ty: &LadFieldOrVariableKind| match lad_instance_to_lua_type(ladfile, ty) {
+        Ok(ty) => ty,
+        Err(e) => {
+            log::warn!(
+                "error converting field of variant {}: {e}. for enum {enum_name}",
+                variant.name()
+            );
+            LuaType::Any
+        }
+    };
+
+    let payload = match variant {
+        ladfile::LadVariant::TupleStruct { fields, .. } if !fields.is_empty() => Some(
+            LuaType::Tuple(fields.iter().map(|f| field_type(&f.type_)).collect()),
+        ),
+        ladfile::LadVariant::Struct { fields, .. } if !fields.is_empty() => {
+            Some(LuaType::Dictionary {
+                key: Box::new(LuaType::Primitive(LuaPrimitiveType::String)),
+                value: Box::new(LuaType::Any),
+            })
+        }
+        _ => None,
+    };
+
+    FunctionSignature {
+        name: variant.name().to_owned(),
+        params: payload
+            .map(|ty| FunctionParam::new(String::from("fields"), ty, false, None))
+            .into_iter()
+            .collect(),
+        returns: vec![LuaType::Alias(enum_name.to_owned())],
+        documentation: Some(format!(
+            "Constructs the `{}` variant of `{enum_name}`.",
+            variant.name()
+        )),
+        ..Default::default()
+    }
+}
+
 /// converts a lua function to an operator if it matches the expected strucutre
 pub fn lua_function_to_operator(
     func: &FunctionSignature,
@@ -325,6 +401,56 @@ pub fn lua_function_to_operator(
                 ..func.clone()
             }));
         }
+        ScriptOperatorNames::LessThanOrEqualComparison => {
+            return Some(Err(FunctionSignature {
+                name: "__le".into(),
+                ..func.clone()
+            }));
+        }
+        // lua has no greater than metamethods, `a > b` is evaluated as `b < a` and `a >= b` as `b <= a`
+        ScriptOperatorNames::GreaterThanComparison
+        | ScriptOperatorNames::GreaterThanOrEqualComparison => return None,
+        ScriptOperatorNames::BitwiseAnd => (
+            LuaOperatorKind::BAnd,
+            Some(func.params.get(1)?),
+            func.returns.first()?,
+        ),
+        ScriptOperatorNames::BitwiseOr => (
+            LuaOperatorKind::BOr,
+            Some(func.params.get(1)?),
+            func.returns.first()?,
+        ),
+        ScriptOperatorNames::BitwiseXor => (
+            LuaOperatorKind::BXor,
+            Some(func.params.get(1)?),
+            func.returns.first()?,
+        ),
+        ScriptOperatorNames::ShiftLeft => (
+            LuaOperatorKind::Shl,
+            Some(func.params.get(1)?),
+            func.returns.first()?,
+        ),
+        ScriptOperatorNames::ShiftRight => (
+            LuaOperatorKind::Shr,
+            Some(func.params.get(1)?),
+            func.returns.first()?,
+        ),
+        ScriptOperatorNames::BitwiseNot => (LuaOperatorKind::BNot, None, func.returns.first()?),
+        ScriptOperatorNames::FloorDivision => (
+            LuaOperatorKind::IDiv,
+            Some(func.params.get(1)?),
+            func.returns.first()?,
+        ),
+        ScriptOperatorNames::Concatenation => (
+            LuaOperatorKind::Concat,
+            Some(func.params.get(1)?),
+            func.returns.first()?,
+        ),
+        ScriptOperatorNames::Call => (
+            LuaOperatorKind::Call,
+            func.params.get(1),
+            func.returns.first()?,
+        ),
         ScriptOperatorNames::Length => (LuaOperatorKind::Len, None, func.returns.first()?),
         ScriptOperatorNames::Iteration => {
             return Some(Err(FunctionSignature {
@@ -388,22 +514,7 @@ pub fn lad_function_to_lua_function(
             )
         })
         .enumerate()
-        .map(|(idx, a)| {
-            let ident = a
-                .name
-                .as_ref()
-                .map(|v| v.to_string())
-                .unwrap_or(format!("p{}", idx + 1));
-            Ok(FunctionParam::new(
-                match ForbiddenKeywords::is_forbidden_err(&ident) {
-                    Ok(_) => ident,
-                    Err(_) => format!("_{ident}"),
-                },
-                lad_instance_to_lua_type(ladfile, &a.kind)?,
-                matches!(a.kind, LadFieldOrVariableKind::Option(..)),
-                a.documentation.as_ref().map(|d| d.to_string()),
-            ))
-        })
+        .map(|(idx, a)| lad_argument_to_lua_param(ladfile, idx, a))
         .collect::<Result<Vec<_>, anyhow::Error>>()?;
 
     let self_type = match &function.namespace {
@@ -427,7 +538,7 @@ pub fn lad_function_to_lua_function(
         params,
         returns: vec![returns],
         async_fn: false,
-        deprecated: false,
+        deprecated: function.metadata.deprecated.is_some(),
         nodiscard: false,
         package: false,
         overloads: vec![],
@@ -437,6 +548,56 @@ pub fn lad_function_to_lua_function(
     })
 }
 
+pub fn lad_argument_to_lua_param(
+    ladfile: &LadFile,
+    idx: usize,
+    argument: &LadArgument,
+) -> Result<FunctionParam, anyhow::Error> {
+    let ident = argument
+        .name
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or(format!("p{}", idx + 1));
+    Ok(FunctionParam::new(
+        match ForbiddenKeywords::is_forbidden_err(&ident) {
+            Ok(_) => ident,
+            Err(_) => format!("_{ident}"),
+        },
+        lad_instance_to_lua_type(ladfile, &argument.kind)?,
+        matches!(argument.kind, LadFieldOrVariableKind::Option(..)),
+        argument.documentation.as_ref().map(|d| d.to_string()),
+    ))
+}
+
+/// Converts a callback into a stub function scripts can implement, the return type is left open unless the callback documents one.
+pub fn lad_callback_to_lua_function(
+    ladfile: &LadFile,
+    label: &str,
+    callback: &LadCallback,
+) -> Result<FunctionSignature, anyhow::Error> {
+    ForbiddenKeywords::is_forbidden_err(label)?;
+
+    let params = callback
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(idx, a)| lad_argument_to_lua_param(ladfile, idx, a))
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+    let returns = match &callback.return_type {
+        Some(return_type) => lad_instance_to_lua_type(ladfile, &return_type.kind)?,
+        None => LuaType::Any,
+    };
+
+    Ok(FunctionSignature {
+        name: label.to_string(),
+        params,
+        returns: vec![returns],
+        documentation: callback.documentation.as_ref().map(|d| d.to_string()),
+        ..Default::default()
+    })
+}
+
 pub fn to_lua_many(
     ladfile: &LadFile,
     lad_types: &[ladfile::LadFieldOrVariableKind],
@@ -523,7 +684,8 @@ pub fn lad_primitive_to_lua_type(lad_primitive: &ReflectionPrimitiveKind) -> Lua
         | ReflectionPrimitiveKind::Str
         | ReflectionPrimitiveKind::String
         | ReflectionPrimitiveKind::OsString
-        | ReflectionPrimitiveKind::PathBuf => LuaPrimitiveType::String,
+        | ReflectionPrimitiveKind::PathBuf
+        | ReflectionPrimitiveKind::Bytes => LuaPrimitiveType::String,
         ReflectionPrimitiveKind::FunctionCallContext => return LuaType::Any,
         ReflectionPrimitiveKind::DynamicFunction | ReflectionPrimitiveKind::DynamicFunctionMut => {
             LuaPrimitiveType::Function
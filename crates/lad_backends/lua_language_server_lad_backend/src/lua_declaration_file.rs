@@ -237,6 +237,16 @@ pub enum LuaOperatorKind {
     Eq,  // ==
     Lt,  // <
     Le,  // <=
+    #[serde(rename = "band")]
+    BAnd, // &
+    #[serde(rename = "bor")]
+    BOr, // |
+    #[serde(rename = "bxor")]
+    BXor, // ~
+    Shl, // <<
+    Shr, // >>
+    #[serde(rename = "bnot")]
+    BNot, // unary ~
     Call, // ()
     Index, // []
     #[serde(rename = "newindex")]
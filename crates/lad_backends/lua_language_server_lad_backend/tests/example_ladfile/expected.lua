@@ -7,6 +7,7 @@
 ---@field  int_field ? integer
 PlainStructType = {}
 
+---@deprecated
 ---@param p1 PlainStructType 
 ---@param p2 integer 
 ---@return any
@@ -20,6 +21,12 @@ Bool = {}
 
 
 
+---@class Bytes
+--- A contiguous buffer of bytes
+Bytes = {}
+
+
+
 ---@class Char
 --- An 8-bit character
 Char = {}
@@ -167,6 +174,20 @@ Usize = {}
 ---@class EnumType : ReflectReference
 EnumType = {}
 
+--- Constructs the `Unit` variant of `EnumType`.
+---@return EnumType
+function EnumType.Unit() end
+
+--- Constructs the `Struct` variant of `EnumType`.
+---@param fields table<string, any> 
+---@return EnumType
+function EnumType.Struct(fields) end
+
+--- Constructs the `TupleStruct` variant of `EnumType`.
+---@param fields [integer, string] 
+---@return EnumType
+function EnumType.TupleStruct(fields) end
+
 
 
 ---@class TupleStructType : ReflectReference
@@ -201,3 +222,16 @@ my_non_static_instance = {}
 --- An global instance of this type
 map = {}
 
+
+
+---@return any
+function on_farewell() end
+
+--- hello from callback
+---@param count integer 
+--- how many greetings were sent
+---@param sender PlainStructType 
+---@return string
+function on_greeting(count,sender) end
+
+
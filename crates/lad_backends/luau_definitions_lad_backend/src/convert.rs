@@ -0,0 +1,700 @@
+use std::{collections::HashSet, fmt::Write};
+
+use bevy_mod_scripting_bindings_domain::ScriptOperatorNames;
+use indexmap::IndexMap;
+use ladfile::{
+    LadArgument, LadCallback, LadFieldOrVariableKind, LadFile, LadFunction, LadTypeDefinition,
+    LadTypeId, LadTypeLayout, LadVariant, ReflectionPrimitiveKind,
+};
+
+use crate::keywords::is_reserved;
+
+const INDENT: &str = "    ";
+
+/// A parameter of a luau function.
+struct LuauParam {
+    name: String,
+    ty: String,
+    variadic: bool,
+    documentation: Option<String>,
+}
+
+/// A luau function signature.
+struct LuauFunction {
+    params: Vec<LuauParam>,
+    returns: String,
+    return_documentation: Option<String>,
+    documentation: Option<String>,
+}
+
+impl LuauFunction {
+    /// Renders the function as a function type, i.e. `(a: number) -> number`
+    fn as_type(&self) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|p| {
+                if p.variadic {
+                    format!("...{}", p.ty)
+                } else {
+                    format!("{}: {}", p.name, p.ty)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("({params}) -> {}", self.returns)
+    }
+
+    /// Renders the function as a declaration, i.e. `function name(a: number): number`
+    fn as_declaration(&self, name: &str) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(LuauParam::as_declaration)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("function {name}({params}): {}", self.returns)
+    }
+
+    /// Renders the function as a method declaration within a class, the first parameter is replaced by `self`.
+    fn as_method(&self, name: &str) -> String {
+        let params = std::iter::once(String::from("self"))
+            .chain(self.params.iter().skip(1).map(LuauParam::as_declaration))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("function {name}({params}): {}", self.returns)
+    }
+
+    /// Replaces the first parameter with an explicitly typed `self` parameter
+    fn with_typed_self(mut self, class: &str) -> Self {
+        if let Some(first) = self.params.first_mut() {
+            first.name = String::from("self");
+            first.ty = class.to_owned();
+        }
+        self
+    }
+}
+
+impl LuauParam {
+    /// Renders the parameter as it appears in a function declaration
+    fn as_declaration(&self) -> String {
+        if self.variadic {
+            format!("...: {}", self.ty)
+        } else {
+            format!("{}: {}", self.name, self.ty)
+        }
+    }
+}
+
+pub fn convert_ladfile_to_luau_definitions(ladfile: &LadFile) -> Result<String, anyhow::Error> {
+    let mut out = String::new();
+
+    if let Some(description) = &ladfile.description {
+        write_comment(&mut out, "", "--", description)?;
+        writeln!(out)?;
+    }
+
+    // classes need to be declared before they can be extended
+    let mut types = ladfile
+        .types
+        .iter()
+        .filter(|(type_id, _)| match ladfile.primitive_kind(type_id) {
+            Some(kind) => matches!(kind, ReflectionPrimitiveKind::ReflectReference),
+            None => true,
+        })
+        .collect::<Vec<_>>();
+    types.sort_by_key(|(type_id, _)| ladfile.primitive_kind(type_id).is_none());
+
+    let mut seen_identifiers = HashSet::new();
+    for (type_id, lad_type) in types {
+        // TODO: support generics, currently bevy doesn't let you track back generic instantiations to their definition
+        if !seen_identifiers.insert(lad_type.identifier.as_str()) {
+            log::warn!(
+                "Type {type_id} shares the identifier {} with another type, ignoring.",
+                lad_type.identifier
+            );
+            continue;
+        }
+        if is_reserved(&lad_type.identifier) {
+            log::warn!(
+                "Type {type_id} uses the reserved keyword {} as its identifier, ignoring.",
+                lad_type.identifier
+            );
+            continue;
+        }
+
+        write_class(&mut out, ladfile, type_id, lad_type)?;
+        write_static_table(&mut out, ladfile, lad_type)?;
+    }
+
+    for (name, instance) in ladfile.globals.iter() {
+        let ty = match &instance.type_kind {
+            LadFieldOrVariableKind::Val(type_id) if instance.is_static => {
+                format!("{}Static", ladfile.get_type_identifier(type_id, None))
+            }
+            kind => lad_kind_to_luau_type(ladfile, kind),
+        };
+        if instance.is_static {
+            writeln!(
+                out,
+                "--- A static table, allowing calls through the \".\" operator only."
+            )?;
+        } else {
+            writeln!(out, "--- A global instance of this type.")?;
+        }
+        writeln!(out, "declare {name}: {ty}")?;
+        writeln!(out)?;
+    }
+
+    let global_functions = group_overloads(
+        ladfile
+            .functions
+            .values()
+            .filter(|function| function.namespace.is_global()),
+    );
+    for (name, overloads) in global_functions {
+        let functions = overloads
+            .iter()
+            .map(|function| lad_function_to_luau_function(ladfile, function))
+            .collect::<Vec<_>>();
+        if let Some(first) = functions.first() {
+            write_function_docs(&mut out, "", first)?;
+        }
+        match functions.as_slice() {
+            [function] => writeln!(out, "declare {}", function.as_declaration(name))?,
+            functions => writeln!(out, "declare {name}: {}", intersection(functions))?,
+        }
+        writeln!(out)?;
+    }
+
+    if !ladfile.callbacks.is_empty() {
+        writeln!(
+            out,
+            "--- Callbacks scripts can implement, which are called by the host application."
+        )?;
+        writeln!(out, "export type Callbacks = {{")?;
+        for (label, callback) in ladfile.callbacks.iter() {
+            if is_reserved(label) {
+                log::warn!("Callback {label} is a reserved keyword in luau, ignoring.");
+                continue;
+            }
+            let function = lad_callback_to_luau_function(ladfile, callback);
+            write_function_docs(&mut out, INDENT, &function)?;
+            writeln!(out, "{INDENT}{label}: {},", function.as_type())?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+    }
+
+    Ok(out)
+}
+
+/// Writes a `declare class` block containing the fields, methods and metamethods of the type
+fn write_class(
+    out: &mut String,
+    ladfile: &LadFile,
+    type_id: &LadTypeId,
+    lad_type: &LadTypeDefinition,
+) -> std::fmt::Result {
+    let name = lad_type.identifier.as_str();
+
+    if let Some(docs) = &lad_type.documentation {
+        write_comment(out, "", "---", docs)?;
+    }
+    if !lad_type.generics.is_empty() {
+        let generics = lad_type
+            .generics
+            .iter()
+            .map(|g| {
+                format!(
+                    "`{}` = `{}`",
+                    g.name,
+                    lad_type_id_to_luau_type(ladfile, &g.type_id)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(out, "--- Generic parameters: {generics}")?;
+    }
+
+    match lad_type.metadata.is_reflect && name != "ReflectReference" {
+        true => writeln!(out, "declare class {name} extends ReflectReference")?,
+        false => writeln!(out, "declare class {name}")?,
+    }
+
+    match &lad_type.layout {
+        LadTypeLayout::MonoVariant(LadVariant::Struct { fields, .. }) => {
+            for field in fields {
+                let ty = lad_kind_to_luau_type(ladfile, &field.type_);
+                writeln!(out, "{INDENT}{}: {ty}", field.name)?;
+            }
+        }
+        LadTypeLayout::MonoVariant(LadVariant::TupleStruct { fields, .. }) => {
+            for (idx, field) in fields.iter().enumerate() {
+                let ty = lad_kind_to_luau_type(ladfile, &field.type_);
+                writeln!(out, "{INDENT}--- Field `[{}]`: `{ty}`", idx + 1)?;
+            }
+        }
+        LadTypeLayout::Opaque | LadTypeLayout::MonoVariant(_) | LadTypeLayout::Enum(_) => {}
+    }
+
+    let methods = group_overloads(
+        lad_type
+            .associated_functions
+            .iter()
+            .filter_map(|id| ladfile.functions.get(id))
+            .filter(|function| function_has_self(type_id, function)),
+    );
+
+    for (function_name, overloads) in &methods {
+        let functions = overloads
+            .iter()
+            .map(|function| lad_function_to_luau_function(ladfile, function))
+            .collect::<Vec<_>>();
+        if let Some(first) = functions.first() {
+            write_function_docs(out, INDENT, first)?;
+        }
+        write_member(out, function_name, name, functions)?;
+    }
+
+    for (function_name, overloads) in &methods {
+        let Some(metamethod) = ScriptOperatorNames::parse(function_name).and_then(metamethod_name)
+        else {
+            continue;
+        };
+        let functions = overloads
+            .iter()
+            .filter(|function| function.metadata.is_operator)
+            .map(|function| lad_function_to_luau_function(ladfile, function))
+            .collect::<Vec<_>>();
+        if !functions.is_empty() {
+            write_member(out, metamethod, name, functions)?;
+        }
+    }
+
+    writeln!(out, "end")?;
+    writeln!(out)
+}
+
+/// Writes a method in a class, overloaded methods are written as a property with an intersection of function types
+fn write_member(
+    out: &mut String,
+    name: &str,
+    class: &str,
+    functions: Vec<LuauFunction>,
+) -> std::fmt::Result {
+    if let [function] = functions.as_slice() {
+        return writeln!(out, "{INDENT}{}", function.as_method(name));
+    }
+
+    let overloads = functions
+        .into_iter()
+        .map(|f| f.with_typed_self(class))
+        .collect::<Vec<_>>();
+    writeln!(out, "{INDENT}{name}: {}", intersection(&overloads))
+}
+
+/// Writes the type of the table through which all functions associated with the type can be called with the "." operator, i.e. `Type.func(...)`
+fn write_static_table(
+    out: &mut String,
+    ladfile: &LadFile,
+    lad_type: &LadTypeDefinition,
+) -> std::fmt::Result {
+    let name = lad_type.identifier.as_str();
+    writeln!(out, "export type {name}Static = {{")?;
+
+    if let LadTypeLayout::Enum(variants) = &lad_type.layout {
+        for variant in variants {
+            if is_reserved(variant.name()) {
+                continue;
+            }
+            let constructor = lad_variant_to_luau_constructor(ladfile, name, variant);
+            write_function_docs(out, INDENT, &constructor)?;
+            writeln!(
+                out,
+                "{INDENT}{}: {},",
+                variant.name(),
+                constructor.as_type()
+            )?;
+        }
+    }
+
+    let functions = group_overloads(
+        lad_type
+            .associated_functions
+            .iter()
+            .filter_map(|id| ladfile.functions.get(id))
+            // variant constructors are typed more precisely from the layout above
+            .filter(|f| !lad_type.layout.is_enum_variant(&f.identifier)),
+    );
+    for (function_name, overloads) in functions {
+        let functions = overloads
+            .iter()
+            .map(|function| lad_function_to_luau_function(ladfile, function))
+            .collect::<Vec<_>>();
+        if let Some(first) = functions.first() {
+            write_function_docs(out, INDENT, first)?;
+        }
+        writeln!(
+            out,
+            "{INDENT}{function_name}: {},",
+            intersection(&functions)
+        )?;
+    }
+
+    writeln!(out, "}}")?;
+    writeln!(out)
+}
+
+/// Groups functions by their identifier, keeping the order of first appearance. Functions named after reserved keywords are ignored.
+fn group_overloads<'a>(
+    functions: impl Iterator<Item = &'a LadFunction>,
+) -> IndexMap<&'a str, Vec<&'a LadFunction>> {
+    let mut groups = IndexMap::<&str, Vec<&LadFunction>>::new();
+    for function in functions {
+        if is_reserved(&function.identifier) {
+            log::warn!(
+                "Function {} on namespace {:?} is a reserved keyword in luau, ignoring.",
+                function.identifier,
+                function.namespace
+            );
+            continue;
+        }
+        groups
+            .entry(function.identifier.as_ref())
+            .or_default()
+            .push(function);
+    }
+    groups
+}
+
+/// Renders a list of overloads as an intersection of function types
+fn intersection(functions: &[LuauFunction]) -> String {
+    match functions {
+        [function] => function.as_type(),
+        functions => functions
+            .iter()
+            .map(|f| format!("({})", f.as_type()))
+            .collect::<Vec<_>>()
+            .join(" & "),
+    }
+}
+
+fn write_function_docs(
+    out: &mut String,
+    indent: &str,
+    function: &LuauFunction,
+) -> std::fmt::Result {
+    let mut sections = Vec::new();
+    if let Some(docs) = &function.documentation {
+        sections.push(docs.clone());
+    }
+
+    let documented_params = function
+        .params
+        .iter()
+        .filter_map(|p| {
+            p.documentation
+                .as_ref()
+                .map(|docs| format!("- `{}`: {docs}", p.name))
+        })
+        .collect::<Vec<_>>();
+    if !documented_params.is_empty() {
+        sections.push(format!("Arguments:\n{}", documented_params.join("\n")));
+    }
+
+    if let Some(docs) = &function.return_documentation {
+        sections.push(format!("Returns: {docs}"));
+    }
+
+    write_comment(out, indent, "---", &sections.join("\n\n"))
+}
+
+/// Writes multi-line text as comments with the given prefix
+fn write_comment(out: &mut String, indent: &str, prefix: &str, text: &str) -> std::fmt::Result {
+    for line in text.lines() {
+        let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+        if line.is_empty() {
+            writeln!(out, "{indent}{prefix}")?;
+        } else {
+            writeln!(out, "{indent}{prefix} {line}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the luau metamethod implementing the operator, if luau supports overloading it
+fn metamethod_name(operator: ScriptOperatorNames) -> Option<&'static str> {
+    Some(match operator {
+        ScriptOperatorNames::Addition => "__add",
+        ScriptOperatorNames::Subtraction => "__sub",
+        ScriptOperatorNames::Multiplication => "__mul",
+        ScriptOperatorNames::Division => "__div",
+        ScriptOperatorNames::Remainder => "__mod",
+        ScriptOperatorNames::Negation => "__unm",
+        ScriptOperatorNames::Exponentiation => "__pow",
+        ScriptOperatorNames::Equality => "__eq",
+        ScriptOperatorNames::LessThanComparison => "__lt",
+        ScriptOperatorNames::LessThanOrEqualComparison => "__le",
+        ScriptOperatorNames::FloorDivision => "__idiv",
+        ScriptOperatorNames::Concatenation => "__concat",
+        ScriptOperatorNames::Call => "__call",
+        ScriptOperatorNames::Length => "__len",
+        ScriptOperatorNames::Iteration => "__iter",
+        ScriptOperatorNames::DisplayPrint | ScriptOperatorNames::DebugPrint => "__tostring",
+        // `a > b` is evaluated as `b < a`, `a >= b` as `b <= a`, and luau has no bitwise operators
+        ScriptOperatorNames::GreaterThanComparison
+        | ScriptOperatorNames::GreaterThanOrEqualComparison
+        | ScriptOperatorNames::BitwiseAnd
+        | ScriptOperatorNames::BitwiseOr
+        | ScriptOperatorNames::BitwiseXor
+        | ScriptOperatorNames::ShiftLeft
+        | ScriptOperatorNames::ShiftRight
+        | ScriptOperatorNames::BitwiseNot => return None,
+    })
+}
+
+/// Returns true if the first argument of the function is the type it's associated with, meaning it can be called on instances of it
+fn function_has_self(type_id: &LadTypeId, function: &LadFunction) -> bool {
+    function.arguments.first().is_some_and(|a| match &a.kind {
+        LadFieldOrVariableKind::Ref(i)
+        | LadFieldOrVariableKind::Mut(i)
+        | LadFieldOrVariableKind::Val(i) => i == type_id,
+        _ => false,
+    })
+}
+
+fn lad_function_to_luau_function(ladfile: &LadFile, function: &LadFunction) -> LuauFunction {
+    let params = function
+        .arguments
+        .iter()
+        .filter(|a| {
+            !matches!(
+                a.kind,
+                LadFieldOrVariableKind::Primitive(ReflectionPrimitiveKind::FunctionCallContext)
+            )
+        })
+        .enumerate()
+        .map(|(idx, arg)| lad_argument_to_luau_param(ladfile, idx, arg))
+        .collect();
+
+    LuauFunction {
+        params,
+        returns: lad_kind_to_luau_return_type(ladfile, &function.return_type.kind),
+        return_documentation: function
+            .return_type
+            .documentation
+            .as_deref()
+            .map(Into::into),
+        documentation: function.documentation.as_deref().map(Into::into),
+    }
+}
+
+/// Converts a callback into the signature of the function scripts can implement, the return type is left open unless the callback documents one.
+fn lad_callback_to_luau_function(ladfile: &LadFile, callback: &LadCallback) -> LuauFunction {
+    LuauFunction {
+        params: callback
+            .arguments
+            .iter()
+            .enumerate()
+            .map(|(idx, arg)| lad_argument_to_luau_param(ladfile, idx, arg))
+            .collect(),
+        returns: callback
+            .return_type
+            .as_ref()
+            .map(|r| lad_kind_to_luau_return_type(ladfile, &r.kind))
+            .unwrap_or_else(|| String::from("any")),
+        return_documentation: callback
+            .return_type
+            .as_ref()
+            .and_then(|r| r.documentation.as_deref())
+            .map(Into::into),
+        documentation: callback.documentation.as_deref().map(Into::into),
+    }
+}
+
+/// converts an enum variant to the constructor function registered under the variant's name on the enum's namespace
+fn lad_variant_to_luau_constructor(
+    ladfile: &LadFile,
+    enum_name: &str,
+    variant: &LadVariant,
+) -> LuauFunction {
+    let payload = match variant {
+        LadVariant::TupleStruct { fields, .. } if !fields.is_empty() => {
+            let types = fields
+                .iter()
+                .map(|f| lad_kind_to_luau_type(ladfile, &f.type_))
+                .collect::<Vec<_>>();
+            Some(format!("{{ {} }}", union(types)))
+        }
+        LadVariant::Struct { fields, .. } if !fields.is_empty() => {
+            let fields = fields
+                .iter()
+                .map(|f| format!("{}: {}", f.name, lad_kind_to_luau_type(ladfile, &f.type_)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some(format!("{{ {fields} }}"))
+        }
+        _ => None,
+    };
+
+    LuauFunction {
+        params: payload
+            .map(|ty| LuauParam {
+                name: String::from("fields"),
+                ty,
+                variadic: false,
+                documentation: None,
+            })
+            .into_iter()
+            .collect(),
+        returns: enum_name.to_owned(),
+        return_documentation: None,
+        documentation: Some(format!(
+            "Constructs the `{}` variant of `{enum_name}`.",
+            variant.name()
+        )),
+    }
+}
+
+fn lad_argument_to_luau_param(ladfile: &LadFile, idx: usize, argument: &LadArgument) -> LuauParam {
+    let name = argument
+        .name
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or(format!("p{}", idx + 1));
+    let variadic = matches!(argument.kind, LadFieldOrVariableKind::UntypedTuple);
+    LuauParam {
+        name: if is_reserved(&name) {
+            format!("_{name}")
+        } else {
+            name
+        },
+        ty: if variadic {
+            String::from("any")
+        } else {
+            lad_kind_to_luau_type(ladfile, &argument.kind)
+        },
+        variadic,
+        documentation: argument.documentation.as_deref().map(Into::into),
+    }
+}
+
+/// Like [`lad_kind_to_luau_type`] but allows for type packs which are only valid in return position
+fn lad_kind_to_luau_return_type(ladfile: &LadFile, kind: &LadFieldOrVariableKind) -> String {
+    match kind {
+        LadFieldOrVariableKind::Tuple(kinds) if kinds.is_empty() => String::from("()"),
+        LadFieldOrVariableKind::UntypedTuple => String::from("...any"),
+        LadFieldOrVariableKind::InteropResult(inner) => {
+            lad_kind_to_luau_return_type(ladfile, inner)
+        }
+        kind => lad_kind_to_luau_type(ladfile, kind),
+    }
+}
+
+pub fn lad_kind_to_luau_type(ladfile: &LadFile, kind: &LadFieldOrVariableKind) -> String {
+    match kind {
+        LadFieldOrVariableKind::Primitive(prim) => lad_primitive_to_luau_type(prim).to_owned(),
+        LadFieldOrVariableKind::Ref(type_id)
+        | LadFieldOrVariableKind::Mut(type_id)
+        | LadFieldOrVariableKind::Val(type_id) => lad_type_id_to_luau_type(ladfile, type_id),
+        LadFieldOrVariableKind::Option(inner) => {
+            let inner = lad_kind_to_luau_type(ladfile, inner);
+            if inner == "any" || inner.ends_with('?') {
+                inner
+            } else if inner.contains(['|', '&', '>']) {
+                format!("({inner})?")
+            } else {
+                format!("{inner}?")
+            }
+        }
+        LadFieldOrVariableKind::Vec(inner) | LadFieldOrVariableKind::Array(inner, _) => {
+            format!("{{ {} }}", lad_kind_to_luau_type(ladfile, inner))
+        }
+        LadFieldOrVariableKind::HashMap(key, value) => format!(
+            "{{ [{}]: {} }}",
+            lad_kind_to_luau_type(ladfile, key),
+            lad_kind_to_luau_type(ladfile, value)
+        ),
+        LadFieldOrVariableKind::HashSet(key) => {
+            format!("{{ [{}]: boolean }}", lad_kind_to_luau_type(ladfile, key))
+        }
+        // TODO: currently ignores the possibility of an error type
+        LadFieldOrVariableKind::InteropResult(inner) => lad_kind_to_luau_type(ladfile, inner),
+        LadFieldOrVariableKind::Tuple(kinds) if kinds.is_empty() => String::from("nil"),
+        LadFieldOrVariableKind::Tuple(kinds) => format!(
+            "{{ {} }}",
+            union(
+                kinds
+                    .iter()
+                    .map(|k| lad_kind_to_luau_type(ladfile, k))
+                    .collect()
+            )
+        ),
+        LadFieldOrVariableKind::UntypedTuple => String::from("{ any }"),
+        LadFieldOrVariableKind::Union(kinds) => union(
+            kinds
+                .iter()
+                .map(|k| lad_kind_to_luau_type(ladfile, k))
+                .collect(),
+        ),
+        LadFieldOrVariableKind::Unknown(_) => String::from("any"),
+    }
+}
+
+/// Joins the types into a union, removing duplicates
+fn union(types: Vec<String>) -> String {
+    let mut unique = Vec::with_capacity(types.len());
+    for ty in types {
+        if !unique.contains(&ty) {
+            unique.push(ty);
+        }
+    }
+    if unique.iter().any(|ty| ty == "any") {
+        return String::from("any");
+    }
+    unique.join(" | ")
+}
+
+fn lad_type_id_to_luau_type(ladfile: &LadFile, type_id: &LadTypeId) -> String {
+    match ladfile.primitive_kind(type_id) {
+        Some(prim) => lad_primitive_to_luau_type(prim).to_owned(),
+        None if ladfile.types.contains_key(type_id) => {
+            ladfile.get_type_identifier(type_id, None).to_string()
+        }
+        None => String::from("any"),
+    }
+}
+
+pub fn lad_primitive_to_luau_type(primitive: &ReflectionPrimitiveKind) -> &'static str {
+    match primitive {
+        ReflectionPrimitiveKind::Bool => "boolean",
+        ReflectionPrimitiveKind::Isize
+        | ReflectionPrimitiveKind::I8
+        | ReflectionPrimitiveKind::I16
+        | ReflectionPrimitiveKind::I32
+        | ReflectionPrimitiveKind::I64
+        | ReflectionPrimitiveKind::I128
+        | ReflectionPrimitiveKind::Usize
+        | ReflectionPrimitiveKind::U8
+        | ReflectionPrimitiveKind::U16
+        | ReflectionPrimitiveKind::U32
+        | ReflectionPrimitiveKind::U64
+        | ReflectionPrimitiveKind::U128
+        | ReflectionPrimitiveKind::F32
+        | ReflectionPrimitiveKind::F64 => "number",
+        ReflectionPrimitiveKind::Char
+        | ReflectionPrimitiveKind::Str
+        | ReflectionPrimitiveKind::String
+        | ReflectionPrimitiveKind::OsString
+        | ReflectionPrimitiveKind::PathBuf
+        | ReflectionPrimitiveKind::Bytes => "string",
+        ReflectionPrimitiveKind::DynamicFunction | ReflectionPrimitiveKind::DynamicFunctionMut => {
+            "(...any) -> ...any"
+        }
+        ReflectionPrimitiveKind::ReflectReference => "ReflectReference",
+        ReflectionPrimitiveKind::FunctionCallContext
+        | ReflectionPrimitiveKind::ScriptValue
+        | ReflectionPrimitiveKind::External(_) => "any",
+    }
+}
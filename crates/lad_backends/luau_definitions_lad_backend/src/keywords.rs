@@ -0,0 +1,10 @@
+/// Words which cannot be used as identifiers in luau.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "if", "in", "local",
+    "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+/// Returns true if the word is a reserved keyword in luau
+pub(crate) fn is_reserved(word: &str) -> bool {
+    RESERVED_KEYWORDS.contains(&word)
+}
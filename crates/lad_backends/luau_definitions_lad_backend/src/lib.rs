@@ -0,0 +1,38 @@
+//! Logic for generating Luau definition files from a LAD file.
+
+use std::path::Path;
+
+use anyhow::Context;
+
+use crate::convert::convert_ladfile_to_luau_definitions;
+
+mod convert;
+mod keywords;
+mod plugin;
+pub use plugin::*;
+
+/// Processess a LAD file and generates a Luau definition file.
+pub fn generate_luau_definition_files(
+    ladfile: &ladfile::LadFile,
+    output_dir: &Path,
+    file_name: &Path,
+) -> Result<(), anyhow::Error> {
+    let definitions = convert_ladfile_to_luau_definitions(ladfile)
+        .with_context(|| "failed to convert LAD file to luau definitions")?;
+
+    let output_path = output_dir.join(file_name);
+    std::fs::create_dir_all(
+        output_path
+            .parent()
+            .ok_or_else(|| anyhow::anyhow!("Output path has no parent"))?,
+    )
+    .with_context(|| "failed to create output directories")?;
+
+    std::fs::write(&output_path, definitions).with_context(|| {
+        format!(
+            "Failed to write luau definitions to file: {}",
+            output_path.display()
+        )
+    })?;
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use std::{borrow::Cow, collections::HashSet, path::PathBuf};
 
 use ladfile::{
-    LadArgument, LadFieldOrVariableKind, LadFile, LadFunction, LadInstance, LadTypeDefinition,
-    LadTypeId, LadTypeLayout, LadVisitable, ReflectionPrimitiveKind,
+    LadArgument, LadCallback, LadFieldOrVariableKind, LadFile, LadFunction, LadInstance,
+    LadTypeDefinition, LadTypeId, LadTypeLayout, LadVisitable, ReflectionPrimitiveKind,
 };
 use mdbook_preprocessor::book::{BookItem, Chapter, SectionNumber};
 
@@ -59,6 +59,12 @@ pub(crate) enum SectionData<'a> {
     FunctionSummary,
     /// A link directory to all global instances within the ladfile
     InstancesSummary,
+    /// A listing of all the callbacks scripts can implement
+    CallbacksSummary,
+    /// A link directory to all functions within the ladfile, grouped by namespace
+    FunctionIndex,
+    /// A listing of every name available in the global scope, i.e. global instances and global functions
+    AllGlobals,
     TypeDetail {
         lad_type_id: &'a LadTypeId,
         lad_type: &'a LadTypeDefinition,
@@ -78,6 +84,27 @@ pub(crate) struct Section<'a> {
     pub data: SectionData<'a>,
 }
 
+/// The notice shown next to deprecated types, fields and variants
+fn deprecation_notice(message: &str) -> String {
+    match message.trim() {
+        "" => "Deprecated.".to_owned(),
+        message => format!("Deprecated: {message}"),
+    }
+}
+
+/// The documentation and deprecation notice shown after a field in a type layout
+fn member_notes(documentation: Option<&str>, deprecated: Option<&str>) -> Vec<Markdown> {
+    let mut notes = Vec::new();
+    if let Some(docs) = documentation.map(str::trim).filter(|docs| !docs.is_empty()) {
+        notes.push(Markdown::new_paragraph("-"));
+        notes.push(Markdown::Raw(docs.to_owned()));
+    }
+    if let Some(deprecated) = deprecated {
+        notes.push(Markdown::new_paragraph(deprecation_notice(deprecated)).italic());
+    }
+    notes
+}
+
 /// Makes a filename safe to put in links
 pub fn linkify_filename(name: impl Into<String>) -> String {
     name.into()
@@ -151,6 +178,9 @@ impl<'a> Section<'a> {
             SectionData::TypeSummary { .. } => "Types".to_owned(),
             SectionData::FunctionSummary { .. } => "Functions".to_owned(),
             SectionData::InstancesSummary { .. } => "Globals".to_owned(),
+            SectionData::CallbacksSummary => "Callbacks".to_owned(),
+            SectionData::FunctionIndex => "Function Index".to_owned(),
+            SectionData::AllGlobals => "All Globals".to_owned(),
             SectionData::TypeDetail { lad_type_id, .. } => {
                 print_type(self.ladfile, *lad_type_id, None, None)
             }
@@ -179,7 +209,7 @@ impl<'a> Section<'a> {
 
         match self.data {
             SectionData::Summary { .. } => {
-                vec![
+                let mut children = vec![
                     Section::new(
                         child_parent_path.clone(),
                         self.ladfile,
@@ -195,7 +225,25 @@ impl<'a> Section<'a> {
                         self.ladfile,
                         SectionData::TypeSummary,
                     ),
-                ]
+                ];
+                if !self.ladfile.callbacks.is_empty() {
+                    children.push(Section::new(
+                        child_parent_path.clone(),
+                        self.ladfile,
+                        SectionData::CallbacksSummary,
+                    ));
+                }
+                children.push(Section::new(
+                    child_parent_path.clone(),
+                    self.ladfile,
+                    SectionData::FunctionIndex,
+                ));
+                children.push(Section::new(
+                    child_parent_path.clone(),
+                    self.ladfile,
+                    SectionData::AllGlobals,
+                ));
+                children
             }
             SectionData::TypeSummary => self
                 .ladfile
@@ -213,34 +261,24 @@ impl<'a> Section<'a> {
                 })
                 .collect(),
 
-            SectionData::FunctionSummary => {
-                let associated_functions = self
-                    .ladfile
-                    .types
-                    .iter()
-                    .flat_map(|t| &t.1.associated_functions)
-                    .collect::<HashSet<_>>();
-
-                let non_associated_functions = self
-                    .ladfile
-                    .functions
-                    .iter()
-                    .filter_map(|f| (!associated_functions.contains(f.0)).then_some(f.1));
-
-                non_associated_functions
-                    .map(|function| {
-                        Section::new(
-                            child_parent_path.clone(),
-                            self.ladfile,
-                            SectionData::FunctionDetail {
-                                function,
-                                types_directory: PathBuf::from("../types"),
-                            },
-                        )
-                    })
-                    .collect()
-            }
-            SectionData::InstancesSummary { .. } => {
+            SectionData::FunctionSummary => self
+                .non_associated_functions()
+                .into_iter()
+                .map(|function| {
+                    Section::new(
+                        child_parent_path.clone(),
+                        self.ladfile,
+                        SectionData::FunctionDetail {
+                            function,
+                            types_directory: PathBuf::from("../types"),
+                        },
+                    )
+                })
+                .collect(),
+            SectionData::InstancesSummary { .. }
+            | SectionData::CallbacksSummary
+            | SectionData::FunctionIndex
+            | SectionData::AllGlobals => {
                 vec![]
             }
             SectionData::TypeDetail { lad_type, .. } => lad_type
@@ -262,10 +300,27 @@ impl<'a> Section<'a> {
         }
     }
 
+    /// Functions which are not associated with any type, i.e. the ones callable from anywhere
+    fn non_associated_functions(&self) -> Vec<&'a LadFunction> {
+        let associated_functions = self
+            .ladfile
+            .types
+            .iter()
+            .flat_map(|t| &t.1.associated_functions)
+            .collect::<HashSet<_>>();
+
+        self.ladfile
+            .functions
+            .iter()
+            .filter_map(|f| (!associated_functions.contains(f.0)).then_some(f.1))
+            .collect()
+    }
+
     pub(crate) fn section_items(&self) -> Vec<SectionItem<'_>> {
         match self.data {
             SectionData::Summary { .. } => {
                 let title = self.title().clone();
+                let has_callbacks = !self.ladfile.callbacks.is_empty();
 
                 vec![SectionItem::Markdown {
                     markdown: Box::new(move |builder| {
@@ -307,6 +362,47 @@ impl<'a> Section<'a> {
                                     anchor: false
                                 }
                             ]);
+                            if has_callbacks {
+                                builder.row(markdown_vec![
+                                    Markdown::new_paragraph("Callbacks").code(),
+                                    Markdown::Link {
+                                        text: Box::new(
+                                            "Documents all the callbacks scripts can implement"
+                                        ),
+                                        url: format!(
+                                            "./{}/callbacks.md",
+                                            linkify_filename(title.clone())
+                                        ),
+                                        anchor: false
+                                    }
+                                ]);
+                            }
+                            builder.row(markdown_vec![
+                                Markdown::new_paragraph("Function Index").code(),
+                                Markdown::Link {
+                                    text: Box::new(
+                                        "Lists all functions grouped by their namespace"
+                                    ),
+                                    url: format!(
+                                        "./{}/function_index.md",
+                                        linkify_filename(title.clone())
+                                    ),
+                                    anchor: false
+                                }
+                            ]);
+                            builder.row(markdown_vec![
+                                Markdown::new_paragraph("All Globals").code(),
+                                Markdown::Link {
+                                    text: Box::new(
+                                        "Lists every name available in the global scope of scripts"
+                                    ),
+                                    url: format!(
+                                        "./{}/all_globals.md",
+                                        linkify_filename(title.clone())
+                                    ),
+                                    anchor: false
+                                }
+                            ]);
                         });
                     }),
                 }]
@@ -320,6 +416,23 @@ impl<'a> Section<'a> {
                     types_directory,
                 }]
             }
+            SectionData::CallbacksSummary => {
+                let mut items = vec![SectionItem::Markdown {
+                    markdown: Box::new(|builder| {
+                        builder.heading(2, "Script Callbacks");
+                        builder.text("Functions scripts can define, which are called by the host application when the corresponding event happens.");
+                    }),
+                }];
+                items.extend(self.ladfile.callbacks.iter().map(|(label, callback)| {
+                    SectionItem::CallbackDetails {
+                        label,
+                        callback,
+                        ladfile: self.ladfile,
+                        types_directory: PathBuf::from("./types"),
+                    }
+                }));
+                items
+            }
             SectionData::TypeSummary => {
                 let types = self.ladfile.types.keys().collect::<Vec<_>>();
                 vec![SectionItem::TypesSummary {
@@ -329,19 +442,7 @@ impl<'a> Section<'a> {
                 }]
             }
             SectionData::FunctionSummary => {
-                let associated_functions = self
-                    .ladfile
-                    .types
-                    .iter()
-                    .flat_map(|t| &t.1.associated_functions)
-                    .collect::<HashSet<_>>();
-
-                let non_associated_functions = self
-                    .ladfile
-                    .functions
-                    .iter()
-                    .filter_map(|f| (!associated_functions.contains(f.0)).then_some(f.1))
-                    .collect();
+                let non_associated_functions = self.non_associated_functions();
                 vec![
                     SectionItem::Markdown {
                         markdown: Box::new(|builder| {
@@ -355,6 +456,61 @@ impl<'a> Section<'a> {
                     },
                 ]
             }
+            SectionData::FunctionIndex => {
+                let mut items = vec![SectionItem::Markdown {
+                    markdown: Box::new(|builder| {
+                        builder.heading(2, "Functions By Namespace");
+                        builder.text(
+                            "Every function available to scripts, grouped by the namespace it lives in.",
+                        );
+                    }),
+                }];
+
+                let global_functions = self.non_associated_functions();
+                if !global_functions.is_empty() {
+                    items.push(SectionItem::Markdown {
+                        markdown: Box::new(|builder| {
+                            builder.heading(3, "Global");
+                        }),
+                    });
+                    items.push(SectionItem::FunctionsSummary {
+                        functions: global_functions,
+                        functions_directory: "functions".to_owned(),
+                    });
+                }
+
+                for (lad_type_id, lad_type) in self.ladfile.types.iter() {
+                    let functions = lad_type
+                        .associated_functions
+                        .iter()
+                        .filter_map(|i| self.ladfile.functions.get(i))
+                        .collect::<Vec<_>>();
+                    if functions.is_empty() {
+                        continue;
+                    }
+                    let type_name = print_type(self.ladfile, lad_type_id, Some("Unknown"), None);
+                    items.push(SectionItem::Markdown {
+                        markdown: Box::new(move |builder| {
+                            builder.heading(3, Markdown::new_paragraph(type_name.clone()).code());
+                        }),
+                    });
+                    // the functions of a type live next to the type page, in a directory named after it
+                    let type_page =
+                        linkify_filename(print_type(self.ladfile, lad_type_id, None, None));
+                    items.push(SectionItem::FunctionsSummary {
+                        functions,
+                        functions_directory: format!("types/{type_page}"),
+                    });
+                }
+                items
+            }
+            SectionData::AllGlobals => vec![SectionItem::AllGlobals {
+                instances: self.ladfile.globals.iter().collect(),
+                functions: self.non_associated_functions(),
+                ladfile: self.ladfile,
+                types_directory: PathBuf::from("./types"),
+                functions_directory: "functions".to_owned(),
+            }],
             SectionData::TypeDetail { lad_type, .. } => {
                 let functions = lad_type
                     .associated_functions
@@ -363,6 +519,7 @@ impl<'a> Section<'a> {
                     .collect::<Vec<_>>();
                 vec![
                     SectionItem::Layout {
+                        type_name: &lad_type.identifier,
                         layout: &lad_type.layout,
                         ladfile: self.ladfile,
                         types_directory: PathBuf::from("./"),
@@ -415,6 +572,7 @@ pub enum SectionItem<'a> {
         markdown: Box<dyn Fn(&mut MarkdownBuilder) + 'static>,
     },
     Layout {
+        type_name: &'a str,
         layout: &'a LadTypeLayout,
         ladfile: &'a LadFile,
         types_directory: PathBuf,
@@ -441,6 +599,19 @@ pub enum SectionItem<'a> {
         instances: Vec<(&'a Cow<'static, str>, &'a LadInstance)>,
         types_directory: PathBuf,
     },
+    CallbackDetails {
+        label: &'a str,
+        callback: &'a LadCallback,
+        ladfile: &'a ladfile::LadFile,
+        types_directory: PathBuf,
+    },
+    AllGlobals {
+        instances: Vec<(&'a Cow<'static, str>, &'a LadInstance)>,
+        functions: Vec<&'a LadFunction>,
+        ladfile: &'a ladfile::LadFile,
+        types_directory: PathBuf,
+        functions_directory: String,
+    },
 }
 
 impl std::fmt::Debug for SectionItem<'_> {
@@ -453,6 +624,8 @@ impl std::fmt::Debug for SectionItem<'_> {
             SectionItem::FunctionDetails { .. } => "FunctionDetails",
             SectionItem::TypesSummary { .. } => "TypesSummary",
             SectionItem::InstancesSummary { .. } => "InstancesSummary",
+            SectionItem::CallbackDetails { .. } => "CallbackDetails",
+            SectionItem::AllGlobals { .. } => "AllGlobals",
         })
     }
 }
@@ -462,51 +635,78 @@ impl IntoMarkdown for SectionItem<'_> {
         match self {
             SectionItem::Markdown { markdown } => (markdown)(builder),
             SectionItem::Layout {
+                type_name,
                 layout,
                 ladfile,
                 types_directory,
             } => {
+                let is_enum = matches!(layout, LadTypeLayout::Enum(_));
                 // process the variants here
                 let opaque = layout.for_each_variant(
-                    |v, _i| match v {
-                        ladfile::LadVariant::TupleStruct { name, fields } => {
-                            builder.heading(3, name.to_string()).list(
-                                true,
-                                fields
-                                    .iter()
-                                    .map(|f| {
-                                        Markdown::Raw(print_type(
-                                            ladfile,
-                                            &f.type_,
-                                            None,
-                                            Some((types_directory.clone(), true)),
-                                        ))
-                                    })
-                                    .collect(),
-                            );
+                    |v, _i| {
+                        match v {
+                            ladfile::LadVariant::TupleStruct { name, fields, .. } => {
+                                builder.heading(3, name.to_string()).list(
+                                    true,
+                                    fields
+                                        .iter()
+                                        .map(|f| {
+                                            markdown_vec![
+                                                Markdown::Raw(print_type(
+                                                    ladfile,
+                                                    &f.type_,
+                                                    None,
+                                                    Some((types_directory.clone(), true)),
+                                                )),
+                                                member_notes(
+                                                    f.documentation.as_deref(),
+                                                    f.deprecated.as_deref()
+                                                )
+                                            ]
+                                        })
+                                        .collect(),
+                                );
+                            }
+                            ladfile::LadVariant::Struct { name, fields, .. } => {
+                                builder.heading(3, name.to_string()).list(
+                                    false,
+                                    fields
+                                        .iter()
+                                        .map(|f| {
+                                            markdown_vec![
+                                                Markdown::new_paragraph(f.name.clone()).bold(),
+                                                Markdown::new_paragraph(":"),
+                                                Markdown::Raw(print_type(
+                                                    ladfile,
+                                                    &f.type_,
+                                                    None,
+                                                    Some((types_directory.clone(), true))
+                                                )),
+                                                member_notes(
+                                                    f.documentation.as_deref(),
+                                                    f.deprecated.as_deref()
+                                                )
+                                            ]
+                                        })
+                                        .collect(),
+                                );
+                            }
+                            ladfile::LadVariant::Unit { name, .. } => {
+                                builder.heading(3, name.to_string());
+                            }
                         }
-                        ladfile::LadVariant::Struct { name, fields } => {
-                            builder.heading(3, name.to_string()).list(
-                                false,
-                                fields
-                                    .iter()
-                                    .map(|f| {
-                                        markdown_vec![
-                                            Markdown::new_paragraph(f.name.clone()).bold(),
-                                            Markdown::new_paragraph(":"),
-                                            Markdown::Raw(print_type(
-                                                ladfile,
-                                                &f.type_,
-                                                None,
-                                                Some((types_directory.clone(), true))
-                                            ))
-                                        ]
-                                    })
-                                    .collect(),
-                            );
-                        }
-                        ladfile::LadVariant::Unit { name } => {
-                            builder.heading(3, name.to_string());
+                        if is_enum {
+                            if let Some(docs) = v.documentation() {
+                                builder.text(docs.trim());
+                            }
+                            if let Some(deprecated) = v.deprecated() {
+                                builder.italic(deprecation_notice(deprecated));
+                            }
+                            builder
+                                .inline()
+                                .text("Constructed via")
+                                .non_inline()
+                                .inline_code(format!("{type_name}.{}", v.name()));
                         }
                     },
                     "Opaque Type. 🔒",
@@ -526,6 +726,9 @@ impl IntoMarkdown for SectionItem<'_> {
                         .unwrap_or(NO_DOCS_STRING)
                         .to_owned(),
                 ));
+                if let Some(deprecated) = description.metadata.deprecated.as_deref() {
+                    builder.bold(deprecation_notice(deprecated));
+                }
             }
             SectionItem::FunctionsSummary {
                 functions,
@@ -651,6 +854,14 @@ impl IntoMarkdown for SectionItem<'_> {
                 ladfile,
                 types_directory,
             } => {
+                if let Some(deprecation) = &function.metadata.deprecated {
+                    builder.raw(format!(
+                        "<div class=\"warning\">\n    This function is {}.\n</div>",
+                        deprecation.message()
+                    ));
+                    builder.append("\n\n");
+                }
+
                 // if the function takes in a FunctionCallContext argument, we notify that it's an impure function
                 // which potentially tries to access anything in the world
                 if function.arguments.iter().any(|a| {
@@ -707,6 +918,149 @@ impl IntoMarkdown for SectionItem<'_> {
                         builder,
                     )
                 });
+
+                if !function.examples.is_empty() {
+                    builder.heading(4, "Examples");
+                    // examples in different languages are shown as tabs by `multi-code-block.js`
+                    builder.raw(r#"<div class="multi-code-block">"#);
+                    builder.append("\n\n");
+                    for example in function.examples.iter() {
+                        builder.codeblock(Some(example.language.as_str()), example.code.trim());
+                    }
+                    builder.raw("</div>");
+                    builder.append("\n\n");
+                }
+            }
+            SectionItem::AllGlobals {
+                instances,
+                functions,
+                ladfile,
+                types_directory,
+                functions_directory,
+            } => {
+                builder.heading(2, "Global Names");
+                builder.text("Every name available in the global scope of scripts, including global values and functions. You should avoid naming conflicts with these.");
+
+                let mut rows = instances
+                    .iter()
+                    .map(|(name, instance)| {
+                        let kind = match instance.is_static {
+                            true => "Static Instance",
+                            false => "Instance",
+                        };
+                        let printed_type = print_type(
+                            ladfile,
+                            &instance.type_kind,
+                            None,
+                            Some((types_directory.clone(), true)),
+                        );
+                        (
+                            name.to_string(),
+                            markdown_vec![
+                                Markdown::new_paragraph(name.to_string()).code(),
+                                Markdown::new_paragraph(kind),
+                                Markdown::Raw(printed_type),
+                            ],
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                rows.extend(functions.iter().map(|function| {
+                    let name = function.identifier_with_overload().to_string();
+                    let summary = function
+                        .documentation
+                        .as_deref()
+                        .map(|doc| markdown_substring(doc, 100))
+                        .unwrap_or_else(|| NO_DOCS_STRING.to_string());
+                    (
+                        name.clone(),
+                        markdown_vec![
+                            Markdown::Link {
+                                text: Box::new(Markdown::new_paragraph(name.clone()).code()),
+                                url: format!("./{functions_directory}/{name}.md"),
+                                anchor: false
+                            },
+                            Markdown::new_paragraph("Function"),
+                            Markdown::new_paragraph(summary.replace("\n", " ")),
+                        ],
+                    )
+                }));
+                rows.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                builder.table(|builder| {
+                    builder.headers(vec!["Name", "Kind", "Summary"]);
+                    for (_, row) in rows {
+                        builder.row(row);
+                    }
+                });
+            }
+            SectionItem::CallbackDetails {
+                label,
+                callback,
+                ladfile,
+                types_directory,
+            } => {
+                let arguments = callback
+                    .arguments
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, arg)| {
+                        arg.name
+                            .as_deref()
+                            .map(ToOwned::to_owned)
+                            .unwrap_or_else(|| format!("arg{idx}"))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                builder.heading(
+                    3,
+                    Markdown::new_paragraph(format!("{label}({arguments})")).code(),
+                );
+
+                // we don't escape this, this is already markdown
+                builder.quote(Markdown::Raw(
+                    callback
+                        .documentation
+                        .as_deref()
+                        .unwrap_or(NO_DOCS_STRING)
+                        .to_owned(),
+                ));
+
+                let headers = vec!["Name", "Type", "Documentation"];
+                if !callback.arguments.is_empty() {
+                    builder.heading(4, "Arguments");
+                    builder.table(|builder| {
+                        builder.headers(headers.clone());
+                        for (idx, arg) in callback.arguments.iter().enumerate() {
+                            build_lad_function_argument_row(
+                                idx,
+                                arg,
+                                ladfile,
+                                types_directory.clone(),
+                                builder,
+                            );
+                        }
+                    });
+                }
+
+                builder.heading(4, "Returns");
+                match &callback.return_type {
+                    Some(return_type) => {
+                        builder.table(|builder| {
+                            builder.headers(headers.clone());
+                            build_lad_function_argument_row(
+                                0,
+                                return_type,
+                                ladfile,
+                                types_directory.clone(),
+                                builder,
+                            )
+                        });
+                    }
+                    None => {
+                        builder.text("The return value is ignored.");
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,494 @@
+use std::{collections::HashSet, fmt::Write};
+
+use bevy_mod_scripting_bindings_domain::ScriptOperatorNames;
+use ladfile::{
+    LadArgument, LadCallback, LadFieldOrVariableKind, LadFile, LadFunction, LadTypeDefinition,
+    LadTypeId, LadTypeLayout, LadVariant, ReflectionPrimitiveKind,
+};
+
+use crate::keywords::rhai_identifier;
+
+/// The rhai type used for values whose type cannot be expressed in a definition file.
+const DYNAMIC: &str = "?";
+
+/// The rhai type of the values holding script functions.
+const FUNCTION: &str = "FnPtr";
+
+const SECTION_SEPARATOR: &str =
+    "// ----------------------------------------------------------------------------";
+
+/// A parameter of a function, as seen from a script.
+struct RhaiParam {
+    name: String,
+    ty: String,
+    documentation: Option<String>,
+}
+
+/// A function reachable through a property on a type or in the global scope, called via `receiver.name.call(...)`.
+struct RhaiFunction {
+    /// The value the function is accessed on, `None` for functions living in the global scope
+    receiver: Option<String>,
+    name: String,
+    params: Vec<RhaiParam>,
+    returns: RhaiParam,
+    documentation: Option<String>,
+}
+
+pub fn convert_ladfile_to_rhai_definitions(ladfile: &LadFile) -> Result<String, anyhow::Error> {
+    let mut out = String::new();
+    writeln!(out, "module static;")?;
+
+    if let Some(description) = &ladfile.description {
+        writeln!(out)?;
+        write_comment(&mut out, "//", description)?;
+    }
+
+    let mut seen_identifiers = HashSet::new();
+    for (type_id, lad_type) in ladfile.types.iter() {
+        // rhai has its own representation of primitives, only references are backed by BMS types
+        if ladfile
+            .primitive_kind(type_id)
+            .is_some_and(|kind| !matches!(kind, ReflectionPrimitiveKind::ReflectReference))
+        {
+            continue;
+        }
+
+        // TODO: support generics, currently bevy doesn't let you track back generic instantiations to their definition
+        if !seen_identifiers.insert(lad_type.identifier.as_str()) {
+            log::warn!(
+                "Type {type_id} shares the identifier {} with another type, ignoring.",
+                lad_type.identifier
+            );
+            continue;
+        }
+
+        write_type(&mut out, ladfile, type_id, lad_type)?;
+    }
+
+    write_section_header(&mut out, "Globals", None)?;
+    for (name, instance) in ladfile.globals.iter() {
+        let ty = lad_kind_to_rhai_type(ladfile, &instance.type_kind);
+        writeln!(out)?;
+        if instance.is_static {
+            writeln!(
+                out,
+                "/// A static namespace, only functions without a receiver can be called through it, i.e. `{name}.func.call()`."
+            )?;
+            writeln!(out, "const {name}: {ty};")?;
+        } else {
+            writeln!(out, "/// A global instance of this type.")?;
+            writeln!(out, "let {name}: {ty};")?;
+        }
+    }
+
+    let mut seen_functions = HashSet::new();
+    for function in ladfile
+        .functions
+        .values()
+        .filter(|function| function.namespace.is_global())
+    {
+        let function = lad_function_to_rhai_function(ladfile, None, false, function);
+        if !seen_functions.insert(function.name.clone()) {
+            continue;
+        }
+        writeln!(out)?;
+        write_function_docs(&mut out, &function)?;
+        writeln!(out, "const {}: {FUNCTION};", function.name)?;
+    }
+
+    if !ladfile.callbacks.is_empty() {
+        write_section_header(
+            &mut out,
+            "Callbacks",
+            Some("Functions scripts can define, which are called by the host application."),
+        )?;
+        for (label, callback) in ladfile.callbacks.iter() {
+            writeln!(out)?;
+            write_callback(&mut out, ladfile, label, callback)?;
+        }
+    }
+
+    Ok(out)
+}
+
+fn write_type(
+    out: &mut String,
+    ladfile: &LadFile,
+    type_id: &LadTypeId,
+    lad_type: &LadTypeDefinition,
+) -> std::fmt::Result {
+    let name = lad_type.identifier.as_str();
+    write_section_header(out, name, lad_type.documentation.as_deref())?;
+
+    match &lad_type.layout {
+        LadTypeLayout::Opaque => {}
+        LadTypeLayout::MonoVariant(LadVariant::Struct { fields, .. }) => {
+            for field in fields {
+                let ty = lad_kind_to_rhai_type(ladfile, &field.type_);
+                writeln!(out)?;
+                writeln!(out, "/// The `{}` field.", field.name)?;
+                writeln!(out, "fn get {}(this: {name}) -> {ty};", field.name)?;
+                writeln!(out, "fn set {}(this: {name}, value: {ty});", field.name)?;
+            }
+        }
+        LadTypeLayout::MonoVariant(LadVariant::TupleStruct { fields, .. })
+            if !fields.is_empty() =>
+        {
+            writeln!(out)?;
+            writeln!(out, "/// Indexes into the fields of `{name}`:")?;
+            for (idx, field) in fields.iter().enumerate() {
+                let ty = lad_kind_to_rhai_type(ladfile, &field.type_);
+                writeln!(out, "/// - `{idx}`: `{ty}`")?;
+            }
+            writeln!(out, "fn index get(this: {name}, index: int) -> {DYNAMIC};")?;
+            writeln!(
+                out,
+                "fn index set(this: {name}, index: int, value: {DYNAMIC});"
+            )?;
+        }
+        LadTypeLayout::MonoVariant(_) => {}
+        LadTypeLayout::Enum(variants) => {
+            for variant in variants {
+                writeln!(out)?;
+                let constructor = lad_variant_to_rhai_constructor(name, variant);
+                write_function_docs(out, &constructor)?;
+                writeln!(
+                    out,
+                    "fn get {}(this: {name}) -> {FUNCTION};",
+                    constructor.name
+                )?;
+            }
+        }
+    }
+
+    let mut seen_functions = HashSet::new();
+    for function in lad_type
+        .associated_functions
+        .iter()
+        .filter_map(|id| ladfile.functions.get(id))
+        // variant constructors are typed more precisely from the layout above
+        .filter(|f| !lad_type.layout.is_enum_variant(&f.identifier))
+    {
+        let has_self = function_has_self(type_id, function);
+        let receiver = if has_self { "value" } else { name };
+        let rhai_function =
+            lad_function_to_rhai_function(ladfile, Some(receiver), has_self, function);
+        // overloads are dispatched through the same property, but can still define distinct operators
+        if seen_functions.insert(rhai_function.name.clone()) {
+            writeln!(out)?;
+            write_function_docs(out, &rhai_function)?;
+            writeln!(
+                out,
+                "fn get {}(this: {name}) -> {FUNCTION};",
+                rhai_function.name
+            )?;
+        }
+
+        if function.metadata.is_operator && has_self {
+            write_operator(out, ladfile, name, function)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes an operator overload for the given function if rhai supports the operator.
+fn write_operator(
+    out: &mut String,
+    ladfile: &LadFile,
+    self_type: &str,
+    function: &LadFunction,
+) -> std::fmt::Result {
+    let Some(operator) = ScriptOperatorNames::parse(&function.identifier) else {
+        return Ok(());
+    };
+    let (symbol, binary) = match operator {
+        ScriptOperatorNames::Addition => ("+", true),
+        ScriptOperatorNames::Subtraction => ("-", true),
+        ScriptOperatorNames::Multiplication => ("*", true),
+        ScriptOperatorNames::Division => ("/", true),
+        ScriptOperatorNames::Remainder => ("%", true),
+        ScriptOperatorNames::Negation => ("-", false),
+        ScriptOperatorNames::Exponentiation => ("**", true),
+        ScriptOperatorNames::Equality => ("==", true),
+        ScriptOperatorNames::LessThanComparison => ("<", true),
+        ScriptOperatorNames::LessThanOrEqualComparison => ("<=", true),
+        ScriptOperatorNames::GreaterThanComparison => (">", true),
+        ScriptOperatorNames::GreaterThanOrEqualComparison => (">=", true),
+        ScriptOperatorNames::BitwiseAnd => ("&", true),
+        ScriptOperatorNames::BitwiseOr => ("|", true),
+        ScriptOperatorNames::BitwiseXor => ("^", true),
+        ScriptOperatorNames::ShiftLeft => ("<<", true),
+        ScriptOperatorNames::ShiftRight => (">>", true),
+        ScriptOperatorNames::BitwiseNot => ("!", false),
+        // these are exposed through functions or rhai built-ins rather than operators
+        ScriptOperatorNames::FloorDivision
+        | ScriptOperatorNames::Concatenation
+        | ScriptOperatorNames::Call
+        | ScriptOperatorNames::Length
+        | ScriptOperatorNames::Iteration
+        | ScriptOperatorNames::DisplayPrint
+        | ScriptOperatorNames::DebugPrint => return Ok(()),
+    };
+
+    let returns = lad_kind_to_rhai_type(ladfile, &function.return_type.kind);
+    if binary {
+        let Some(rhs) = script_arguments(function).nth(1) else {
+            log::warn!(
+                "Operator function {} on {self_type} is missing its right hand side argument. Skipping",
+                function.identifier
+            );
+            return Ok(());
+        };
+        let rhs = lad_kind_to_rhai_type(ladfile, &rhs.kind);
+        writeln!(out, "op {symbol}({self_type}, {rhs}) -> {returns};")
+    } else {
+        writeln!(out, "op {symbol}({self_type}) -> {returns};")
+    }
+}
+
+fn write_callback(
+    out: &mut String,
+    ladfile: &LadFile,
+    label: &str,
+    callback: &LadCallback,
+) -> std::fmt::Result {
+    let params = callback
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| lad_argument_to_rhai_param(ladfile, idx, arg))
+        .collect::<Vec<_>>();
+
+    if let Some(docs) = &callback.documentation {
+        write_comment(out, "///", docs)?;
+    }
+    let params = params
+        .iter()
+        .map(|p| format!("{}: {}", p.name, p.ty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match &callback.return_type {
+        Some(return_type) => {
+            let returns = lad_kind_to_rhai_type(ladfile, &return_type.kind);
+            writeln!(out, "fn {label}({params}) -> {returns};")
+        }
+        None => writeln!(out, "fn {label}({params});"),
+    }
+}
+
+fn write_function_docs(out: &mut String, function: &RhaiFunction) -> std::fmt::Result {
+    if let Some(docs) = &function.documentation {
+        write_comment(out, "///", docs)?;
+        writeln!(out, "///")?;
+    }
+
+    let args = function
+        .params
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    writeln!(out, "/// ```rhai")?;
+    match &function.receiver {
+        Some(receiver) => writeln!(out, "/// {receiver}.{}.call({args})", function.name)?,
+        None => writeln!(out, "/// {}.call({args})", function.name)?,
+    }
+    writeln!(out, "/// ```")?;
+
+    if !function.params.is_empty() {
+        writeln!(out, "///")?;
+        writeln!(out, "/// Arguments:")?;
+        for param in &function.params {
+            match &param.documentation {
+                Some(docs) => writeln!(out, "/// - `{}: {}`: {docs}", param.name, param.ty)?,
+                None => writeln!(out, "/// - `{}: {}`", param.name, param.ty)?,
+            }
+        }
+    }
+
+    writeln!(out, "///")?;
+    match &function.returns.documentation {
+        Some(docs) => writeln!(out, "/// Returns: `{}`: {docs}", function.returns.ty),
+        None => writeln!(out, "/// Returns: `{}`", function.returns.ty),
+    }
+}
+
+fn write_section_header(out: &mut String, title: &str, docs: Option<&str>) -> std::fmt::Result {
+    writeln!(out)?;
+    writeln!(out, "{SECTION_SEPARATOR}")?;
+    writeln!(out, "// {title}")?;
+    if let Some(docs) = docs {
+        write_comment(out, "//", docs)?;
+    }
+    writeln!(out, "{SECTION_SEPARATOR}")
+}
+
+/// Writes multi-line text as comments with the given prefix
+fn write_comment(out: &mut String, prefix: &str, text: &str) -> std::fmt::Result {
+    for line in text.lines() {
+        let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+        if line.is_empty() {
+            writeln!(out, "{prefix}")?;
+        } else {
+            writeln!(out, "{prefix} {line}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if the first argument of the function is the type it's associated with, meaning it can be called on instances of it
+fn function_has_self(type_id: &LadTypeId, function: &LadFunction) -> bool {
+    function.arguments.first().is_some_and(|a| match &a.kind {
+        LadFieldOrVariableKind::Ref(i)
+        | LadFieldOrVariableKind::Mut(i)
+        | LadFieldOrVariableKind::Val(i) => i == type_id,
+        _ => false,
+    })
+}
+
+/// The arguments scripts pass to the function, i.e. all but the injected call context
+fn script_arguments(function: &LadFunction) -> impl Iterator<Item = &LadArgument> {
+    function.arguments.iter().filter(|a| {
+        !matches!(
+            a.kind,
+            LadFieldOrVariableKind::Primitive(ReflectionPrimitiveKind::FunctionCallContext)
+        )
+    })
+}
+
+fn lad_function_to_rhai_function(
+    ladfile: &LadFile,
+    receiver: Option<&str>,
+    has_self: bool,
+    function: &LadFunction,
+) -> RhaiFunction {
+    let mut params = script_arguments(function)
+        .enumerate()
+        .map(|(idx, arg)| lad_argument_to_rhai_param(ladfile, idx, arg))
+        .collect::<Vec<_>>();
+
+    // the receiver is passed implicitly when calling through a value
+    if has_self && !params.is_empty() {
+        params.remove(0);
+    }
+
+    RhaiFunction {
+        receiver: receiver.map(ToOwned::to_owned),
+        name: rhai_identifier(&function.identifier),
+        params,
+        returns: RhaiParam {
+            name: String::from("return"),
+            ty: lad_kind_to_rhai_type(ladfile, &function.return_type.kind),
+            documentation: function
+                .return_type
+                .documentation
+                .as_deref()
+                .map(Into::into),
+        },
+        documentation: function.documentation.as_deref().map(Into::into),
+    }
+}
+
+/// converts an enum variant to the constructor function registered under the variant's name on the enum's namespace
+fn lad_variant_to_rhai_constructor(enum_name: &str, variant: &LadVariant) -> RhaiFunction {
+    let payload = match variant {
+        LadVariant::TupleStruct { fields, .. } if !fields.is_empty() => Some("Array"),
+        LadVariant::Struct { fields, .. } if !fields.is_empty() => Some("Map"),
+        _ => None,
+    };
+
+    RhaiFunction {
+        receiver: Some(enum_name.to_owned()),
+        name: variant.name().to_owned(),
+        params: payload
+            .map(|ty| RhaiParam {
+                name: String::from("fields"),
+                ty: ty.to_owned(),
+                documentation: None,
+            })
+            .into_iter()
+            .collect(),
+        returns: RhaiParam {
+            name: String::from("return"),
+            ty: enum_name.to_owned(),
+            documentation: None,
+        },
+        documentation: Some(format!(
+            "Constructs the `{}` variant of `{enum_name}`.",
+            variant.name()
+        )),
+    }
+}
+
+fn lad_argument_to_rhai_param(ladfile: &LadFile, idx: usize, argument: &LadArgument) -> RhaiParam {
+    let name = argument
+        .name
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or(format!("p{}", idx + 1));
+    RhaiParam {
+        name: rhai_identifier(&name),
+        ty: lad_kind_to_rhai_type(ladfile, &argument.kind),
+        documentation: argument.documentation.as_deref().map(Into::into),
+    }
+}
+
+pub fn lad_kind_to_rhai_type(ladfile: &LadFile, kind: &LadFieldOrVariableKind) -> String {
+    match kind {
+        LadFieldOrVariableKind::Primitive(prim) => lad_primitive_to_rhai_type(prim).to_owned(),
+        LadFieldOrVariableKind::Ref(type_id)
+        | LadFieldOrVariableKind::Mut(type_id)
+        | LadFieldOrVariableKind::Val(type_id) => match ladfile.primitive_kind(type_id) {
+            Some(prim) => lad_primitive_to_rhai_type(prim).to_owned(),
+            None if ladfile.types.contains_key(type_id) => {
+                ladfile.get_type_identifier(type_id, None).to_string()
+            }
+            None => DYNAMIC.to_owned(),
+        },
+        // rhai definitions cannot express unions, `()` is a valid value for any type
+        LadFieldOrVariableKind::Option(_) | LadFieldOrVariableKind::Union(_) => DYNAMIC.to_owned(),
+        LadFieldOrVariableKind::Tuple(kinds) if kinds.is_empty() => String::from("()"),
+        LadFieldOrVariableKind::Vec(_)
+        | LadFieldOrVariableKind::Array(..)
+        | LadFieldOrVariableKind::Tuple(_)
+        | LadFieldOrVariableKind::UntypedTuple => String::from("Array"),
+        LadFieldOrVariableKind::HashMap(..) | LadFieldOrVariableKind::HashSet(_) => {
+            String::from("Map")
+        }
+        // TODO: currently ignores the possibility of an error type
+        LadFieldOrVariableKind::InteropResult(inner) => lad_kind_to_rhai_type(ladfile, inner),
+        LadFieldOrVariableKind::Unknown(_) => DYNAMIC.to_owned(),
+    }
+}
+
+pub fn lad_primitive_to_rhai_type(primitive: &ReflectionPrimitiveKind) -> &'static str {
+    match primitive {
+        ReflectionPrimitiveKind::Bool => "bool",
+        ReflectionPrimitiveKind::Isize
+        | ReflectionPrimitiveKind::I8
+        | ReflectionPrimitiveKind::I16
+        | ReflectionPrimitiveKind::I32
+        | ReflectionPrimitiveKind::I64
+        | ReflectionPrimitiveKind::I128
+        | ReflectionPrimitiveKind::Usize
+        | ReflectionPrimitiveKind::U8
+        | ReflectionPrimitiveKind::U16
+        | ReflectionPrimitiveKind::U32
+        | ReflectionPrimitiveKind::U64
+        | ReflectionPrimitiveKind::U128 => "int",
+        ReflectionPrimitiveKind::F32 | ReflectionPrimitiveKind::F64 => "float",
+        ReflectionPrimitiveKind::Char => "char",
+        ReflectionPrimitiveKind::Str
+        | ReflectionPrimitiveKind::String
+        | ReflectionPrimitiveKind::OsString
+        | ReflectionPrimitiveKind::PathBuf => "String",
+        ReflectionPrimitiveKind::Bytes => "Blob",
+        ReflectionPrimitiveKind::DynamicFunction | ReflectionPrimitiveKind::DynamicFunctionMut => {
+            FUNCTION
+        }
+        ReflectionPrimitiveKind::ReflectReference => "ReflectReference",
+        ReflectionPrimitiveKind::FunctionCallContext
+        | ReflectionPrimitiveKind::ScriptValue
+        | ReflectionPrimitiveKind::External(_) => DYNAMIC,
+    }
+}
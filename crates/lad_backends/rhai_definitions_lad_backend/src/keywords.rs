@@ -0,0 +1,57 @@
+/// Words which cannot be used as identifiers in rhai.
+///
+/// The rhai runtime registers functions named after these with a trailing underscore, i.e. `new` becomes `new_`.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "fn",
+    "private",
+    "import",
+    "export",
+    "as",
+    "public",
+    "protected",
+    "super",
+    "new",
+    "use",
+    "module",
+    "package",
+    "var",
+    "static",
+    "shared",
+    "with",
+    "is",
+    "goto",
+    "exit",
+    "match",
+    "case",
+    "default",
+    "void",
+    "null",
+    "nil",
+    "spawn",
+    "thread",
+    "go",
+    "sync",
+    "async",
+    "await",
+    "yield",
+    "print",
+    "debug",
+    "type_of",
+    "eval",
+    "Fn",
+    "call",
+    "curry",
+    "this",
+    "is_def_var",
+    "is_def_fn",
+    "is_shared",
+];
+
+/// Returns the identifier scripts use to refer to the given name, escaping reserved keywords.
+pub(crate) fn rhai_identifier(name: &str) -> String {
+    if RESERVED_KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_owned()
+    }
+}
@@ -0,0 +1,55 @@
+//! Language Agnostic Declaration (LAD) file format post processor for generating Rhai definition files for the bevy_mod_scripting crate.
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use rhai_definitions_lad_backend::generate_rhai_definition_files;
+
+#[derive(Debug, clap::Parser)]
+/// Command line arguments for the Rhai definitions LAD backend.
+pub struct Args {
+    /// Input LAD file path
+    #[clap(short, long, help = "LAD json input file")]
+    pub input: String,
+
+    /// Output directory for the generated Rhai definition files
+    #[clap(
+        short,
+        long,
+        help = "Output directory for the generated Rhai definition file"
+    )]
+    pub output: PathBuf,
+
+    #[clap(short, long, help = "The file name of the definition file")]
+    /// File name
+    pub filename: Option<PathBuf>,
+}
+fn main() {
+    if let Err(e) = try_main() {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+fn try_main() -> Result<(), anyhow::Error> {
+    let args = Args::parse();
+
+    // Initialize the logger
+    env_logger::init();
+
+    // Log the input and output paths
+    log::trace!("Input LAD file: {}", args.input);
+    log::trace!("Output directory: {:?}", args.output);
+
+    // Load the LAD file
+    let file = std::fs::read_to_string(&args.input)
+        .map_err(|e| anyhow::anyhow!("Failed to read LAD file {}: {}", args.input, e))?;
+    let ladfile = ladfile::parse_lad_file(&file)?;
+
+    generate_rhai_definition_files(
+        &ladfile,
+        &args.output,
+        &args.filename.unwrap_or(PathBuf::from("bindings.d.rhai")),
+    )?;
+    Ok(())
+}
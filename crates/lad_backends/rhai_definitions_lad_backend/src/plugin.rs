@@ -0,0 +1,34 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use ladfile::LadFilePlugin;
+
+use crate::generate_rhai_definition_files;
+
+/// A plugin which generates rhai definition files to a specified directory when run
+#[derive(Clone)]
+pub struct RhaiDefinitionsLadPlugin {
+    /// The filename of the generated definition file
+    pub filename: PathBuf,
+}
+
+impl Default for RhaiDefinitionsLadPlugin {
+    fn default() -> Self {
+        Self {
+            filename: PathBuf::from("bindings.d.rhai"),
+        }
+    }
+}
+
+impl LadFilePlugin for RhaiDefinitionsLadPlugin {
+    fn run(&self, ladfile: &ladfile::LadFile, path: &Path) -> Result<(), Box<dyn Error>> {
+        generate_rhai_definition_files(ladfile, path, &self.filename)
+            .map_err(|e| e.into_boxed_dyn_error() as Box<dyn Error>)
+    }
+
+    fn name(&self) -> &'static str {
+        "Rhai definition file generator"
+    }
+}
@@ -0,0 +1,596 @@
+use std::{collections::HashSet, fmt::Write};
+
+use ladfile::{
+    LadArgument, LadCallback, LadFieldOrVariableKind, LadFile, LadFunction, LadTypeDefinition,
+    LadTypeId, LadTypeLayout, LadVariant, ReflectionPrimitiveKind,
+};
+
+use crate::keywords::{is_reserved, property_key};
+
+const INDENT: &str = "    ";
+
+/// A parameter of a typescript function.
+struct TsParam {
+    name: String,
+    ty: String,
+    rest: bool,
+    optional: bool,
+    documentation: Option<String>,
+}
+
+impl TsParam {
+    fn as_declaration(&self) -> String {
+        match (self.rest, self.optional) {
+            (true, _) => format!("...{}: {}", self.name, self.ty),
+            (false, true) => format!("{}?: {}", self.name, self.ty),
+            (false, false) => format!("{}: {}", self.name, self.ty),
+        }
+    }
+}
+
+/// A typescript function signature.
+struct TsFunction {
+    params: Vec<TsParam>,
+    returns: String,
+    return_documentation: Option<String>,
+    documentation: Option<String>,
+    /// The name of the overloaded function and the overload index, if this is an overload
+    overload: Option<(String, usize)>,
+}
+
+impl TsFunction {
+    /// Renders the signature of the function, i.e. `(a: number): number`
+    fn signature(&self, skip_params: usize) -> String {
+        let params = self
+            .params
+            .iter()
+            .skip(skip_params)
+            .map(TsParam::as_declaration)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("({params}): {}", self.returns)
+    }
+}
+
+pub fn convert_ladfile_to_typescript_declarations(
+    ladfile: &LadFile,
+) -> Result<String, anyhow::Error> {
+    let mut out = String::new();
+
+    if let Some(description) = &ladfile.description {
+        write_jsdoc(&mut out, "", description)?;
+        writeln!(out)?;
+    }
+
+    let mut seen_identifiers = HashSet::new();
+    for (type_id, lad_type) in ladfile.types.iter() {
+        // typescript has its own primitives, only references are backed by BMS types
+        if ladfile
+            .primitive_kind(type_id)
+            .is_some_and(|kind| !matches!(kind, ReflectionPrimitiveKind::ReflectReference))
+        {
+            continue;
+        }
+
+        // TODO: support generics, currently bevy doesn't let you track back generic instantiations to their definition
+        if !seen_identifiers.insert(lad_type.identifier.as_str()) {
+            log::warn!(
+                "Type {type_id} shares the identifier {} with another type, ignoring.",
+                lad_type.identifier
+            );
+            continue;
+        }
+
+        write_interface(&mut out, ladfile, type_id, lad_type)?;
+        write_static_interface(&mut out, ladfile, lad_type)?;
+    }
+
+    for (name, instance) in ladfile.globals.iter() {
+        if is_reserved(name) {
+            log::warn!("Global {name} is a reserved keyword in typescript, ignoring.");
+            continue;
+        }
+        let ty = match &instance.type_kind {
+            LadFieldOrVariableKind::Val(type_id) if instance.is_static => {
+                format!("{}Static", ladfile.get_type_identifier(type_id, None))
+            }
+            kind => lad_kind_to_ts_type(ladfile, kind),
+        };
+        if instance.is_static {
+            write_jsdoc(
+                &mut out,
+                "",
+                "A static namespace, only functions without a receiver can be called through it.",
+            )?;
+        } else {
+            write_jsdoc(&mut out, "", "A global instance of this type.")?;
+        }
+        writeln!(out, "export declare const {name}: {ty};")?;
+        writeln!(out)?;
+    }
+
+    for function in ladfile
+        .functions
+        .values()
+        .filter(|function| function.namespace.is_global())
+    {
+        if is_reserved(&function.identifier) {
+            log::warn!(
+                "Function {} is a reserved keyword in typescript, ignoring.",
+                function.identifier
+            );
+            continue;
+        }
+        let ts_function = lad_function_to_ts_function(ladfile, function);
+        write_function_docs(&mut out, "", &ts_function)?;
+        writeln!(
+            out,
+            "export declare function {}{};",
+            function.identifier,
+            ts_function.signature(0)
+        )?;
+        writeln!(out)?;
+    }
+
+    if !ladfile.callbacks.is_empty() {
+        write_jsdoc(
+            &mut out,
+            "",
+            "Callbacks scripts can implement, which are called by the host application.",
+        )?;
+        writeln!(out, "export interface Callbacks {{")?;
+        for (label, callback) in ladfile.callbacks.iter() {
+            let function = lad_callback_to_ts_function(ladfile, callback);
+            write_function_docs(&mut out, INDENT, &function)?;
+            writeln!(
+                out,
+                "{INDENT}{}{};",
+                property_key(label),
+                function.signature(0)
+            )?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+    }
+
+    Ok(out)
+}
+
+/// Writes an interface describing instances of the type, with its fields and methods
+fn write_interface(
+    out: &mut String,
+    ladfile: &LadFile,
+    type_id: &LadTypeId,
+    lad_type: &LadTypeDefinition,
+) -> std::fmt::Result {
+    let name = lad_type.identifier.as_str();
+
+    let mut docs = lad_type.documentation.clone().unwrap_or_default();
+    if !lad_type.generics.is_empty() {
+        let generics = lad_type
+            .generics
+            .iter()
+            .map(|g| {
+                format!(
+                    "`{}` = `{}`",
+                    g.name,
+                    lad_type_id_to_ts_type(ladfile, &g.type_id)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        if !docs.is_empty() {
+            docs.push_str("\n\n");
+        }
+        docs.push_str(&format!("Generic parameters: {generics}"));
+    }
+    write_jsdoc(out, "", &docs)?;
+
+    if lad_type.metadata.is_reflect && name != "ReflectReference" {
+        writeln!(out, "export interface {name} extends ReflectReference {{")?;
+    } else {
+        writeln!(out, "export interface {name} {{")?;
+    }
+
+    match &lad_type.layout {
+        LadTypeLayout::MonoVariant(LadVariant::Struct { fields, .. }) => {
+            for field in fields {
+                let ty = lad_kind_to_ts_type(ladfile, &field.type_);
+                writeln!(out, "{INDENT}{}: {ty};", property_key(&field.name))?;
+            }
+        }
+        LadTypeLayout::MonoVariant(LadVariant::TupleStruct { fields, .. }) => {
+            for (idx, field) in fields.iter().enumerate() {
+                let ty = lad_kind_to_ts_type(ladfile, &field.type_);
+                writeln!(out, "{INDENT}{idx}: {ty};")?;
+            }
+        }
+        LadTypeLayout::Opaque | LadTypeLayout::MonoVariant(_) | LadTypeLayout::Enum(_) => {}
+    }
+
+    for function in lad_type
+        .associated_functions
+        .iter()
+        .filter_map(|id| ladfile.functions.get(id))
+        .filter(|function| function_has_self(type_id, function))
+    {
+        let ts_function = lad_function_to_ts_function(ladfile, function);
+        write_function_docs(out, INDENT, &ts_function)?;
+        writeln!(
+            out,
+            "{INDENT}{}{};",
+            property_key(&function.identifier),
+            ts_function.signature(1)
+        )?;
+    }
+
+    writeln!(out, "}}")?;
+    writeln!(out)
+}
+
+/// Writes the interface of the namespace through which all functions associated with the type can be called, i.e. `Type.func(...)`
+fn write_static_interface(
+    out: &mut String,
+    ladfile: &LadFile,
+    lad_type: &LadTypeDefinition,
+) -> std::fmt::Result {
+    let name = lad_type.identifier.as_str();
+    write_jsdoc(
+        out,
+        "",
+        &format!("The functions callable through the `{name}` namespace."),
+    )?;
+    writeln!(out, "export interface {name}Static {{")?;
+
+    if let LadTypeLayout::Enum(variants) = &lad_type.layout {
+        for variant in variants {
+            let constructor = lad_variant_to_ts_constructor(ladfile, name, variant);
+            write_function_docs(out, INDENT, &constructor)?;
+            writeln!(
+                out,
+                "{INDENT}{}{};",
+                property_key(variant.name()),
+                constructor.signature(0)
+            )?;
+        }
+    }
+
+    for function in lad_type
+        .associated_functions
+        .iter()
+        .filter_map(|id| ladfile.functions.get(id))
+        // variant constructors are typed more precisely from the layout above
+        .filter(|f| !lad_type.layout.is_enum_variant(&f.identifier))
+    {
+        let ts_function = lad_function_to_ts_function(ladfile, function);
+        write_function_docs(out, INDENT, &ts_function)?;
+        writeln!(
+            out,
+            "{INDENT}{}{};",
+            property_key(&function.identifier),
+            ts_function.signature(0)
+        )?;
+    }
+
+    writeln!(out, "}}")?;
+    writeln!(out)
+}
+
+fn write_function_docs(out: &mut String, indent: &str, function: &TsFunction) -> std::fmt::Result {
+    let mut sections = Vec::new();
+    if let Some(docs) = &function.documentation {
+        sections.push(docs.clone());
+    }
+    if let Some((name, index)) = &function.overload {
+        sections.push(format!("Overload {index} of `{name}`."));
+    }
+
+    let mut tags = function
+        .params
+        .iter()
+        .filter_map(|p| {
+            p.documentation
+                .as_ref()
+                .map(|docs| format!("@param {} {docs}", p.name))
+        })
+        .collect::<Vec<_>>();
+    if let Some(docs) = &function.return_documentation {
+        tags.push(format!("@returns {docs}"));
+    }
+    if !tags.is_empty() {
+        sections.push(tags.join("\n"));
+    }
+
+    write_jsdoc(out, indent, &sections.join("\n\n"))
+}
+
+/// Writes multi-line text as a JSDoc comment, nothing is written for empty text
+fn write_jsdoc(out: &mut String, indent: &str, text: &str) -> std::fmt::Result {
+    if text.trim().is_empty() {
+        return Ok(());
+    }
+    writeln!(out, "{indent}/**")?;
+    for line in text.lines() {
+        let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+        if line.is_empty() {
+            writeln!(out, "{indent} *")?;
+        } else {
+            writeln!(out, "{indent} * {line}")?;
+        }
+    }
+    writeln!(out, "{indent} */")
+}
+
+/// Returns true if the first argument of the function is the type it's associated with, meaning it can be called on instances of it
+fn function_has_self(type_id: &LadTypeId, function: &LadFunction) -> bool {
+    function.arguments.first().is_some_and(|a| match &a.kind {
+        LadFieldOrVariableKind::Ref(i)
+        | LadFieldOrVariableKind::Mut(i)
+        | LadFieldOrVariableKind::Val(i) => i == type_id,
+        _ => false,
+    })
+}
+
+fn lad_function_to_ts_function(ladfile: &LadFile, function: &LadFunction) -> TsFunction {
+    let mut params = function
+        .arguments
+        .iter()
+        .filter(|a| {
+            !matches!(
+                a.kind,
+                LadFieldOrVariableKind::Primitive(ReflectionPrimitiveKind::FunctionCallContext)
+            )
+        })
+        .enumerate()
+        .map(|(idx, arg)| lad_argument_to_ts_param(ladfile, idx, arg))
+        .collect::<Vec<_>>();
+    mark_trailing_optionals(&mut params);
+
+    TsFunction {
+        params,
+        returns: lad_kind_to_ts_return_type(ladfile, &function.return_type.kind),
+        return_documentation: function
+            .return_type
+            .documentation
+            .as_deref()
+            .map(Into::into),
+        documentation: function.documentation.as_deref().map(Into::into),
+        overload: function
+            .as_overload()
+            .map(|(name, index)| (name.to_string(), index)),
+    }
+}
+
+/// Converts a callback into the signature of the function scripts can implement, the return type is left open unless the callback documents one.
+fn lad_callback_to_ts_function(ladfile: &LadFile, callback: &LadCallback) -> TsFunction {
+    let mut params = callback
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| lad_argument_to_ts_param(ladfile, idx, arg))
+        .collect::<Vec<_>>();
+    mark_trailing_optionals(&mut params);
+
+    TsFunction {
+        params,
+        returns: callback
+            .return_type
+            .as_ref()
+            .map(|r| lad_kind_to_ts_return_type(ladfile, &r.kind))
+            .unwrap_or_else(|| String::from("unknown")),
+        return_documentation: callback
+            .return_type
+            .as_ref()
+            .and_then(|r| r.documentation.as_deref())
+            .map(Into::into),
+        documentation: callback.documentation.as_deref().map(Into::into),
+        overload: None,
+    }
+}
+
+/// converts an enum variant to the constructor function registered under the variant's name on the enum's namespace
+fn lad_variant_to_ts_constructor(
+    ladfile: &LadFile,
+    enum_name: &str,
+    variant: &LadVariant,
+) -> TsFunction {
+    let payload = match variant {
+        LadVariant::TupleStruct { fields, .. } if !fields.is_empty() => Some(format!(
+            "[{}]",
+            fields
+                .iter()
+                .map(|f| lad_kind_to_ts_type(ladfile, &f.type_))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        LadVariant::Struct { fields, .. } if !fields.is_empty() => Some(format!(
+            "{{ {} }}",
+            fields
+                .iter()
+                .map(|f| format!(
+                    "{}: {}",
+                    property_key(&f.name),
+                    lad_kind_to_ts_type(ladfile, &f.type_)
+                ))
+                .collect::<Vec<_>>()
+                .join("; ")
+        )),
+        _ => None,
+    };
+
+    TsFunction {
+        params: payload
+            .map(|ty| TsParam {
+                name: String::from("fields"),
+                ty,
+                rest: false,
+                optional: false,
+                documentation: None,
+            })
+            .into_iter()
+            .collect(),
+        returns: enum_name.to_owned(),
+        return_documentation: None,
+        documentation: Some(format!(
+            "Constructs the `{}` variant of `{enum_name}`.",
+            variant.name()
+        )),
+        overload: None,
+    }
+}
+
+fn lad_argument_to_ts_param(ladfile: &LadFile, idx: usize, argument: &LadArgument) -> TsParam {
+    let name = argument
+        .name
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or(format!("p{}", idx + 1));
+    let rest = matches!(argument.kind, LadFieldOrVariableKind::UntypedTuple);
+    TsParam {
+        name: if is_reserved(&name) {
+            format!("_{name}")
+        } else {
+            name
+        },
+        ty: lad_kind_to_ts_type(ladfile, &argument.kind),
+        rest,
+        optional: matches!(argument.kind, LadFieldOrVariableKind::Option(_)),
+        documentation: argument.documentation.as_deref().map(Into::into),
+    }
+}
+
+/// Only trailing parameters can be optional in typescript, any optional parameters followed by required ones are made required
+fn mark_trailing_optionals(params: &mut [TsParam]) {
+    let mut trailing = true;
+    for param in params.iter_mut().rev() {
+        trailing &= param.optional;
+        param.optional = trailing;
+    }
+}
+
+/// Like [`lad_kind_to_ts_type`] but maps the unit type to `void`
+fn lad_kind_to_ts_return_type(ladfile: &LadFile, kind: &LadFieldOrVariableKind) -> String {
+    match kind {
+        LadFieldOrVariableKind::Tuple(kinds) if kinds.is_empty() => String::from("void"),
+        LadFieldOrVariableKind::InteropResult(inner) => lad_kind_to_ts_return_type(ladfile, inner),
+        kind => lad_kind_to_ts_type(ladfile, kind),
+    }
+}
+
+pub fn lad_kind_to_ts_type(ladfile: &LadFile, kind: &LadFieldOrVariableKind) -> String {
+    match kind {
+        LadFieldOrVariableKind::Primitive(prim) => lad_primitive_to_ts_type(prim).to_owned(),
+        LadFieldOrVariableKind::Ref(type_id)
+        | LadFieldOrVariableKind::Mut(type_id)
+        | LadFieldOrVariableKind::Val(type_id) => lad_type_id_to_ts_type(ladfile, type_id),
+        LadFieldOrVariableKind::Option(inner) => union(vec![
+            lad_kind_to_ts_type(ladfile, inner),
+            String::from("null"),
+        ]),
+        LadFieldOrVariableKind::Vec(inner) | LadFieldOrVariableKind::Array(inner, _) => {
+            format!("{}[]", parenthesize(lad_kind_to_ts_type(ladfile, inner)))
+        }
+        LadFieldOrVariableKind::HashMap(key, value) => format!(
+            "Map<{}, {}>",
+            lad_kind_to_ts_type(ladfile, key),
+            lad_kind_to_ts_type(ladfile, value)
+        ),
+        LadFieldOrVariableKind::HashSet(key) => {
+            format!("Set<{}>", lad_kind_to_ts_type(ladfile, key))
+        }
+        // TODO: currently ignores the possibility of an error type
+        LadFieldOrVariableKind::InteropResult(inner) => lad_kind_to_ts_type(ladfile, inner),
+        LadFieldOrVariableKind::Tuple(kinds) => format!(
+            "[{}]",
+            kinds
+                .iter()
+                .map(|k| lad_kind_to_ts_type(ladfile, k))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        LadFieldOrVariableKind::UntypedTuple => String::from("any[]"),
+        LadFieldOrVariableKind::Union(kinds) => union(
+            kinds
+                .iter()
+                .map(|k| lad_kind_to_ts_type(ladfile, k))
+                .collect(),
+        ),
+        LadFieldOrVariableKind::Unknown(_) => String::from("any"),
+    }
+}
+
+/// Wraps union and function types in parentheses so they can be used within array types
+fn parenthesize(ty: String) -> String {
+    if ty.contains(['|', '=']) {
+        format!("({ty})")
+    } else {
+        ty
+    }
+}
+
+/// Joins the types into a union, removing duplicates
+fn union(types: Vec<String>) -> String {
+    let mut unique = Vec::with_capacity(types.len());
+    for ty in types {
+        if !unique.contains(&ty) {
+            unique.push(ty);
+        }
+    }
+    if unique.iter().any(|ty| ty == "any") {
+        return String::from("any");
+    }
+    unique
+        .into_iter()
+        .map(|ty| {
+            if ty.contains('=') {
+                format!("({ty})")
+            } else {
+                ty
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn lad_type_id_to_ts_type(ladfile: &LadFile, type_id: &LadTypeId) -> String {
+    match ladfile.primitive_kind(type_id) {
+        Some(prim) => lad_primitive_to_ts_type(prim).to_owned(),
+        None if ladfile.types.contains_key(type_id) => {
+            ladfile.get_type_identifier(type_id, None).to_string()
+        }
+        None => String::from("any"),
+    }
+}
+
+pub fn lad_primitive_to_ts_type(primitive: &ReflectionPrimitiveKind) -> &'static str {
+    match primitive {
+        ReflectionPrimitiveKind::Bool => "boolean",
+        ReflectionPrimitiveKind::Isize
+        | ReflectionPrimitiveKind::I8
+        | ReflectionPrimitiveKind::I16
+        | ReflectionPrimitiveKind::I32
+        | ReflectionPrimitiveKind::I64
+        | ReflectionPrimitiveKind::I128
+        | ReflectionPrimitiveKind::Usize
+        | ReflectionPrimitiveKind::U8
+        | ReflectionPrimitiveKind::U16
+        | ReflectionPrimitiveKind::U32
+        | ReflectionPrimitiveKind::U64
+        | ReflectionPrimitiveKind::U128
+        | ReflectionPrimitiveKind::F32
+        | ReflectionPrimitiveKind::F64 => "number",
+        ReflectionPrimitiveKind::Char
+        | ReflectionPrimitiveKind::Str
+        | ReflectionPrimitiveKind::String
+        | ReflectionPrimitiveKind::OsString
+        | ReflectionPrimitiveKind::PathBuf => "string",
+        ReflectionPrimitiveKind::Bytes => "Uint8Array",
+        ReflectionPrimitiveKind::DynamicFunction | ReflectionPrimitiveKind::DynamicFunctionMut => {
+            "(...args: any[]) => any"
+        }
+        ReflectionPrimitiveKind::ReflectReference => "ReflectReference",
+        ReflectionPrimitiveKind::FunctionCallContext
+        | ReflectionPrimitiveKind::ScriptValue
+        | ReflectionPrimitiveKind::External(_) => "any",
+    }
+}
@@ -0,0 +1,71 @@
+/// Words which cannot be used as identifiers in typescript declarations.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+];
+
+/// Returns true if the word is a reserved keyword in typescript
+pub(crate) fn is_reserved(word: &str) -> bool {
+    RESERVED_KEYWORDS.contains(&word)
+}
+
+/// Returns the name as a property key, quoting it if it's not a plain identifier.
+///
+/// Keywords are valid property names, but `new` would declare a constructor within an interface.
+pub(crate) fn property_key(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier && name != "new" && name != "constructor" {
+        name.to_owned()
+    } else {
+        format!("{name:?}")
+    }
+}
@@ -0,0 +1,34 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use ladfile::LadFilePlugin;
+
+use crate::generate_typescript_declaration_files;
+
+/// A plugin which generates typescript declaration files to a specified directory when run
+#[derive(Clone)]
+pub struct TypeScriptDefinitionsLadPlugin {
+    /// The filename of the generated definition file
+    pub filename: PathBuf,
+}
+
+impl Default for TypeScriptDefinitionsLadPlugin {
+    fn default() -> Self {
+        Self {
+            filename: PathBuf::from("bindings.d.ts"),
+        }
+    }
+}
+
+impl LadFilePlugin for TypeScriptDefinitionsLadPlugin {
+    fn run(&self, ladfile: &ladfile::LadFile, path: &Path) -> Result<(), Box<dyn Error>> {
+        generate_typescript_declaration_files(ladfile, path, &self.filename)
+            .map_err(|e| e.into_boxed_dyn_error() as Box<dyn Error>)
+    }
+
+    fn name(&self) -> &'static str {
+        "TypeScript declaration file generator"
+    }
+}
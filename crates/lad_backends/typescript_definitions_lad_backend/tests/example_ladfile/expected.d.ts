@@ -0,0 +1,135 @@
+/**
+ * ## Hello gentlemen
+ * I am  markdown file.
+ * - hello
+ * - world
+ */
+
+/**
+ * I am a simple plain struct type
+ */
+export interface PlainStructType extends ReflectReference {
+    int_field: number;
+    plain_struct_function(p2: number): any;
+}
+
+/**
+ * The functions callable through the `PlainStructType` namespace.
+ */
+export interface PlainStructTypeStatic {
+    plain_struct_function(p1: PlainStructType, p2: number): any;
+}
+
+/**
+ * I am a struct
+ *
+ * Generic parameters: `T` = `number`
+ */
+export interface GenericStructType extends ReflectReference {
+    field: number;
+    field2: number;
+}
+
+/**
+ * The functions callable through the `GenericStructType` namespace.
+ */
+export interface GenericStructTypeStatic {
+    /**
+     * @param ref_ I am some docs for argument 1
+     * @param tuple I am some docs for argument 2
+     * @param option_vec_ref_wrapper I am some docs for argument 3
+     * @returns I am some docs for the return type, I provide a name for the return value too
+     */
+    hello_world(ref_: ReflectReference, tuple: [number, string], option_vec_ref_wrapper?: EnumType[] | null): number;
+}
+
+/**
+ * A reference to a reflectable type
+ */
+export interface ReflectReference {
+}
+
+/**
+ * The functions callable through the `ReflectReference` namespace.
+ */
+export interface ReflectReferenceStatic {
+}
+
+export interface EnumType extends ReflectReference {
+}
+
+/**
+ * The functions callable through the `EnumType` namespace.
+ */
+export interface EnumTypeStatic {
+    /**
+     * Constructs the `Unit` variant of `EnumType`.
+     */
+    Unit(): EnumType;
+    /**
+     * Constructs the `Struct` variant of `EnumType`.
+     */
+    Struct(fields: { field: number }): EnumType;
+    /**
+     * Constructs the `TupleStruct` variant of `EnumType`.
+     */
+    TupleStruct(fields: [number, string]): EnumType;
+}
+
+/**
+ * I am a tuple test type
+ */
+export interface TupleStructType extends ReflectReference {
+    0: number;
+    1: string;
+}
+
+/**
+ * The functions callable through the `TupleStructType` namespace.
+ */
+export interface TupleStructTypeStatic {
+}
+
+/**
+ * I am a unit test type
+ */
+export interface UnitType extends ReflectReference {
+}
+
+/**
+ * The functions callable through the `UnitType` namespace.
+ */
+export interface UnitTypeStatic {
+}
+
+/**
+ * A static namespace, only functions without a receiver can be called through it.
+ */
+export declare const my_static_instance: GenericStructTypeStatic;
+
+/**
+ * A global instance of this type.
+ */
+export declare const my_non_static_instance: UnitType[];
+
+/**
+ * A global instance of this type.
+ */
+export declare const map: Map<string, string>;
+
+export declare function hello_world(arg1: number): number;
+
+/**
+ * Callbacks scripts can implement, which are called by the host application.
+ */
+export interface Callbacks {
+    on_farewell(): unknown;
+    /**
+     * hello from callback
+     *
+     * @param count how many greetings were sent
+     * @returns the reply to the greeting
+     */
+    on_greeting(count: number, sender: PlainStructType): string;
+}
+
@@ -0,0 +1,970 @@
+//! Comparison of LAD files, used to detect changes in the scripting API between versions.
+
+use std::fmt::Display;
+
+use crate::{
+    LadArgument, LadCallback, LadFieldOrVariableKind, LadFile, LadFunction, LadFunctionNamespace,
+    LadInstance, LadTypeDefinition, LadTypeLayout, LadVariant, ReflectionPrimitiveKind,
+};
+
+/// Computes the changes between two versions of a LAD file.
+///
+/// Items are matched by their ids, so a type or function which moved to a different module will appear as removed and re-added.
+pub fn diff_lad_files(old: &LadFile, new: &LadFile) -> LadDiff {
+    let mut differ = LadDiffer {
+        old,
+        new,
+        changes: Vec::default(),
+    };
+    differ.diff_types();
+    differ.diff_functions();
+    differ.diff_globals();
+    differ.diff_callbacks();
+    LadDiff {
+        changes: differ.changes,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+/// The changes between two versions of a LAD file.
+pub struct LadDiff {
+    /// All the changes, in order of appearance
+    pub changes: Vec<LadChange>,
+}
+
+impl LadDiff {
+    /// Returns true if the files describe the same API.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns true if any of the changes can break existing scripts.
+    pub fn is_breaking(&self) -> bool {
+        self.breaking_changes().next().is_some()
+    }
+
+    /// Iterates over the changes which can break existing scripts.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &LadChange> {
+        self.changes
+            .iter()
+            .filter(|c| c.severity == LadChangeSeverity::Breaking)
+    }
+
+    /// Iterates over the changes which do not affect existing scripts.
+    pub fn non_breaking_changes(&self) -> impl Iterator<Item = &LadChange> {
+        self.changes
+            .iter()
+            .filter(|c| c.severity == LadChangeSeverity::NonBreaking)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// A single change to an item of the scripting API.
+pub struct LadChange {
+    /// The kind of item which changed
+    pub item: LadItemKind,
+    /// A human readable path to the item, i.e. `Vec3::new`, `Vec3.x` or `Vec3::new(x)`
+    pub path: String,
+    /// What happened to the item
+    pub change: LadChangeKind,
+    /// A description of the item before the change, for removed and changed items.
+    ///
+    /// For items with a type, like fields and arguments this is the type.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub old: Option<String>,
+    /// A description of the item after the change, for added and changed items.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub new: Option<String>,
+    /// Whether the change can break existing scripts
+    pub severity: LadChangeSeverity,
+}
+
+impl Display for LadChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(
+                f,
+                "{} {} `{}` from `{old}` to `{new}`",
+                self.change, self.item, self.path
+            ),
+            (None, Some(description)) | (Some(description), None) => write!(
+                f,
+                "{} {} `{}`: `{description}`",
+                self.change, self.item, self.path
+            ),
+            (None, None) => write!(f, "{} {} `{}`", self.change, self.item, self.path),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// The kinds of items of the scripting API a change can apply to.
+pub enum LadItemKind {
+    /// A type definition
+    Type,
+    /// A field of a struct or enum variant
+    Field,
+    /// An enum variant
+    Variant,
+    /// A function, either global or associated with a type
+    Function,
+    /// An argument of a function or callback
+    Argument,
+    /// The return type of a function
+    ReturnType,
+    /// A global instance
+    Global,
+    /// A callback scripts can implement
+    Callback,
+}
+
+impl Display for LadItemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LadItemKind::Type => "type",
+            LadItemKind::Field => "field",
+            LadItemKind::Variant => "variant",
+            LadItemKind::Function => "function",
+            LadItemKind::Argument => "argument",
+            LadItemKind::ReturnType => "return type of",
+            LadItemKind::Global => "global",
+            LadItemKind::Callback => "callback",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// What happened to an item of the scripting API.
+pub enum LadChangeKind {
+    /// The item only exists in the new file
+    Added,
+    /// The item only exists in the old file
+    Removed,
+    /// The item exists in both files but is different
+    Changed,
+}
+
+impl Display for LadChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LadChangeKind::Added => "added",
+            LadChangeKind::Removed => "removed",
+            LadChangeKind::Changed => "changed",
+        })
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+/// How a change affects scripts written against the old version of the API.
+pub enum LadChangeSeverity {
+    /// Existing scripts keep working
+    NonBreaking,
+    /// Existing scripts might stop working
+    Breaking,
+}
+
+struct LadDiffer<'a> {
+    old: &'a LadFile,
+    new: &'a LadFile,
+    changes: Vec<LadChange>,
+}
+
+impl LadDiffer<'_> {
+    fn push(
+        &mut self,
+        item: LadItemKind,
+        path: String,
+        change: LadChangeKind,
+        old: Option<String>,
+        new: Option<String>,
+        severity: LadChangeSeverity,
+    ) {
+        self.changes.push(LadChange {
+            item,
+            path,
+            change,
+            old,
+            new,
+            severity,
+        })
+    }
+
+    fn diff_types(&mut self) {
+        let (old, new) = (self.old, self.new);
+        for (type_id, old_type) in old.types.iter() {
+            match new.types.get(type_id) {
+                Some(new_type) => self.diff_type(old_type, new_type),
+                None => self.push(
+                    LadItemKind::Type,
+                    old_type.identifier.clone(),
+                    LadChangeKind::Removed,
+                    None,
+                    None,
+                    LadChangeSeverity::Breaking,
+                ),
+            }
+        }
+
+        for (type_id, new_type) in new.types.iter() {
+            if !old.types.contains_key(type_id) {
+                self.push(
+                    LadItemKind::Type,
+                    new_type.identifier.clone(),
+                    LadChangeKind::Added,
+                    None,
+                    None,
+                    LadChangeSeverity::NonBreaking,
+                );
+            }
+        }
+    }
+
+    fn diff_type(&mut self, old_type: &LadTypeDefinition, new_type: &LadTypeDefinition) {
+        let path = new_type.identifier.as_str();
+        match (&old_type.layout, &new_type.layout) {
+            (LadTypeLayout::Opaque, LadTypeLayout::Opaque) => {}
+            (LadTypeLayout::MonoVariant(old_variant), LadTypeLayout::MonoVariant(new_variant))
+                if variant_kind(old_variant) == variant_kind(new_variant) =>
+            {
+                self.diff_variant_fields(path, old_variant, new_variant)
+            }
+            (LadTypeLayout::Enum(old_variants), LadTypeLayout::Enum(new_variants)) => {
+                self.diff_variants(path, old_variants, new_variants)
+            }
+            (old_layout, new_layout) => self.push(
+                LadItemKind::Type,
+                path.to_owned(),
+                LadChangeKind::Changed,
+                Some(layout_kind(old_layout).to_owned()),
+                Some(layout_kind(new_layout).to_owned()),
+                // exposing the layout of an opaque type can't break anything
+                if matches!(old_layout, LadTypeLayout::Opaque) {
+                    LadChangeSeverity::NonBreaking
+                } else {
+                    LadChangeSeverity::Breaking
+                },
+            ),
+        }
+    }
+
+    fn diff_variants(
+        &mut self,
+        type_path: &str,
+        old_variants: &[LadVariant],
+        new_variants: &[LadVariant],
+    ) {
+        for old_variant in old_variants {
+            let path = format!("{type_path}::{}", old_variant.name());
+            match new_variants.iter().find(|v| v.name() == old_variant.name()) {
+                Some(new_variant) if variant_kind(old_variant) == variant_kind(new_variant) => {
+                    self.diff_variant_fields(&path, old_variant, new_variant)
+                }
+                Some(new_variant) => self.push(
+                    LadItemKind::Variant,
+                    path,
+                    LadChangeKind::Changed,
+                    Some(variant_kind(old_variant).to_owned()),
+                    Some(variant_kind(new_variant).to_owned()),
+                    LadChangeSeverity::Breaking,
+                ),
+                None => self.push(
+                    LadItemKind::Variant,
+                    path,
+                    LadChangeKind::Removed,
+                    None,
+                    None,
+                    LadChangeSeverity::Breaking,
+                ),
+            }
+        }
+
+        for new_variant in new_variants {
+            if !old_variants.iter().any(|v| v.name() == new_variant.name()) {
+                self.push(
+                    LadItemKind::Variant,
+                    format!("{type_path}::{}", new_variant.name()),
+                    LadChangeKind::Added,
+                    None,
+                    None,
+                    LadChangeSeverity::NonBreaking,
+                );
+            }
+        }
+    }
+
+    /// Compares the fields of two variants of the same kind
+    fn diff_variant_fields(
+        &mut self,
+        path: &str,
+        old_variant: &LadVariant,
+        new_variant: &LadVariant,
+    ) {
+        let (old_fields, new_fields) = match (old_variant, new_variant) {
+            (LadVariant::Struct { fields: old, .. }, LadVariant::Struct { fields: new, .. }) => (
+                old.iter()
+                    .map(|f| (f.name.clone(), &f.type_))
+                    .collect::<Vec<_>>(),
+                new.iter()
+                    .map(|f| (f.name.clone(), &f.type_))
+                    .collect::<Vec<_>>(),
+            ),
+            (
+                LadVariant::TupleStruct { fields: old, .. },
+                LadVariant::TupleStruct { fields: new, .. },
+            ) => (
+                old.iter()
+                    .enumerate()
+                    .map(|(i, f)| (i.to_string(), &f.type_))
+                    .collect(),
+                new.iter()
+                    .enumerate()
+                    .map(|(i, f)| (i.to_string(), &f.type_))
+                    .collect(),
+            ),
+            _ => return,
+        };
+
+        for (name, old_kind) in old_fields.iter() {
+            let field_path = format!("{path}.{name}");
+            match new_fields.iter().find(|(n, _)| n == name) {
+                Some((_, new_kind)) if old_kind == new_kind => {}
+                Some((_, new_kind)) => self.push(
+                    LadItemKind::Field,
+                    field_path,
+                    LadChangeKind::Changed,
+                    Some(kind_to_string(self.old, old_kind)),
+                    Some(kind_to_string(self.new, new_kind)),
+                    LadChangeSeverity::Breaking,
+                ),
+                None => self.push(
+                    LadItemKind::Field,
+                    field_path,
+                    LadChangeKind::Removed,
+                    Some(kind_to_string(self.old, old_kind)),
+                    None,
+                    LadChangeSeverity::Breaking,
+                ),
+            }
+        }
+
+        for (name, new_kind) in new_fields.iter() {
+            if !old_fields.iter().any(|(n, _)| n == name) {
+                self.push(
+                    LadItemKind::Field,
+                    format!("{path}.{name}"),
+                    LadChangeKind::Added,
+                    None,
+                    Some(kind_to_string(self.new, new_kind)),
+                    LadChangeSeverity::NonBreaking,
+                );
+            }
+        }
+    }
+
+    fn diff_functions(&mut self) {
+        let (old, new) = (self.old, self.new);
+        for (function_id, old_function) in old.functions.iter() {
+            let path = function_path(old, old_function);
+            match new.functions.get(function_id) {
+                Some(new_function) => {
+                    self.diff_arguments(
+                        &path,
+                        &old_function.arguments,
+                        &new_function.arguments,
+                        false,
+                    );
+                    self.diff_return_type(
+                        &path,
+                        &old_function.return_type.kind,
+                        &new_function.return_type.kind,
+                        false,
+                    );
+                }
+                None => self.push(
+                    LadItemKind::Function,
+                    path,
+                    LadChangeKind::Removed,
+                    None,
+                    None,
+                    LadChangeSeverity::Breaking,
+                ),
+            }
+        }
+
+        for (function_id, new_function) in new.functions.iter() {
+            if !old.functions.contains_key(function_id) {
+                self.push(
+                    LadItemKind::Function,
+                    function_path(new, new_function),
+                    LadChangeKind::Added,
+                    None,
+                    None,
+                    LadChangeSeverity::NonBreaking,
+                );
+            }
+        }
+    }
+
+    /// Compares arguments by position.
+    ///
+    /// For functions, scripts provide the arguments, so they can be made more permissive without breaking anything,
+    /// for callbacks scripts receive the arguments, so any change is breaking.
+    fn diff_arguments(
+        &mut self,
+        path: &str,
+        old_arguments: &[LadArgument],
+        new_arguments: &[LadArgument],
+        received_by_script: bool,
+    ) {
+        // the call context is supplied by the host, scripts never see it
+        let visible = |a: &&LadArgument| {
+            !matches!(
+                a.kind,
+                LadFieldOrVariableKind::Primitive(ReflectionPrimitiveKind::FunctionCallContext)
+            )
+        };
+        let old_arguments = old_arguments.iter().filter(visible).collect::<Vec<_>>();
+        let new_arguments = new_arguments.iter().filter(visible).collect::<Vec<_>>();
+
+        for (idx, old_argument) in old_arguments.iter().enumerate() {
+            let argument_path = format!("{path}({})", argument_name(idx, old_argument));
+            match new_arguments.get(idx) {
+                Some(new_argument)
+                    if old_argument.kind == new_argument.kind
+                        && old_argument.name == new_argument.name => {}
+                Some(new_argument) => {
+                    let severity = if old_argument.kind == new_argument.kind
+                        || (!received_by_script
+                            && accepts_more(&old_argument.kind, &new_argument.kind))
+                    {
+                        LadChangeSeverity::NonBreaking
+                    } else {
+                        LadChangeSeverity::Breaking
+                    };
+                    self.push(
+                        LadItemKind::Argument,
+                        argument_path,
+                        LadChangeKind::Changed,
+                        Some(argument_to_string(self.old, idx, old_argument)),
+                        Some(argument_to_string(self.new, idx, new_argument)),
+                        severity,
+                    )
+                }
+                None => self.push(
+                    LadItemKind::Argument,
+                    argument_path,
+                    LadChangeKind::Removed,
+                    Some(argument_to_string(self.old, idx, old_argument)),
+                    None,
+                    LadChangeSeverity::Breaking,
+                ),
+            }
+        }
+
+        for (idx, new_argument) in new_arguments.iter().enumerate().skip(old_arguments.len()) {
+            // missing trailing arguments are passed as nil/unit, which optional arguments accept
+            let severity = if received_by_script
+                || matches!(new_argument.kind, LadFieldOrVariableKind::Option(_))
+            {
+                LadChangeSeverity::NonBreaking
+            } else {
+                LadChangeSeverity::Breaking
+            };
+            self.push(
+                LadItemKind::Argument,
+                format!("{path}({})", argument_name(idx, new_argument)),
+                LadChangeKind::Added,
+                None,
+                Some(argument_to_string(self.new, idx, new_argument)),
+                severity,
+            );
+        }
+    }
+
+    /// Compares return types.
+    ///
+    /// For functions, scripts receive the return value, so it can be made more specific without breaking anything,
+    /// for callbacks scripts provide the return value, so it can be made more permissive instead.
+    fn diff_return_type(
+        &mut self,
+        path: &str,
+        old_kind: &LadFieldOrVariableKind,
+        new_kind: &LadFieldOrVariableKind,
+        provided_by_script: bool,
+    ) {
+        if old_kind == new_kind {
+            return;
+        }
+        let (narrow, wide) = if provided_by_script {
+            (old_kind, new_kind)
+        } else {
+            (new_kind, old_kind)
+        };
+        let severity = if accepts_more(narrow, wide) {
+            LadChangeSeverity::NonBreaking
+        } else {
+            LadChangeSeverity::Breaking
+        };
+        self.push(
+            LadItemKind::ReturnType,
+            path.to_owned(),
+            LadChangeKind::Changed,
+            Some(kind_to_string(self.old, old_kind)),
+            Some(kind_to_string(self.new, new_kind)),
+            severity,
+        );
+    }
+
+    fn diff_globals(&mut self) {
+        let (old, new) = (self.old, self.new);
+        for (name, old_global) in old.globals.iter() {
+            match new.globals.get(name) {
+                Some(new_global)
+                    if old_global.is_static == new_global.is_static
+                        && old_global.type_kind == new_global.type_kind => {}
+                Some(new_global) => self.push(
+                    LadItemKind::Global,
+                    name.to_string(),
+                    LadChangeKind::Changed,
+                    Some(global_to_string(self.old, old_global)),
+                    Some(global_to_string(self.new, new_global)),
+                    LadChangeSeverity::Breaking,
+                ),
+                None => self.push(
+                    LadItemKind::Global,
+                    name.to_string(),
+                    LadChangeKind::Removed,
+                    Some(global_to_string(self.old, old_global)),
+                    None,
+                    LadChangeSeverity::Breaking,
+                ),
+            }
+        }
+
+        for (name, new_global) in new.globals.iter() {
+            if !old.globals.contains_key(name) {
+                self.push(
+                    LadItemKind::Global,
+                    name.to_string(),
+                    LadChangeKind::Added,
+                    None,
+                    Some(global_to_string(self.new, new_global)),
+                    LadChangeSeverity::NonBreaking,
+                );
+            }
+        }
+    }
+
+    fn diff_callbacks(&mut self) {
+        let (old, new) = (self.old, self.new);
+        for (label, old_callback) in old.callbacks.iter() {
+            match new.callbacks.get(label) {
+                Some(new_callback) => {
+                    self.diff_arguments(
+                        label,
+                        &old_callback.arguments,
+                        &new_callback.arguments,
+                        true,
+                    );
+                    self.diff_callback_return_type(label, old_callback, new_callback);
+                }
+                // scripts implementing the callback will no longer have it called
+                None => self.push(
+                    LadItemKind::Callback,
+                    label.to_string(),
+                    LadChangeKind::Removed,
+                    None,
+                    None,
+                    LadChangeSeverity::Breaking,
+                ),
+            }
+        }
+
+        for label in new.callbacks.keys() {
+            if !old.callbacks.contains_key(label) {
+                self.push(
+                    LadItemKind::Callback,
+                    label.to_string(),
+                    LadChangeKind::Added,
+                    None,
+                    None,
+                    LadChangeSeverity::NonBreaking,
+                );
+            }
+        }
+    }
+
+    fn diff_callback_return_type(
+        &mut self,
+        label: &str,
+        old_callback: &LadCallback,
+        new_callback: &LadCallback,
+    ) {
+        match (&old_callback.return_type, &new_callback.return_type) {
+            (Some(old_return), Some(new_return)) => {
+                self.diff_return_type(label, &old_return.kind, &new_return.kind, true)
+            }
+            // the value returned by scripts is now ignored
+            (Some(old_return), None) => self.push(
+                LadItemKind::ReturnType,
+                label.to_owned(),
+                LadChangeKind::Removed,
+                Some(kind_to_string(self.old, &old_return.kind)),
+                None,
+                LadChangeSeverity::NonBreaking,
+            ),
+            // scripts which return nothing no longer satisfy the callback, unless the value is optional
+            (None, Some(new_return)) => {
+                let severity = if matches!(new_return.kind, LadFieldOrVariableKind::Option(_)) {
+                    LadChangeSeverity::NonBreaking
+                } else {
+                    LadChangeSeverity::Breaking
+                };
+                self.push(
+                    LadItemKind::ReturnType,
+                    label.to_owned(),
+                    LadChangeKind::Added,
+                    None,
+                    Some(kind_to_string(self.new, &new_return.kind)),
+                    severity,
+                )
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Returns true if every value of the `narrow` kind is also accepted by the `wide` kind, and they differ.
+fn accepts_more(narrow: &LadFieldOrVariableKind, wide: &LadFieldOrVariableKind) -> bool {
+    match (narrow, wide) {
+        (narrow, LadFieldOrVariableKind::Option(inner)) => narrow == inner.as_ref(),
+        // errors are raised in scripts rather than returned, so results look like their inner value
+        (narrow, LadFieldOrVariableKind::InteropResult(inner))
+        | (LadFieldOrVariableKind::InteropResult(inner), narrow) => narrow == inner.as_ref(),
+        // references are converted as necessary by the host
+        (
+            LadFieldOrVariableKind::Ref(narrow)
+            | LadFieldOrVariableKind::Mut(narrow)
+            | LadFieldOrVariableKind::Val(narrow),
+            LadFieldOrVariableKind::Ref(wide)
+            | LadFieldOrVariableKind::Mut(wide)
+            | LadFieldOrVariableKind::Val(wide),
+        ) => narrow == wide,
+        _ => false,
+    }
+}
+
+fn variant_kind(variant: &LadVariant) -> &'static str {
+    match variant {
+        LadVariant::TupleStruct { .. } => "tuple struct",
+        LadVariant::Struct { .. } => "struct",
+        LadVariant::Unit { .. } => "unit",
+    }
+}
+
+fn layout_kind(layout: &LadTypeLayout) -> &'static str {
+    match layout {
+        LadTypeLayout::Opaque => "opaque",
+        LadTypeLayout::MonoVariant(variant) => variant_kind(variant),
+        LadTypeLayout::Enum(_) => "enum",
+    }
+}
+
+fn function_path(ladfile: &LadFile, function: &LadFunction) -> String {
+    match &function.namespace {
+        LadFunctionNamespace::Type(type_id) => format!(
+            "{}::{}",
+            ladfile.get_type_identifier(type_id, None),
+            function.identifier_with_overload()
+        ),
+        LadFunctionNamespace::Global => function.identifier_with_overload().into_owned(),
+    }
+}
+
+fn argument_name(idx: usize, argument: &LadArgument) -> String {
+    argument
+        .name
+        .as_ref()
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| format!("#{}", idx + 1))
+}
+
+fn argument_to_string(ladfile: &LadFile, idx: usize, argument: &LadArgument) -> String {
+    format!(
+        "{}: {}",
+        argument_name(idx, argument),
+        kind_to_string(ladfile, &argument.kind)
+    )
+}
+
+fn global_to_string(ladfile: &LadFile, global: &LadInstance) -> String {
+    if global.is_static {
+        format!("static {}", kind_to_string(ladfile, &global.type_kind))
+    } else {
+        kind_to_string(ladfile, &global.type_kind)
+    }
+}
+
+/// Renders a kind as a rust-like type
+fn kind_to_string(ladfile: &LadFile, kind: &LadFieldOrVariableKind) -> String {
+    match kind {
+        LadFieldOrVariableKind::Ref(type_id)
+        | LadFieldOrVariableKind::Mut(type_id)
+        | LadFieldOrVariableKind::Val(type_id)
+        | LadFieldOrVariableKind::Unknown(type_id) => {
+            ladfile.get_type_identifier(type_id, None).into_owned()
+        }
+        LadFieldOrVariableKind::Option(inner) => {
+            format!("Option<{}>", kind_to_string(ladfile, inner))
+        }
+        LadFieldOrVariableKind::Vec(inner) => format!("Vec<{}>", kind_to_string(ladfile, inner)),
+        LadFieldOrVariableKind::HashMap(key, value) => format!(
+            "HashMap<{}, {}>",
+            kind_to_string(ladfile, key),
+            kind_to_string(ladfile, value)
+        ),
+        LadFieldOrVariableKind::HashSet(inner) => {
+            format!("HashSet<{}>", kind_to_string(ladfile, inner))
+        }
+        LadFieldOrVariableKind::InteropResult(inner) => {
+            format!("Result<{}>", kind_to_string(ladfile, inner))
+        }
+        LadFieldOrVariableKind::Tuple(kinds) => format!(
+            "({})",
+            kinds
+                .iter()
+                .map(|k| kind_to_string(ladfile, k))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        LadFieldOrVariableKind::UntypedTuple => String::from("(..)"),
+        LadFieldOrVariableKind::Array(inner, len) => {
+            format!("[{}; {len}]", kind_to_string(ladfile, inner))
+        }
+        LadFieldOrVariableKind::Primitive(primitive) => primitive.to_string(),
+        LadFieldOrVariableKind::Union(kinds) => kinds
+            .iter()
+            .map(|k| kind_to_string(ladfile, k))
+            .collect::<Vec<_>>()
+            .join(" | "),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "testfile")]
+mod test {
+    use std::borrow::Cow;
+
+    use crate::{LadFunctionId, LadNamedField, LadTypeId, parse_lad_file};
+
+    use super::*;
+
+    fn example_ladfile() -> LadFile {
+        parse_lad_file(crate::EXAMPLE_LADFILE).expect("failed to parse example LAD file")
+    }
+
+    fn plain_struct_function(ladfile: &mut LadFile) -> &mut LadFunction {
+        ladfile
+            .functions
+            .get_mut(&LadFunctionId::new_string_id(
+                "ladfile_builder::test::PlainStructType::plain_struct_function".into(),
+            ))
+            .expect("missing function")
+    }
+
+    #[test]
+    fn identical_files_have_no_changes() {
+        let ladfile = example_ladfile();
+        let diff = diff_lad_files(&ladfile, &ladfile);
+        assert!(diff.is_empty(), "{diff:?}");
+    }
+
+    #[test]
+    fn removed_and_added_items_are_reported() {
+        let old = example_ladfile();
+        let mut new = old.clone();
+        new.globals.shift_remove("map");
+        new.functions
+            .shift_remove(&LadFunctionId::new_string_id("::hello_world".into()));
+        new.callbacks.insert(
+            Cow::Borrowed("on_update"),
+            crate::LadCallback {
+                arguments: vec![],
+                documentation: None,
+                return_type: None,
+            },
+        );
+
+        let diff = diff_lad_files(&old, &new);
+
+        assert_eq!(
+            diff.changes
+                .iter()
+                .map(|c| (c.item, c.path.as_str(), c.change, c.severity))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    LadItemKind::Function,
+                    "hello_world",
+                    LadChangeKind::Removed,
+                    LadChangeSeverity::Breaking
+                ),
+                (
+                    LadItemKind::Global,
+                    "map",
+                    LadChangeKind::Removed,
+                    LadChangeSeverity::Breaking
+                ),
+                (
+                    LadItemKind::Callback,
+                    "on_update",
+                    LadChangeKind::Added,
+                    LadChangeSeverity::NonBreaking
+                ),
+            ]
+        );
+        assert!(diff.is_breaking());
+    }
+
+    #[test]
+    fn optional_trailing_arguments_are_not_breaking() {
+        let old = example_ladfile();
+        let mut new = old.clone();
+        plain_struct_function(&mut new).arguments.push(LadArgument {
+            kind: LadFieldOrVariableKind::Option(Box::new(LadFieldOrVariableKind::Primitive(
+                ReflectionPrimitiveKind::Bool,
+            ))),
+            documentation: None,
+            name: Some("flag".into()),
+        });
+
+        let diff = diff_lad_files(&old, &new);
+
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(
+            diff.changes[0].to_string(),
+            "added argument `PlainStructType::plain_struct_function(flag)`: `flag: Option<Bool>`"
+        );
+        assert!(!diff.is_breaking());
+    }
+
+    #[test]
+    fn argument_and_return_type_changes_are_classified() {
+        let old = example_ladfile();
+        let mut new = old.clone();
+        let function = plain_struct_function(&mut new);
+        // accepting an option is more permissive
+        function.arguments[1].kind =
+            LadFieldOrVariableKind::Option(Box::new(function.arguments[1].kind.clone()));
+        function.return_type.kind =
+            LadFieldOrVariableKind::Primitive(ReflectionPrimitiveKind::String);
+
+        let diff = diff_lad_files(&old, &new);
+
+        assert_eq!(
+            diff.changes
+                .iter()
+                .map(|c| (c.item, c.severity))
+                .collect::<Vec<_>>(),
+            vec![
+                (LadItemKind::Argument, LadChangeSeverity::NonBreaking),
+                (LadItemKind::ReturnType, LadChangeSeverity::Breaking),
+            ]
+        );
+    }
+
+    #[test]
+    fn field_changes_are_reported() {
+        let old = example_ladfile();
+        let mut new = old.clone();
+        let plain_struct = new
+            .types
+            .get_mut(&LadTypeId::new_string_id(
+                "ladfile_builder::test::PlainStructType".into(),
+            ))
+            .expect("missing type");
+        plain_struct.layout = LadTypeLayout::MonoVariant(LadVariant::Struct {
+            name: "PlainStructType".into(),
+            fields: vec![LadNamedField {
+                name: "float_field".into(),
+                type_: LadFieldOrVariableKind::Primitive(ReflectionPrimitiveKind::F32),
+                documentation: None,
+                deprecated: None,
+            }],
+            documentation: None,
+            deprecated: None,
+        });
+
+        let diff = diff_lad_files(&old, &new);
+
+        assert_eq!(
+            diff.changes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "removed field `PlainStructType.int_field`: `Usize`",
+                "added field `PlainStructType.float_field`: `F32`",
+            ]
+        );
+    }
+
+    #[test]
+    fn callback_return_type_changes_are_classified() {
+        let usize_kind = LadFieldOrVariableKind::Primitive(ReflectionPrimitiveKind::Usize);
+        let callback = |return_kind: Option<LadFieldOrVariableKind>| LadCallback {
+            arguments: vec![],
+            documentation: None,
+            return_type: return_kind.map(|kind| LadArgument {
+                kind,
+                documentation: None,
+                name: None,
+            }),
+        };
+        let mut old = example_ladfile();
+        old.callbacks.insert(
+            Cow::Borrowed("on_widened"),
+            callback(Some(usize_kind.clone())),
+        );
+        old.callbacks
+            .insert(Cow::Borrowed("on_required"), callback(None));
+        let mut new = old.clone();
+        // the host accepting an option is more permissive
+        new.callbacks.insert(
+            Cow::Borrowed("on_widened"),
+            callback(Some(LadFieldOrVariableKind::Option(Box::new(
+                usize_kind.clone(),
+            )))),
+        );
+        new.callbacks
+            .insert(Cow::Borrowed("on_required"), callback(Some(usize_kind)));
+
+        let diff = diff_lad_files(&old, &new);
+
+        assert_eq!(
+            diff.changes
+                .iter()
+                .map(|c| (c.item, c.path.as_str(), c.change, c.severity))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    LadItemKind::ReturnType,
+                    "on_widened",
+                    LadChangeKind::Changed,
+                    LadChangeSeverity::NonBreaking
+                ),
+                (
+                    LadItemKind::ReturnType,
+                    "on_required",
+                    LadChangeKind::Added,
+                    LadChangeSeverity::Breaking
+                ),
+            ]
+        );
+    }
+}
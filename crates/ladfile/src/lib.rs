@@ -10,8 +10,11 @@ use std::{
 };
 
 pub use bevy_mod_scripting_bindings_domain::ReflectionPrimitiveKind; // re-export the thing we use
+use bevy_mod_scripting_bindings_domain::deprecation_message;
 use indexmap::IndexMap;
+mod diff;
 mod plugin;
+pub use diff::*;
 pub use plugin::*;
 
 /// The current version of the LAD_VERSION format supported by this library.
@@ -37,6 +40,10 @@ pub struct LadFile {
     /// The functions defined in the LAD file.
     pub functions: IndexMap<LadFunctionId, LadFunction>,
 
+    /// The callbacks scripts can implement, keyed by their label.
+    #[serde(skip_serializing_if = "IndexMap::is_empty", default)]
+    pub callbacks: IndexMap<Cow<'static, str>, LadCallback>,
+
     /// A description of the LAD file and its contents in markdown
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub description: Option<String>,
@@ -50,6 +57,7 @@ impl LadFile {
             globals: IndexMap::new(),
             types: IndexMap::new(),
             functions: IndexMap::new(),
+            callbacks: IndexMap::new(),
             description: None,
         }
     }
@@ -201,19 +209,66 @@ pub struct LadFunction {
     /// The documentation describing the function.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub documentation: Option<Cow<'static, str>>,
+    /// Usage examples of the function, possibly written in different languages.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub examples: Vec<LadExample>,
     /// Function metadata
     pub metadata: LadFunctionMetadata,
 }
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// A usage example of a function.
+pub struct LadExample {
+    /// The scripting language the example is written in, i.e. `lua`.
+    pub language: String,
+    /// The code of the example.
+    pub code: String,
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// A callback which scripts can implement, and which is called from the Rust side.
+pub struct LadCallback {
+    /// The arguments the callback is called with, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub arguments: Vec<LadArgument>,
+    /// The documentation describing the callback.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub documentation: Option<Cow<'static, str>>,
+    /// The value the callback is expected to return, if `None` the return value is ignored.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub return_type: Option<LadArgument>,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 /// Additional data relevant to functions
 pub struct LadFunctionMetadata {
     /// True if the function represents a supported binary or unary operation
     pub is_operator: bool,
+    /// If `Some`, the function is deprecated and should not be used in new scripts
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub deprecated: Option<LadFunctionDeprecation>,
     /// Extra metadata to be populated by external plugins
     #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     pub misc: HashMap<String, String>,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+/// Information about the deprecation of a function.
+pub struct LadFunctionDeprecation {
+    /// The version the function was deprecated in.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub since: Option<String>,
+    /// The name of the function which should be used instead.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub replacement: Option<String>,
+}
+
+impl LadFunctionDeprecation {
+    /// Describes the deprecation in a short sentence, i.e. "deprecated since 0.2, use `new` instead".
+    pub fn message(&self) -> String {
+        deprecation_message(self.since.as_deref(), self.replacement.as_deref())
+    }
+}
+
 impl LadFunction {
     /// Checks if the function is an overload, and if so parses the overload number and the true name.
     pub fn as_overload(&self) -> Option<(Cow<'static, str>, usize)> {
@@ -586,6 +641,10 @@ pub struct LadTypeMetadata {
     /// Set to a primitive kind if this type is mapped to a primitive
     pub mapped_to_primitive_kind: Option<ReflectionPrimitiveKind>,
 
+    /// If set, the type is deprecated with the given message
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub deprecated: Option<String>,
+
     /// Extra metadata sections that plugins can use to serialize other information
     #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     pub misc: HashMap<String, String>,
@@ -616,6 +675,16 @@ pub enum LadTypeLayout {
 }
 
 impl LadTypeLayout {
+    /// Returns true if the layout is an enum with a variant of the given name.
+    ///
+    /// Such enums have a constructor function of the same name registered on their namespace.
+    pub fn is_enum_variant(&self, name: &str) -> bool {
+        match self {
+            LadTypeLayout::Enum(variants) => variants.iter().any(|v| v.name() == name),
+            _ => false,
+        }
+    }
+
     /// Traverses the layout in a depth-first manner and calls the provided function on each variant in order of appearance.
     /// Calls the function with the variant and its index in the layout starting from 0.
     ///
@@ -655,6 +724,14 @@ pub enum LadVariant {
         /// The fields of the tuple struct variant.
         #[serde(skip_serializing_if = "Vec::is_empty", default)]
         fields: Vec<LadField>,
+
+        /// The documentation of the variant.
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        documentation: Option<String>,
+
+        /// If set, the variant is deprecated with the given message.
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        deprecated: Option<String>,
     },
     /// A struct variant i.e. a struct with named fields.
     Struct {
@@ -666,6 +743,14 @@ pub enum LadVariant {
         /// The fields of the struct variant.
         #[serde(skip_serializing_if = "Vec::is_empty", default)]
         fields: Vec<LadNamedField>,
+
+        /// The documentation of the variant.
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        documentation: Option<String>,
+
+        /// If set, the variant is deprecated with the given message.
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        deprecated: Option<String>,
     },
     /// A unit variant i.e. a type with no fields
     Unit {
@@ -673,15 +758,62 @@ pub enum LadVariant {
         ///
         /// For types which are not Enums, this will simply be the name of the type or its path if no identifier is present.
         name: Cow<'static, str>,
+
+        /// The documentation of the variant.
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        documentation: Option<String>,
+
+        /// If set, the variant is deprecated with the given message.
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        deprecated: Option<String>,
     },
 }
 
+impl LadVariant {
+    /// Returns the name of the variant.
+    ///
+    /// For enums, scripts can construct each variant through a function of the same name on the enum's namespace, i.e. `MyEnum.Variant{...}`.
+    pub fn name(&self) -> &str {
+        match self {
+            LadVariant::TupleStruct { name, .. }
+            | LadVariant::Struct { name, .. }
+            | LadVariant::Unit { name, .. } => name,
+        }
+    }
+
+    /// Returns the documentation of the variant.
+    ///
+    /// For types which are not Enums, the documentation lives on the type instead.
+    pub fn documentation(&self) -> Option<&str> {
+        match self {
+            LadVariant::TupleStruct { documentation, .. }
+            | LadVariant::Struct { documentation, .. }
+            | LadVariant::Unit { documentation, .. } => documentation.as_deref(),
+        }
+    }
+
+    /// Returns the deprecation message of the variant, if it is deprecated.
+    pub fn deprecated(&self) -> Option<&str> {
+        match self {
+            LadVariant::TupleStruct { deprecated, .. }
+            | LadVariant::Struct { deprecated, .. }
+            | LadVariant::Unit { deprecated, .. } => deprecated.as_deref(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 /// A field definition used in a LAD file.
 pub struct LadField {
     /// The type of the field.
     #[serde(rename = "type")]
     pub type_: LadFieldOrVariableKind,
+    /// The documentation of the field.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub documentation: Option<String>,
+    /// If set, the field is deprecated with the given message.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub deprecated: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -692,6 +824,12 @@ pub struct LadNamedField {
     #[serde(rename = "type")]
     /// The type of the field.
     pub type_: LadFieldOrVariableKind,
+    /// The documentation of the field.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub documentation: Option<String>,
+    /// If set, the field is deprecated with the given message.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub deprecated: Option<String>,
 }
 
 /// A generic type definition used in a LAD file.
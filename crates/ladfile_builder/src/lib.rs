@@ -7,7 +7,8 @@ use bevy_ecs::{
 };
 use bevy_log::warn;
 use bevy_mod_scripting_bindings::{
-    MarkAsCore, MarkAsGenerated, MarkAsSignificant, ReflectReference, ScriptValue,
+    MarkAsCore, MarkAsGenerated, MarkAsSignificant, ReflectReference, ReflectScriptAttributes,
+    ScriptValue,
     docgen::{
         TypedThrough,
         info::FunctionInfo,
@@ -20,6 +21,7 @@ use bevy_mod_scripting_bindings::{
     into_through_type_info,
 };
 pub use bevy_mod_scripting_bindings_domain::*; // re-export the thing we use
+use bevy_mod_scripting_core::callback_registry::ScriptCallbackInfo;
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::{NamedField, TypeInfo, TypeRegistry, Typed, UnnamedField, enums::VariantInfo};
 use ladfile::*;
@@ -75,6 +77,8 @@ fn primitive_from_type_id(type_id: TypeId) -> Option<ReflectionPrimitiveKind> {
         ReflectionPrimitiveKind::OsString
     } else if type_id == TypeId::of::<PathBuf>() {
         ReflectionPrimitiveKind::PathBuf
+    } else if type_id == TypeId::of::<Vec<u8>>() {
+        ReflectionPrimitiveKind::Bytes
     } else if type_id == TypeId::of::<FunctionCallContext>() {
         ReflectionPrimitiveKind::FunctionCallContext
     } else if type_id == TypeId::of::<DynamicScriptFunction>() {
@@ -110,6 +114,7 @@ fn type_id_from_primitive(kind: &ReflectionPrimitiveKind) -> Option<TypeId> {
         ReflectionPrimitiveKind::String => TypeId::of::<String>(),
         ReflectionPrimitiveKind::OsString => TypeId::of::<OsString>(),
         ReflectionPrimitiveKind::PathBuf => TypeId::of::<PathBuf>(),
+        ReflectionPrimitiveKind::Bytes => TypeId::of::<Vec<u8>>(),
         ReflectionPrimitiveKind::FunctionCallContext => TypeId::of::<FunctionCallContext>(),
         ReflectionPrimitiveKind::DynamicFunction => TypeId::of::<DynamicScriptFunction>(),
         ReflectionPrimitiveKind::DynamicFunctionMut => TypeId::of::<DynamicScriptFunctionMut>(),
@@ -127,6 +132,7 @@ pub struct LadFileBuilder<'t> {
     type_registry: &'t TypeRegistry,
     sorted: bool,
     exclude_types_involving_unregistered_types: bool,
+    hidden_types: HashSet<LadTypeId>,
 }
 
 impl<'t> LadFileBuilder<'t> {
@@ -138,6 +144,7 @@ impl<'t> LadFileBuilder<'t> {
             type_registry,
             sorted: false,
             exclude_types_involving_unregistered_types: false,
+            hidden_types: HashSet::new(),
         }
     }
 
@@ -167,6 +174,7 @@ impl<'t> LadFileBuilder<'t> {
             .add_bms_primitive(String, "A heap allocated string")
             .add_bms_primitive(OsString, "A heap allocated OS string")
             .add_bms_primitive(PathBuf, "A heap allocated file path")
+            .add_bms_primitive(Bytes, "A contiguous buffer of bytes")
             .add_bms_primitive(FunctionCallContext, "Function call context, if accepted by a function, means the function can access the world in arbitrary ways.")
             .add_bms_primitive(DynamicFunction, "A callable dynamic function")
             .add_bms_primitive(DynamicFunctionMut, "A stateful and callable dynamic function")
@@ -218,6 +226,7 @@ impl<'t> LadFileBuilder<'t> {
                     is_resource: false,
                     is_reflect: false,
                     mapped_to_primitive_kind: Some(primitive),
+                    deprecated: None,
                     misc: Default::default(),
                 },
             },
@@ -334,6 +343,7 @@ impl<'t> LadFileBuilder<'t> {
                     is_resource: false,
                     is_reflect: false,
                     mapped_to_primitive_kind: primitive_from_type_id(std::any::TypeId::of::<T>()),
+                    deprecated: None,
                     misc: Default::default(),
                 },
             },
@@ -351,8 +361,16 @@ impl<'t> LadFileBuilder<'t> {
 
     /// Add a type definition to the LAD file.
     /// Will overwrite any existing type definitions with the same type id.
+    ///
+    /// Types marked as hidden via [`ReflectScriptAttributes`] are skipped, along with any types, fields, functions, globals and callbacks referring to them.
     pub fn add_type_info(&mut self, type_info: &TypeInfo) -> &mut Self {
         let registration = self.type_registry.get(type_info.type_id());
+        let attributes = self.script_attributes(type_info.type_id());
+        let type_id = self.lad_id_from_type_id(type_info.type_id());
+        if attributes.type_attributes.hidden {
+            self.hidden_types.insert(type_id);
+            return self;
+        }
 
         let mut insignificance = default_importance();
         let mut generated = false;
@@ -377,11 +395,11 @@ impl<'t> LadFileBuilder<'t> {
             }
         }
 
-        let type_id = self.lad_id_from_type_id(type_info.type_id());
         let lad_type = LadTypeDefinition {
-            identifier: type_info
-                .type_path_table()
-                .ident()
+            identifier: attributes
+                .type_attributes
+                .rename
+                .or_else(|| type_info.type_path_table().ident())
                 .unwrap_or_default()
                 .to_string(),
             generics: type_info
@@ -399,7 +417,7 @@ impl<'t> LadFileBuilder<'t> {
                 .crate_name()
                 .map(|s| s.to_owned()),
             path: type_info.type_path_table().path().to_owned(),
-            layout: self.lad_layout_from_type_info(type_info),
+            layout: self.lad_layout_from_type_info(type_info, &attributes),
             generated,
             insignificance,
             metadata: LadTypeMetadata {
@@ -407,6 +425,7 @@ impl<'t> LadFileBuilder<'t> {
                 is_resource,
                 is_reflect,
                 mapped_to_primitive_kind: primitive_from_type_id(type_info.type_id()),
+                deprecated: attributes.type_attributes.deprecated.map(|s| s.to_owned()),
                 misc: Default::default(),
             },
         };
@@ -534,6 +553,14 @@ impl<'t> LadFileBuilder<'t> {
                     }),
             },
             documentation: (!main_docstring.is_empty()).then_some(main_docstring.into()),
+            examples: function_info
+                .examples
+                .iter()
+                .map(|example| LadExample {
+                    language: example.language.to_string(),
+                    code: example.code.to_string(),
+                })
+                .collect(),
             namespace: match function_info.namespace {
                 Namespace::Global => LadFunctionNamespace::Global,
                 Namespace::OnType(type_id) => {
@@ -542,6 +569,15 @@ impl<'t> LadFileBuilder<'t> {
             },
             metadata: LadFunctionMetadata {
                 is_operator: ScriptOperatorNames::parse(identifier).is_some(),
+                deprecated: function_info.deprecation.as_ref().map(|deprecation| {
+                    LadFunctionDeprecation {
+                        since: deprecation.since.as_ref().map(|since| since.to_string()),
+                        replacement: deprecation
+                            .replacement
+                            .as_ref()
+                            .map(|replacement| replacement.to_string()),
+                    }
+                }),
                 misc: Default::default(),
             },
         };
@@ -549,6 +585,42 @@ impl<'t> LadFileBuilder<'t> {
         self
     }
 
+    /// Add a callback scripts can implement to the LAD file, along with the types of its arguments.
+    /// Will overwrite any existing callback with the same label.
+    pub fn add_callback_info(&mut self, callback_info: &ScriptCallbackInfo) -> &mut Self {
+        let arguments = callback_info
+            .arguments
+            .iter()
+            .map(|arg| {
+                self.add_through_type_info(&arg.type_information);
+                LadArgument {
+                    kind: self.lad_type_kind_from_through_type(&arg.type_information),
+                    documentation: arg.documentation.clone(),
+                    name: Some(arg.name.clone()),
+                }
+            })
+            .collect();
+
+        let return_type = callback_info.returns.as_ref().map(|returns| {
+            self.add_through_type_info(&returns.type_information);
+            LadArgument {
+                kind: self.lad_type_kind_from_through_type(&returns.type_information),
+                documentation: returns.documentation.clone(),
+                name: None,
+            }
+        });
+
+        self.file.callbacks.insert(
+            callback_info.label.to_string().into(),
+            LadCallback {
+                arguments,
+                documentation: callback_info.documentation.clone(),
+                return_type,
+            },
+        );
+        self
+    }
+
     /// Set the markdown description of the LAD file.
     pub fn set_description(&mut self, description: impl Into<String>) -> &mut Self {
         self.file.description = Some(description.into());
@@ -604,6 +676,55 @@ impl<'t> LadFileBuilder<'t> {
             file.types.retain(|id, _| !to_remove.contains(id));
         }
 
+        // drop anything referring to hidden types, including generic instantiations over them
+        if !self.hidden_types.is_empty() {
+            let mut hidden = self.hidden_types.clone();
+            loop {
+                let newly_hidden = file
+                    .types
+                    .iter()
+                    .filter(|(id, definition)| {
+                        !hidden.contains(*id)
+                            && definition
+                                .generics
+                                .iter()
+                                .any(|generic| hidden.contains(&generic.type_id))
+                    })
+                    .map(|(id, _)| id.clone())
+                    .collect::<Vec<_>>();
+                if newly_hidden.is_empty() {
+                    break;
+                }
+                hidden.extend(newly_hidden);
+            }
+
+            file.types.retain(|id, _| !hidden.contains(id));
+            for definition in file.types.values_mut() {
+                Self::remove_fields_referring_to(&mut definition.layout, &hidden);
+            }
+            file.functions.retain(|_, function| {
+                let hidden_namespace = match &function.namespace {
+                    LadFunctionNamespace::Type(type_id) => hidden.contains(type_id),
+                    LadFunctionNamespace::Global => false,
+                };
+                !hidden_namespace
+                    && !Self::kind_refers_to(&function.return_type.kind, &hidden)
+                    && !function
+                        .arguments
+                        .iter()
+                        .any(|arg| Self::kind_refers_to(&arg.kind, &hidden))
+            });
+            file.globals
+                .retain(|_, global| !Self::kind_refers_to(&global.type_kind, &hidden));
+            file.callbacks.retain(|_, callback| {
+                !callback
+                    .arguments
+                    .iter()
+                    .chain(callback.return_type.iter())
+                    .any(|arg| Self::kind_refers_to(&arg.kind, &hidden))
+            });
+        }
+
         // associate functions on type namespaces with their types
         for (function_id, function) in file.functions.iter() {
             match &function.namespace {
@@ -651,11 +772,54 @@ impl<'t> LadFileBuilder<'t> {
             });
 
             file.functions.sort_keys();
+            file.callbacks.sort_keys();
         }
 
         file
     }
 
+    /// Checks if the kind mentions any of the given types, at any depth.
+    fn kind_refers_to(kind: &LadFieldOrVariableKind, type_ids: &HashSet<LadTypeId>) -> bool {
+        match kind {
+            LadFieldOrVariableKind::Ref(type_id)
+            | LadFieldOrVariableKind::Mut(type_id)
+            | LadFieldOrVariableKind::Val(type_id)
+            | LadFieldOrVariableKind::Unknown(type_id) => type_ids.contains(type_id),
+            LadFieldOrVariableKind::Option(inner)
+            | LadFieldOrVariableKind::Vec(inner)
+            | LadFieldOrVariableKind::HashSet(inner)
+            | LadFieldOrVariableKind::InteropResult(inner)
+            | LadFieldOrVariableKind::Array(inner, _) => Self::kind_refers_to(inner, type_ids),
+            LadFieldOrVariableKind::HashMap(key, value) => {
+                Self::kind_refers_to(key, type_ids) || Self::kind_refers_to(value, type_ids)
+            }
+            LadFieldOrVariableKind::Tuple(inner) | LadFieldOrVariableKind::Union(inner) => inner
+                .iter()
+                .any(|kind| Self::kind_refers_to(kind, type_ids)),
+            LadFieldOrVariableKind::Primitive(_) | LadFieldOrVariableKind::UntypedTuple => false,
+        }
+    }
+
+    /// Removes the fields of all variants in the layout which mention any of the given types, as if they were hidden.
+    fn remove_fields_referring_to(layout: &mut LadTypeLayout, type_ids: &HashSet<LadTypeId>) {
+        let variants = match layout {
+            LadTypeLayout::Opaque => return,
+            LadTypeLayout::MonoVariant(variant) => std::slice::from_mut(variant),
+            LadTypeLayout::Enum(variants) => variants.as_mut_slice(),
+        };
+        for variant in variants {
+            match variant {
+                LadVariant::TupleStruct { fields, .. } => {
+                    fields.retain(|field| !Self::kind_refers_to(&field.type_, type_ids))
+                }
+                LadVariant::Struct { fields, .. } => {
+                    fields.retain(|field| !Self::kind_refers_to(&field.type_, type_ids))
+                }
+                LadVariant::Unit { .. } => {}
+            }
+        }
+    }
+
     /// Checks if a line is one of:
     /// - `# key:`
     /// - `key:`
@@ -783,45 +947,86 @@ impl<'t> LadFileBuilder<'t> {
             .into()
     }
 
+    /// Returns the script attributes registered for the given type, or the defaults if there are none
+    fn script_attributes(&self, type_id: TypeId) -> ReflectScriptAttributes {
+        self.type_registry
+            .get_type_data::<ReflectScriptAttributes>(type_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     fn struct_variant_from_named_fields<'a, I: Iterator<Item = &'a NamedField>>(
         &mut self,
         name: Cow<'static, str>,
+        documentation: Option<&str>,
+        deprecated: Option<&str>,
         fields: I,
+        attributes: &ReflectScriptAttributes,
+        member_prefix: &str,
     ) -> LadVariant {
         LadVariant::Struct {
             name,
             fields: fields
-                .map(|field| LadNamedField {
-                    name: field.name().to_string(),
-                    type_: self.lad_type_kind_from_type_id(field.type_id()),
+                .filter_map(|field| {
+                    let field_attributes =
+                        attributes.member(&format!("{member_prefix}{}", field.name()));
+                    (!field_attributes.hidden).then(|| LadNamedField {
+                        name: field_attributes.rename.unwrap_or(field.name()).to_string(),
+                        type_: self.lad_type_kind_from_type_id(field.type_id()),
+                        documentation: field.docs().map(|s| s.to_owned()),
+                        deprecated: field_attributes.deprecated.map(|s| s.to_owned()),
+                    })
                 })
                 .collect(),
+            documentation: documentation.map(|s| s.to_owned()),
+            deprecated: deprecated.map(|s| s.to_owned()),
         }
     }
 
     fn tuple_struct_variant_from_fields<'a, I: Iterator<Item = &'a UnnamedField>>(
         &mut self,
         name: Cow<'static, str>,
+        documentation: Option<&str>,
+        deprecated: Option<&str>,
         fields: I,
+        attributes: &ReflectScriptAttributes,
+        member_prefix: &str,
     ) -> LadVariant {
         LadVariant::TupleStruct {
             name,
             fields: fields
-                .map(|field| LadField {
-                    type_: self.lad_type_kind_from_type_id(field.type_id()),
+                .filter_map(|field| {
+                    let field_attributes =
+                        attributes.member(&format!("{member_prefix}{}", field.index()));
+                    // tuple fields are positional, so renaming them has no effect
+                    (!field_attributes.hidden).then(|| LadField {
+                        type_: self.lad_type_kind_from_type_id(field.type_id()),
+                        documentation: field.docs().map(|s| s.to_owned()),
+                        deprecated: field_attributes.deprecated.map(|s| s.to_owned()),
+                    })
                 })
                 .collect(),
+            documentation: documentation.map(|s| s.to_owned()),
+            deprecated: deprecated.map(|s| s.to_owned()),
         }
     }
 
-    fn lad_layout_from_type_info(&mut self, type_info: &TypeInfo) -> LadTypeLayout {
+    fn lad_layout_from_type_info(
+        &mut self,
+        type_info: &TypeInfo,
+        attributes: &ReflectScriptAttributes,
+    ) -> LadTypeLayout {
         match type_info {
             TypeInfo::Struct(struct_info) => {
                 let fields = (0..struct_info.field_len()).filter_map(|i| struct_info.field_at(i));
 
                 LadTypeLayout::MonoVariant(self.struct_variant_from_named_fields(
                     Self::variant_identifier_for_non_enum(type_info),
+                    None,
+                    None,
                     fields,
+                    attributes,
+                    "",
                 ))
             }
             TypeInfo::TupleStruct(tuple_struct_info) => {
@@ -830,29 +1035,57 @@ impl<'t> LadFileBuilder<'t> {
 
                 LadTypeLayout::MonoVariant(self.tuple_struct_variant_from_fields(
                     Self::variant_identifier_for_non_enum(type_info),
+                    None,
+                    None,
                     fields,
+                    attributes,
+                    "",
                 ))
             }
             TypeInfo::Enum(enum_info) => {
                 let mut variants = Vec::new();
                 for i in 0..enum_info.variant_len() {
                     if let Some(variant) = enum_info.variant_at(i) {
-                        let variant_name = variant.name();
+                        let variant_attributes = attributes.member(variant.name());
+                        if variant_attributes.hidden {
+                            continue;
+                        }
+                        let variant_name: Cow<'static, str> =
+                            variant_attributes.rename.unwrap_or(variant.name()).into();
+                        let member_prefix = format!("{}.", variant.name());
+                        let documentation = variant.docs();
+                        let deprecated = variant_attributes.deprecated;
                         let variant = match variant {
                             VariantInfo::Struct(struct_variant_info) => {
                                 let fields = (0..struct_variant_info.field_len())
                                     .filter_map(|i| struct_variant_info.field_at(i));
 
-                                self.struct_variant_from_named_fields(variant_name.into(), fields)
+                                self.struct_variant_from_named_fields(
+                                    variant_name,
+                                    documentation,
+                                    deprecated,
+                                    fields,
+                                    attributes,
+                                    &member_prefix,
+                                )
                             }
                             VariantInfo::Tuple(tuple_variant_info) => {
                                 let fields = (0..tuple_variant_info.field_len())
                                     .filter_map(|i| tuple_variant_info.field_at(i));
 
-                                self.tuple_struct_variant_from_fields(variant_name.into(), fields)
+                                self.tuple_struct_variant_from_fields(
+                                    variant_name,
+                                    documentation,
+                                    deprecated,
+                                    fields,
+                                    attributes,
+                                    &member_prefix,
+                                )
                             }
                             VariantInfo::Unit(_) => LadVariant::Unit {
-                                name: variant_name.into(),
+                                name: variant_name,
+                                documentation: documentation.map(|s| s.to_owned()),
+                                deprecated: deprecated.map(|s| s.to_owned()),
                             },
                         };
                         variants.push(variant);
@@ -996,7 +1229,7 @@ mod test {
 
     use bevy_mod_scripting_bindings::{
         Union, V,
-        docgen::info::GetFunctionInfo,
+        docgen::info::{FunctionDeprecation, GetFunctionInfo},
         function::{
             from::R,
             namespace::{GlobalNamespace, IntoNamespace},
@@ -1267,10 +1500,16 @@ mod test {
 
         let plain_struct_function =
             |_: R<PlainStructType>, _: usize| PlainStructType { int_field: 2 };
-        let plain_struct_function_info = plain_struct_function.get_function_info(
-            "plain_struct_function".into(),
-            PlainStructType::into_namespace(),
-        );
+        let plain_struct_function_info = plain_struct_function
+            .get_function_info(
+                "plain_struct_function".into(),
+                PlainStructType::into_namespace(),
+            )
+            .with_deprecation(
+                FunctionDeprecation::default()
+                    .with_since("0.1.0")
+                    .with_replacement("hello_world"),
+            );
 
         let function = |_: ReflectReference, _: usize| 2usize;
         let function_info = function
@@ -1302,7 +1541,16 @@ mod test {
         let global_function = |_: usize| 2usize;
         let global_function_info = global_function
             .get_function_info("hello_world".into(), GlobalNamespace::into_namespace())
-            .with_arg_names(&["arg1"]);
+            .with_arg_names(&["arg1"])
+            .with_example("lua", "local result = hello_world(2)")
+            .with_example("rhai", "let result = hello_world(2);");
+
+        let greeting_callback_info = ScriptCallbackInfo::new("on_greeting")
+            .with_documentation("hello from callback")
+            .with_argument::<usize>("count", Some("how many greetings were sent"))
+            .with_argument::<R<PlainStructType>>("sender", None)
+            .with_return::<String>(Some("the reply to the greeting"));
+        let farewell_callback_info = ScriptCallbackInfo::new("on_farewell");
 
         let mut lad_file = LadFileBuilder::new(&type_registry)
             .set_description("## Hello gentlemen\n I am  markdown file.\n - hello\n - world")
@@ -1311,6 +1559,8 @@ mod test {
             .add_function_info(&function_info)
             .add_function_info(&global_function_info)
             .add_function_info(&function_with_complex_args_info)
+            .add_callback_info(&greeting_callback_info)
+            .add_callback_info(&farewell_callback_info)
             .add_type::<GenericStructType<usize>>()
             .add_type::<UnitType>()
             .add_type::<TupleStructType>()
@@ -1376,4 +1626,251 @@ mod test {
 
         assert_eq!(lad_file.types.len(), 0);
     }
+
+    #[test]
+    fn test_script_attributes_control_generated_types() {
+        use bevy_mod_scripting_bindings::{GetScriptAttributes, ScriptAttributes};
+
+        let mut type_registry = TypeRegistry::default();
+
+        #[derive(Reflect)]
+        struct Renamed {
+            /// documented field
+            visible: usize,
+            hidden: usize,
+            renamed: usize,
+        }
+
+        impl GetScriptAttributes for Renamed {
+            fn script_attributes() -> ReflectScriptAttributes {
+                ReflectScriptAttributes {
+                    type_attributes: ScriptAttributes {
+                        rename: Some("Player"),
+                        deprecated: Some("use Other instead"),
+                        ..Default::default()
+                    },
+                    members: vec![
+                        (
+                            "hidden",
+                            ScriptAttributes {
+                                hidden: true,
+                                ..Default::default()
+                            },
+                        ),
+                        (
+                            "renamed",
+                            ScriptAttributes {
+                                rename: Some("new_name"),
+                                deprecated: Some(""),
+                                ..Default::default()
+                            },
+                        ),
+                    ],
+                }
+            }
+        }
+
+        #[derive(Reflect)]
+        struct Hidden;
+
+        impl GetScriptAttributes for Hidden {
+            fn script_attributes() -> ReflectScriptAttributes {
+                ReflectScriptAttributes {
+                    type_attributes: ScriptAttributes {
+                        hidden: true,
+                        ..Default::default()
+                    },
+                    members: vec![],
+                }
+            }
+        }
+
+        type_registry.register::<Renamed>();
+        type_registry.register::<Hidden>();
+        type_registry.register_type_data::<Renamed, ReflectScriptAttributes>();
+        type_registry.register_type_data::<Hidden, ReflectScriptAttributes>();
+
+        let hidden_function = |_: R<Hidden>| 2usize;
+        let hidden_function_info =
+            hidden_function.get_function_info("hidden_function".into(), Hidden::into_namespace());
+
+        let lad_file = LadFileBuilder::new_empty(&type_registry)
+            .add_type::<Renamed>()
+            .add_type::<Hidden>()
+            .add_function_info(&hidden_function_info)
+            .build();
+
+        assert_eq!(lad_file.types.len(), 1);
+        assert!(lad_file.functions.is_empty());
+
+        let renamed = lad_file.types.values().next().unwrap();
+        assert_eq!(renamed.identifier, "Player");
+        assert_eq!(
+            renamed.metadata.deprecated.as_deref(),
+            Some("use Other instead")
+        );
+
+        let LadTypeLayout::MonoVariant(LadVariant::Struct { fields, .. }) = &renamed.layout else {
+            panic!("expected a struct layout, got {:?}", renamed.layout);
+        };
+        let fields = fields
+            .iter()
+            .map(|f| {
+                (
+                    f.name.as_str(),
+                    f.documentation.as_deref(),
+                    f.deprecated.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("visible", Some(" documented field"), None),
+                ("new_name", None, Some("")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hidden_types_are_pruned_from_everything_referring_to_them() {
+        use bevy_mod_scripting_bindings::{GetScriptAttributes, ScriptAttributes};
+
+        #[derive(Reflect)]
+        struct Hidden;
+
+        impl GetScriptAttributes for Hidden {
+            fn script_attributes() -> ReflectScriptAttributes {
+                ReflectScriptAttributes {
+                    type_attributes: ScriptAttributes {
+                        hidden: true,
+                        ..Default::default()
+                    },
+                    members: vec![],
+                }
+            }
+        }
+
+        #[derive(Reflect)]
+        struct Wrapper<T> {
+            inner: T,
+        }
+
+        #[derive(Reflect)]
+        struct Holder {
+            visible: usize,
+            hidden: Vec<Hidden>,
+        }
+
+        #[derive(Reflect)]
+        enum Choice {
+            Visible(usize, Option<Hidden>),
+            Other { hidden: Hidden },
+        }
+
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Hidden>();
+        type_registry.register::<Wrapper<Hidden>>();
+        type_registry.register::<Holder>();
+        type_registry.register::<Choice>();
+        type_registry.register_type_data::<Hidden, ReflectScriptAttributes>();
+
+        let visible_function = |a: usize| a;
+        let argument_function = |_: Vec<R<Hidden>>| 2usize;
+        let return_function = || -> Option<V<Wrapper<Hidden>>> { None };
+
+        let lad_file = LadFileBuilder::new_empty(&type_registry)
+            .add_type::<Hidden>()
+            .add_type::<Wrapper<Hidden>>()
+            .add_type::<Holder>()
+            .add_type::<Choice>()
+            .add_function_info(
+                &visible_function.get_function_info("visible_function".into(), Namespace::Global),
+            )
+            .add_function_info(
+                &argument_function.get_function_info("argument_function".into(), Namespace::Global),
+            )
+            .add_function_info(
+                &return_function.get_function_info("return_function".into(), Namespace::Global),
+            )
+            .build();
+
+        let identifiers = lad_file
+            .types
+            .values()
+            .map(|t| t.identifier.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(identifiers, vec!["Holder", "Choice"]);
+        assert_eq!(
+            lad_file
+                .functions
+                .values()
+                .map(|f| f.identifier.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["visible_function"]
+        );
+
+        let holder = lad_file
+            .types
+            .values()
+            .find(|t| t.identifier == "Holder")
+            .unwrap();
+        let LadTypeLayout::MonoVariant(LadVariant::Struct { fields, .. }) = &holder.layout else {
+            panic!("expected a struct layout, got {:?}", holder.layout);
+        };
+        assert_eq!(
+            fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+            vec!["visible"]
+        );
+
+        let choice = lad_file
+            .types
+            .values()
+            .find(|t| t.identifier == "Choice")
+            .unwrap();
+        let LadTypeLayout::Enum(variants) = &choice.layout else {
+            panic!("expected an enum layout, got {:?}", choice.layout);
+        };
+        match &variants[..] {
+            [
+                LadVariant::TupleStruct {
+                    fields: tuple_fields,
+                    ..
+                },
+                LadVariant::Struct {
+                    fields: struct_fields,
+                    ..
+                },
+            ] => {
+                assert_eq!(tuple_fields.len(), 1);
+                assert!(struct_fields.is_empty());
+            }
+            _ => panic!("unexpected variants: {variants:?}"),
+        }
+    }
+
+    #[test]
+    fn test_generation_failures_are_returned() {
+        use crate::plugin::{
+            LadFileGenerationError, LadFileSettings, generate_lad_file_from_world, headless_lad_app,
+        };
+
+        let mut app = headless_lad_app();
+        // a file cannot contain other files
+        let settings = LadFileSettings {
+            enabled: true,
+            ladfile_filename: Some(PathBuf::from("test.lad.json")),
+            output_directory: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"),
+            description: "",
+            exclude_types_containing_unregistered: true,
+            pretty: true,
+            processors: vec![],
+        };
+
+        let result = generate_lad_file_from_world(app.world_mut(), &settings);
+        assert!(
+            matches!(result, Err(LadFileGenerationError::Write { .. })),
+            "expected a write error, got: {result:?}"
+        );
+    }
 }
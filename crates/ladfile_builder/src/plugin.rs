@@ -1,10 +1,22 @@
 //! Plugins for bevy which allow generating ladfiles at startup
 
-use std::{ops::Deref, path::PathBuf, sync::Arc};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Deref,
+    path::PathBuf,
+    sync::Arc,
+};
 
 use ::{
-    bevy_app::{App, Plugin, Startup},
-    bevy_ecs::{prelude::Resource, reflect::AppTypeRegistry, system::Res, world::World},
+    bevy_app::{App, Last, Plugin},
+    bevy_ecs::{
+        prelude::Resource,
+        reflect::AppTypeRegistry,
+        schedule::{IntoScheduleConfigs, common_conditions::run_once},
+        system::Res,
+        world::World,
+    },
 };
 use bevy_log::{error, info};
 use bevy_mod_scripting_bindings::{
@@ -13,13 +25,17 @@ use bevy_mod_scripting_bindings::{
     globals::AppScriptGlobalsRegistry,
     into_through_type_info,
 };
+use bevy_mod_scripting_core::{
+    callback_registry::AppScriptCallbackRegistry, register_core_callbacks,
+};
 use ladfile::{LadFieldOrVariableKind, LadFilePlugin, default_importance};
 
 use crate::LadFileBuilder;
 
 /// Plugin which enables the generation of LAD files at runtime for the purposes of creating documentation and other goodies.
 ///
-/// When added, will automatically generate a LAD file on the Startup schedule
+/// When added, will automatically generate a LAD file at the end of the first frame,
+/// by which point every event handler had the chance to document its callback.
 #[derive(Default, Clone)]
 pub struct ScriptingFilesGenerationPlugin(LadFileSettingsArc);
 
@@ -88,6 +104,29 @@ impl ScriptingFilesGenerationPlugin {
         processors.push(Box::new(
             lua_language_server_lad_backend::LuaLanguageServerLadPlugin::default(),
         ) as Box<dyn LadFilePlugin + Send + Sync + 'static>);
+
+        #[cfg(feature = "rhai_definition_files")]
+        processors.push(
+            Box::new(rhai_definitions_lad_backend::RhaiDefinitionsLadPlugin::default())
+                as Box<dyn LadFilePlugin + Send + Sync + 'static>,
+        );
+
+        #[cfg(feature = "luau_definition_files")]
+        processors.push(
+            Box::new(luau_definitions_lad_backend::LuauDefinitionsLadPlugin::default())
+                as Box<dyn LadFilePlugin + Send + Sync + 'static>,
+        );
+
+        #[cfg(feature = "typescript_definition_files")]
+        processors.push(Box::new(
+            typescript_definitions_lad_backend::TypeScriptDefinitionsLadPlugin::default(),
+        ) as Box<dyn LadFilePlugin + Send + Sync + 'static>);
+
+        #[cfg(feature = "json_schema_files")]
+        processors.push(
+            Box::new(json_schema_lad_backend::JsonSchemaLadPlugin::default())
+                as Box<dyn LadFilePlugin + Send + Sync + 'static>,
+        );
         processors
     }
 
@@ -112,18 +151,70 @@ impl ScriptingFilesGenerationPlugin {
     }
 }
 
-/// The function used to generate a ladfile from pre-populated type, function and global registries
+/// An error which prevented a LAD file or the outputs of its processors from being generated
+#[derive(Debug)]
+pub enum LadFileGenerationError {
+    /// A processor failed to generate its outputs
+    Processor {
+        /// The name of the processor
+        processor: &'static str,
+        /// The error the processor failed with
+        error: Box<dyn Error>,
+    },
+    /// The LAD file could not be serialized
+    Serialization(Box<dyn Error>),
+    /// The LAD file could not be written to disk
+    Write {
+        /// The path the LAD file was written to
+        path: PathBuf,
+        /// The underlying IO error
+        error: std::io::Error,
+    },
+}
+
+impl Display for LadFileGenerationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LadFileGenerationError::Processor { processor, error } => {
+                write!(
+                    f,
+                    "Error in running ladfile processor '{processor}': {error}"
+                )
+            }
+            LadFileGenerationError::Serialization(error) => {
+                write!(f, "Error serializing LAD file: {error}")
+            }
+            LadFileGenerationError::Write { path, error } => {
+                write!(f, "Error saving LAD file to {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for LadFileGenerationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LadFileGenerationError::Processor { error, .. }
+            | LadFileGenerationError::Serialization(error) => Some(error.as_ref()),
+            LadFileGenerationError::Write { error, .. } => Some(error),
+        }
+    }
+}
+
+/// The function used to generate a ladfile from pre-populated type, function, global and callback registries
 pub fn generate_lad_file(
     type_registry: &AppTypeRegistry,
     function_registry: &AppScriptFunctionRegistry,
     dummy_function_registry: &DummyScriptFunctionRegistry,
     global_registry: &AppScriptGlobalsRegistry,
+    callback_registry: &AppScriptCallbackRegistry,
     settings: &LadFileSettings,
-) {
+) -> Result<(), LadFileGenerationError> {
     let type_registry = type_registry.read();
     let function_registry = function_registry.read();
     let dummy_function_registry = dummy_function_registry.0.read();
     let global_registry = global_registry.read();
+    let callback_registry = callback_registry.read();
     let mut builder = LadFileBuilder::new(&type_registry);
     builder
         .set_description(settings.description)
@@ -197,37 +288,80 @@ pub fn generate_lad_file(
         builder.add_instance_manually(key.to_string(), false, kind);
     }
 
+    // find callbacks scripts can implement
+    for callback in callback_registry.iter() {
+        builder.add_callback_info(callback);
+    }
+
     let file = builder.build();
     let directory = &settings.output_directory;
 
     for processor in settings.processors.iter() {
-        bevy_log::info!("Running ladfile processor: '{}'", processor.name());
-        if let Err(e) = processor.run(&file, directory) {
-            bevy_log::error!("Error in running ladfile processor: {e:#}")
-        }
+        info!("Running ladfile processor: '{}'", processor.name());
+        processor
+            .run(&file, directory)
+            .map_err(|error| LadFileGenerationError::Processor {
+                processor: processor.name(),
+                error,
+            })?;
     }
 
     if let Some(filename) = &settings.ladfile_filename {
         let path = directory.join(filename);
-        // generate
-        let file = match ladfile::serialize_lad_file(&file, settings.pretty) {
-            Ok(file) => file,
-            Err(e) => {
-                error!("Error serializing LAD file: {}", e);
-                return;
-            }
-        };
-
-        // save
-        match std::fs::write(&path, file) {
-            Ok(_) => {
-                info!("Successfully generated LAD file at {:?}", path);
-            }
-            Err(e) => {
-                error!("Error saving LAD file to {:?}: {}", path, e);
-            }
-        }
+        let file = ladfile::serialize_lad_file(&file, settings.pretty)
+            .map_err(|e| LadFileGenerationError::Serialization(e.into()))?;
+        std::fs::write(&path, file).map_err(|error| LadFileGenerationError::Write {
+            path: path.clone(),
+            error,
+        })?;
+        info!("Successfully generated LAD file at {:?}", path);
     }
+    Ok(())
+}
+
+/// Creates an app containing only the registries a LAD file is generated from.
+///
+/// Nothing else is set up, so registering bindings on this app and passing its world to [`generate_lad_file_from_world`]
+/// generates a LAD file without having to boot the full application.
+pub fn headless_lad_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<AppScriptFunctionRegistry>()
+        .init_resource::<DummyScriptFunctionRegistry>()
+        .init_resource::<AppScriptGlobalsRegistry>()
+        .init_resource::<AppScriptCallbackRegistry>();
+    register_core_callbacks(&mut app);
+    app
+}
+
+/// Generates a LAD file from the registries stored in the given world, initializing any which are missing.
+///
+/// Plugins should be finished beforehand, as some of them only populate the registries then.
+pub fn generate_lad_file_from_world(
+    world: &mut World,
+    settings: &LadFileSettings,
+) -> Result<(), LadFileGenerationError> {
+    let type_registry = world.get_resource_or_init::<AppTypeRegistry>().clone();
+    let function_registry = world
+        .get_resource_or_init::<AppScriptFunctionRegistry>()
+        .clone();
+    let dummy_function_registry = world
+        .get_resource_or_init::<DummyScriptFunctionRegistry>()
+        .clone();
+    let global_registry = world
+        .get_resource_or_init::<AppScriptGlobalsRegistry>()
+        .clone();
+    let callback_registry = world
+        .get_resource_or_init::<AppScriptCallbackRegistry>()
+        .clone();
+
+    generate_lad_file(
+        &type_registry,
+        &function_registry,
+        &dummy_function_registry,
+        &global_registry,
+        &callback_registry,
+        settings,
+    )
 }
 
 fn generate_lad_file_system(
@@ -235,22 +369,26 @@ fn generate_lad_file_system(
     function_registry: Res<AppScriptFunctionRegistry>,
     dummy_function_registry: Res<DummyScriptFunctionRegistry>,
     global_registry: Res<AppScriptGlobalsRegistry>,
+    callback_registry: Res<AppScriptCallbackRegistry>,
     settings: Res<LadFileSettingsArc>,
 ) {
-    generate_lad_file(
+    if let Err(e) = generate_lad_file(
         &type_registry,
         &function_registry,
         &dummy_function_registry,
         &global_registry,
+        &callback_registry,
         &settings.0,
-    );
+    ) {
+        error!("{e:#}");
+    }
 }
 
 impl Plugin for ScriptingFilesGenerationPlugin {
     fn build(&self, app: &mut App) {
         if self.0.0.enabled {
             app.insert_resource(self.0.clone());
-            app.add_systems(Startup, generate_lad_file_system);
+            app.add_systems(Last, generate_lad_file_system.run_if(run_once));
         }
     }
 }
@@ -0,0 +1,68 @@
+//! Rendering of the changes between two LAD files, i.e. for release notes or CI checks.
+
+use std::fmt::Write;
+
+use ladfile::{LadChange, LadDiff};
+
+/// The formats a diff can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum DiffFormat {
+    /// One line per change, prefixed with its severity
+    #[default]
+    Text,
+    /// A markdown section listing breaking and non-breaking changes, suitable for release notes
+    Markdown,
+    /// The diff serialized as json
+    Json,
+}
+
+/// Renders the diff in the given format.
+pub fn render_diff(diff: &LadDiff, format: DiffFormat) -> Result<String, anyhow::Error> {
+    let mut out = String::new();
+    match format {
+        DiffFormat::Text => {
+            for change in diff.breaking_changes() {
+                writeln!(out, "breaking: {change}")?;
+            }
+            for change in diff.non_breaking_changes() {
+                writeln!(out, "non-breaking: {change}")?;
+            }
+        }
+        DiffFormat::Markdown => {
+            writeln!(out, "## Scripting API changes")?;
+            if diff.is_empty() {
+                writeln!(out)?;
+                writeln!(out, "No changes to the scripting API.")?;
+            }
+            write_markdown_section(&mut out, "Breaking changes", diff.breaking_changes())?;
+            write_markdown_section(
+                &mut out,
+                "Non-breaking changes",
+                diff.non_breaking_changes(),
+            )?;
+        }
+        DiffFormat::Json => {
+            out = serde_json::to_string_pretty(diff)?;
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
+
+fn write_markdown_section<'a>(
+    out: &mut String,
+    title: &str,
+    changes: impl Iterator<Item = &'a LadChange>,
+) -> std::fmt::Result {
+    let mut changes = changes.peekable();
+    if changes.peek().is_none() {
+        return Ok(());
+    }
+    writeln!(out)?;
+    writeln!(out, "### {title}")?;
+    writeln!(out)?;
+    for change in changes {
+        writeln!(out, "- {change}")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! Compares two Language Agnostic Declaration (LAD) files and reports the changes to the scripting API of the bevy_mod_scripting crate.
+
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use ladfile_diff::{DiffFormat, render_diff};
+
+#[derive(Debug, clap::Parser)]
+/// Command line arguments for the LAD diffing tool.
+pub struct Args {
+    /// The LAD file describing the previous version of the API
+    #[clap(long, help = "LAD json file of the previous version")]
+    pub old: PathBuf,
+
+    /// The LAD file describing the new version of the API
+    #[clap(long, help = "LAD json file of the new version")]
+    pub new: PathBuf,
+
+    /// The format of the report
+    #[clap(short, long, value_enum, default_value_t = DiffFormat::Text)]
+    pub format: DiffFormat,
+
+    /// The file to write the report to, printed to stdout if not provided
+    #[clap(short, long, help = "Output file for the report")]
+    pub output: Option<PathBuf>,
+
+    /// Exit with a non-zero exit code if any of the changes are breaking
+    #[clap(long, help = "Fail if any breaking changes are found")]
+    pub fail_on_breaking: bool,
+}
+
+fn main() {
+    match try_main() {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(2),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Returns false if the checks requested by the arguments did not pass
+fn try_main() -> Result<bool, anyhow::Error> {
+    let args = Args::parse();
+
+    // Initialize the logger
+    env_logger::init();
+
+    log::trace!("Old LAD file: {:?}", args.old);
+    log::trace!("New LAD file: {:?}", args.new);
+
+    let old = read_ladfile(&args.old)?;
+    let new = read_ladfile(&args.new)?;
+    let diff = ladfile::diff_lad_files(&old, &new);
+    let report = render_diff(&diff, args.format)?;
+
+    match &args.output {
+        Some(path) => std::fs::write(path, report)
+            .map_err(|e| anyhow::anyhow!("Failed to write report to {}: {}", path.display(), e))?,
+        None => print!("{report}"),
+    }
+
+    Ok(!(args.fail_on_breaking && diff.is_breaking()))
+}
+
+fn read_ladfile(path: &Path) -> Result<ladfile::LadFile, anyhow::Error> {
+    let file = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read LAD file {}: {}", path.display(), e))?;
+    Ok(ladfile::parse_lad_file(&file)?)
+}
@@ -0,0 +1,80 @@
+#![allow(missing_docs, clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+use std::path::PathBuf;
+
+use assert_cmd::{Command, cargo_bin};
+
+// use cargo manifest dir
+fn get_test_dir() -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    PathBuf::from(manifest_dir)
+        .join("tests")
+        .join("example_diff")
+}
+
+/// Compares the example LAD file against a modified version of it.
+///
+/// Each test gets its own copy of the example file, since tests run in parallel.
+fn diff_command(test_dir: &std::path::Path, test_name: &str) -> Command {
+    let old_path = test_dir.join(format!("{test_name}.old.lad.json"));
+    std::fs::write(&old_path, ladfile::EXAMPLE_LADFILE).expect("failed to copy LAD file");
+    let mut command = Command::new(cargo_bin!("lad-diff"));
+    command
+        .arg("--old")
+        .arg(old_path)
+        .arg("--new")
+        .arg(test_dir.join("new.lad.json"));
+    command
+}
+
+#[test]
+fn markdown_report_matches_expected() {
+    let test_dir = get_test_dir();
+    let output = diff_command(&test_dir, "markdown")
+        .arg("--format")
+        .arg("markdown")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let generated_str = String::from_utf8(output).expect("report is not utf8");
+
+    let expected_path = test_dir.join("expected.md");
+    if std::env::var("BLESS_MODE").is_ok() {
+        std::fs::write(&expected_path, &generated_str).expect("failed to write expected.md file");
+        panic!("BLESS_MODE is enabled, please disable it to run the tests");
+    }
+    let expected_str =
+        std::fs::read_to_string(&expected_path).expect("failed to read expected.md file");
+    pretty_assertions::assert_eq!(
+        expected_str,
+        generated_str,
+        "Generated report does not match expected output"
+    );
+}
+
+#[test]
+fn json_report_is_machine_readable() {
+    let test_dir = get_test_dir();
+    let output = diff_command(&test_dir, "json")
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let diff: ladfile::LadDiff = serde_json::from_slice(&output).expect("invalid json report");
+    assert_eq!(diff.breaking_changes().count(), 3);
+    assert_eq!(diff.non_breaking_changes().count(), 2);
+}
+
+#[test]
+fn fails_on_breaking_changes_when_requested() {
+    let test_dir = get_test_dir();
+    diff_command(&test_dir, "fail_on_breaking")
+        .arg("--fail-on-breaking")
+        .assert()
+        .code(2);
+}
@@ -0,0 +1,46 @@
+//! Generates Language Agnostic Declaration (LAD) files and the outputs of all enabled processors, without running the bevy app they describe.
+//!
+//! Only the registries the LAD file is generated from are set up, which makes generation fast enough to run in CI or from editor integrations.
+
+use bevy_app::App;
+use bevy_mod_scripting_bindings::CoreScriptGlobalsPlugin;
+use bevy_mod_scripting_functions::{bevy_bindings, register_core_functions};
+use ladfile_builder::plugin::{LadFileSettings, generate_lad_file_from_world};
+
+/// The names of the bindings which can be selected, i.e. the ones enabled via feature flags
+pub fn available_bindings() -> Vec<&'static str> {
+    bevy_bindings().into_iter().map(|(name, _)| name).collect()
+}
+
+/// Registers the core functions, core globals and the selected bindings in the app.
+///
+/// If no bindings are selected, all available bindings are registered.
+pub fn register_bindings(app: &mut App, selected: &[String]) -> Result<(), anyhow::Error> {
+    let available = bevy_bindings();
+    if let Some(unknown) = selected
+        .iter()
+        .find(|name| !available.iter().any(|(available, _)| available == name))
+    {
+        return Err(anyhow::anyhow!(
+            "Unknown bindings '{unknown}', available bindings are: {}",
+            available_bindings().join(", ")
+        ));
+    }
+
+    register_core_functions(app);
+    for (name, register) in available {
+        if selected.is_empty() || selected.iter().any(|s| s == name) {
+            register(app);
+        }
+    }
+    app.add_plugins(CoreScriptGlobalsPlugin::default());
+    Ok(())
+}
+
+/// Finishes the app and generates the LAD file and the outputs of all processors in the settings from its registries.
+pub fn generate(app: &mut App, settings: &LadFileSettings) -> Result<(), anyhow::Error> {
+    // some plugins only populate the registries when finishing
+    app.finish();
+    app.cleanup();
+    generate_lad_file_from_world(app.world_mut(), settings).map_err(|e| anyhow::anyhow!("{e}"))
+}
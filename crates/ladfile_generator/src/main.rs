@@ -0,0 +1,79 @@
+//! Generates a Language Agnostic Declaration (LAD) file and the outputs of all enabled processors for the bindings of the bevy_mod_scripting crate.
+
+use std::path::PathBuf;
+
+use bevy_log::LogPlugin;
+use clap::Parser;
+use ladfile_builder::plugin::{LadFileSettings, ScriptingFilesGenerationPlugin, headless_lad_app};
+
+#[derive(Debug, clap::Parser)]
+/// Command line arguments for the LAD generator.
+pub struct Args {
+    /// The directory to write the LAD file and processor outputs to
+    #[clap(short, long, default_value = ".", help = "Output directory")]
+    pub output: PathBuf,
+
+    /// The file name of the LAD file within the output directory
+    #[clap(long, default_value = "bindings.lad.json", help = "LAD file name")]
+    pub ladfile_name: PathBuf,
+
+    /// The bindings to include, by name of the feature enabling them, i.e. `bevy_math`.
+    ///
+    /// All available bindings are included if not provided.
+    #[clap(short, long, value_delimiter = ',', help = "Bindings to include")]
+    pub bindings: Vec<String>,
+
+    /// Print the names of the available bindings and exit
+    #[clap(long, help = "List the available bindings")]
+    pub list_bindings: bool,
+
+    /// The description of the LAD file
+    #[clap(long, default_value = "", help = "LAD file description")]
+    pub description: String,
+
+    /// Whether to write the LAD file without whitespace
+    #[clap(long, help = "Don't pretty print the LAD file")]
+    pub compact: bool,
+}
+
+fn main() {
+    if let Err(e) = try_main() {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+fn try_main() -> Result<(), anyhow::Error> {
+    let args = Args::parse();
+
+    if args.list_bindings {
+        for name in ladfile_generator::available_bindings() {
+            println!("{name}");
+        }
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&args.output).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to create output directory {}: {}",
+            args.output.display(),
+            e
+        )
+    })?;
+
+    let settings = LadFileSettings {
+        enabled: true,
+        ladfile_filename: Some(args.ladfile_name),
+        output_directory: args.output,
+        description: args.description.leak(),
+        exclude_types_containing_unregistered: true,
+        pretty: !args.compact,
+        processors: ScriptingFilesGenerationPlugin::default_processors(),
+    };
+
+    let mut app = headless_lad_app();
+    // the generation pipeline reports progress and processor errors through bevy's logging
+    app.add_plugins(LogPlugin::default());
+    ladfile_generator::register_bindings(&mut app, &args.bindings)?;
+    ladfile_generator::generate(&mut app, &settings)
+}
@@ -0,0 +1,107 @@
+#![allow(missing_docs, clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+use std::path::PathBuf;
+
+use assert_cmd::{Command, cargo_bin};
+
+/// Each test gets its own output directory, since tests run in parallel.
+fn get_output_dir(test_name: &str) -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let output_dir = PathBuf::from(manifest_dir)
+        .join("tests")
+        .join(format!("generated_{test_name}"));
+    if output_dir.exists() {
+        std::fs::remove_dir_all(&output_dir).expect("failed to clear output directory");
+    }
+    output_dir
+}
+
+#[test]
+fn generates_ladfile_for_selected_bindings() {
+    let output_dir = get_output_dir("selected");
+    Command::new(cargo_bin!("lad-generate"))
+        .arg("--output")
+        .arg(&output_dir)
+        .arg("--bindings")
+        .arg("bevy_math")
+        .assert()
+        .success();
+
+    let ladfile = std::fs::read_to_string(output_dir.join("bindings.lad.json"))
+        .expect("failed to read generated LAD file");
+    let ladfile = ladfile::parse_lad_file(&ladfile).expect("failed to parse generated LAD file");
+
+    let identifiers = ladfile
+        .types
+        .values()
+        .map(|t| t.identifier.as_str())
+        .collect::<Vec<_>>();
+    assert!(identifiers.contains(&"Vec3"), "missing bevy_math types");
+    assert!(
+        !identifiers.contains(&"Transform"),
+        "bindings which were not selected were generated"
+    );
+    // core callbacks are documented without the scripting infrastructure
+    assert!(ladfile.callbacks.contains_key("on_script_loaded"));
+}
+
+#[test]
+fn runs_processors() {
+    let output_dir = get_output_dir("processors");
+    Command::new(cargo_bin!("lad-generate"))
+        .arg("--output")
+        .arg(&output_dir)
+        .arg("--bindings")
+        .arg("bevy_math")
+        .assert()
+        .success();
+
+    assert!(output_dir.join("bindings.d.lua").exists());
+    assert!(output_dir.join("bindings.d.ts").exists());
+}
+
+#[test]
+fn unknown_bindings_are_rejected() {
+    let output_dir = get_output_dir("unknown");
+    Command::new(cargo_bin!("lad-generate"))
+        .arg("--output")
+        .arg(&output_dir)
+        .arg("--bindings")
+        .arg("bevy_math,not_a_crate")
+        .assert()
+        .failure();
+
+    assert!(!output_dir.join("bindings.lad.json").exists());
+}
+
+#[test]
+fn unwritable_output_is_reported() {
+    let output_dir = get_output_dir("unwritable");
+    std::fs::create_dir_all(&output_dir).expect("failed to create output directory");
+    // the parent directory of the LAD file is never created
+    Command::new(cargo_bin!("lad-generate"))
+        .arg("--output")
+        .arg(&output_dir)
+        .arg("--ladfile-name")
+        .arg("missing/bindings.lad.json")
+        .arg("--bindings")
+        .arg("bevy_math")
+        .assert()
+        .failure();
+
+    assert!(!output_dir.join("missing").exists());
+}
+
+#[test]
+fn lists_available_bindings() {
+    let output = Command::new(cargo_bin!("lad-generate"))
+        .arg("--list-bindings")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let output = String::from_utf8(output).expect("output is not utf8");
+
+    assert!(output.lines().any(|l| l == "bevy_math"));
+}
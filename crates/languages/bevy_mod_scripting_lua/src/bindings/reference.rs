@@ -6,7 +6,7 @@ use bevy_mod_scripting_bindings::{
 use bevy_mod_scripting_bindings_domain::ScriptOperatorNames;
 use bevy_mod_scripting_display::OrFakeId;
 use bevy_mod_scripting_world::ThreadWorldContainer;
-use mlua::{ExternalError, MetaMethod, UserData, UserDataMethods};
+use mlua::{ExternalError, MetaMethod, UserData, UserDataMethods, Variadic};
 
 use crate::IntoMluaError;
 
@@ -340,6 +340,290 @@ impl UserData for LuaReflectReference {
             },
         );
 
+        m.add_meta_function(
+            MetaMethod::Le,
+            |_, (self_, other): (LuaReflectReference, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::Le");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let other: ScriptValue = other.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_), other];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::LessThanOrEqualComparison.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        #[cfg(any(feature = "lua54", feature = "lua53", feature = "luau"))]
+        m.add_meta_function(
+            MetaMethod::IDiv,
+            |_, (self_, other): (LuaReflectReference, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::IDiv");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let other: ScriptValue = other.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_), other];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::FloorDivision.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        #[cfg(any(feature = "lua54", feature = "lua53"))]
+        m.add_meta_function(
+            MetaMethod::BAnd,
+            |_, (self_, other): (LuaReflectReference, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::BAnd");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let other: ScriptValue = other.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_), other];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::BitwiseAnd.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        #[cfg(any(feature = "lua54", feature = "lua53"))]
+        m.add_meta_function(
+            MetaMethod::BOr,
+            |_, (self_, other): (LuaReflectReference, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::BOr");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let other: ScriptValue = other.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_), other];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::BitwiseOr.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        #[cfg(any(feature = "lua54", feature = "lua53"))]
+        m.add_meta_function(
+            MetaMethod::BXor,
+            |_, (self_, other): (LuaReflectReference, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::BXor");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let other: ScriptValue = other.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_), other];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::BitwiseXor.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        #[cfg(any(feature = "lua54", feature = "lua53"))]
+        m.add_meta_function(
+            MetaMethod::Shl,
+            |_, (self_, other): (LuaReflectReference, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::Shl");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let other: ScriptValue = other.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_), other];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::ShiftLeft.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        #[cfg(any(feature = "lua54", feature = "lua53"))]
+        m.add_meta_function(
+            MetaMethod::Shr,
+            |_, (self_, other): (LuaReflectReference, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::Shr");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let other: ScriptValue = other.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_), other];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::ShiftRight.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        #[cfg(any(feature = "lua54", feature = "lua53"))]
+        m.add_meta_function(MetaMethod::BNot, |_, self_: LuaReflectReference| {
+            profiling::function_scope!("MetaMethod::BNot");
+            let world = ThreadWorldContainer
+                .try_get_context()
+                .map_err(IntoMluaError::to_lua_error)?
+                .world;
+            let self_: ReflectReference = self_.into();
+            let target_type_id = self_
+                .tail_type_id(world.clone())
+                .map_err(IntoMluaError::to_lua_error)?
+                .or_fake_id();
+            let args = vec![ScriptValue::Reference(self_)];
+            let out = world
+                .try_call_overloads(
+                    target_type_id,
+                    ScriptOperatorNames::BitwiseNot.script_function_name(),
+                    args,
+                    LUA_CALLER_CONTEXT,
+                )
+                .map_err(IntoMluaError::to_lua_error)?;
+            Ok(LuaScriptValue(out))
+        });
+
+        m.add_meta_function(
+            MetaMethod::Concat,
+            |_, (left, right): (LuaScriptValue, LuaScriptValue)| {
+                profiling::function_scope!("MetaMethod::Concat");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let left: ScriptValue = left.into();
+                let right: ScriptValue = right.into();
+                // either side of the concatenation can be the reference, i.e. `"a" .. ref`
+                let self_ = match (&left, &right) {
+                    (ScriptValue::Reference(r), _) | (_, ScriptValue::Reference(r)) => r.clone(),
+                    _ => {
+                        return Err(InteropError::invariant(
+                            "concatenation metamethod called without a reference",
+                        )
+                        .to_lua_error());
+                    }
+                };
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = vec![left, right];
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::Concatenation.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
+        m.add_meta_function(
+            MetaMethod::Call,
+            |_, (self_, args): (LuaReflectReference, Variadic<LuaScriptValue>)| {
+                profiling::function_scope!("MetaMethod::Call");
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .world;
+                let self_: ReflectReference = self_.into();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoMluaError::to_lua_error)?
+                    .or_fake_id();
+                let args = std::iter::once(ScriptValue::Reference(self_))
+                    .chain(args.into_iter().map(Into::into))
+                    .collect();
+                let out = world
+                    .try_call_overloads(
+                        target_type_id,
+                        ScriptOperatorNames::Call.script_function_name(),
+                        args,
+                        LUA_CALLER_CONTEXT,
+                    )
+                    .map_err(IntoMluaError::to_lua_error)?;
+                Ok(LuaScriptValue(out))
+            },
+        );
+
         m.add_meta_function(MetaMethod::Len, |_lua, self_: LuaScriptValue| {
             profiling::function_scope!("MetaMethod::Len");
             let world = ThreadWorldContainer
@@ -444,7 +728,7 @@ impl UserData for LuaStaticReflectReference {
                 let key: ScriptValue = key.into();
 
                 let key = match key.as_string() {
-                    Ok(name) => match world.lookup_function([type_id], name) {
+                    Ok(name) => match world.lookup_static_function(type_id, name) {
                         Ok(func) => return Ok(LuaScriptValue(ScriptValue::Function(func))),
                         Err(key) => ScriptValue::String(key),
                     },
@@ -23,8 +23,8 @@ impl MultiLuaScriptValue {
     pub fn into_script_value(mut self) -> ScriptValue {
         if self.0.is_empty() {
             ScriptValue::Unit
-        } else if let Some(first) = self.0.pop_front()
-            && self.0.len() == 1
+        } else if self.0.len() == 1
+            && let Some(first) = self.0.pop_front()
         {
             first
         } else {
@@ -113,16 +113,31 @@ impl FromLua for LuaScriptValue {
             // Value::LightUserData(light_user_data) => todo!(),
             Value::Integer(i) => ScriptValue::Integer(i),
             Value::Number(n) => ScriptValue::Float(n),
-            Value::String(s) => ScriptValue::String(s.to_str()?.to_owned().into()),
-            Value::Function(f) => ScriptValue::Function(
-                (move |_context: FunctionCallContext, args: VecDeque<ScriptValue>| {
-                    match f.call::<MultiLuaScriptValue>(MultiLuaScriptValue(args)) {
-                        Ok(v) => v.into_script_value(),
-                        Err(e) => ScriptValue::Error(InteropError::external(Box::new(e))),
-                    }
-                })
-                .into(),
-            ),
+            // strings which are not valid utf-8 are treated as byte buffers
+            Value::String(s) => match s.to_str() {
+                Ok(str_) => ScriptValue::String(str_.to_owned().into()),
+                Err(_) => ScriptValue::Bytes(s.as_bytes().to_vec()),
+            },
+            #[cfg(feature = "luau")]
+            Value::Buffer(buffer) => ScriptValue::Bytes(buffer.to_vec()),
+            Value::Function(f) => {
+                // the function does not keep the lua state alive, which might be unloaded by the time the function is called
+                let owner = lua.weak();
+                ScriptValue::Function(
+                    (move |_context: FunctionCallContext, args: VecDeque<ScriptValue>| {
+                        let Some(_owner) = owner.try_upgrade() else {
+                            return ScriptValue::Error(InteropError::script_context_unloaded(
+                                Language::Lua,
+                            ));
+                        };
+                        match f.call::<MultiLuaScriptValue>(MultiLuaScriptValue(args)) {
+                            Ok(v) => v.into_script_value(),
+                            Err(e) => ScriptValue::Error(InteropError::external(Box::new(e))),
+                        }
+                    })
+                    .into(),
+                )
+            }
             Value::Table(table) => {
                 // check the key types, if strings then it's a map
                 let mut iter = table.pairs::<Value, LuaScriptValue>();
@@ -187,6 +202,10 @@ impl IntoLua for LuaScriptValue {
             ScriptValue::Integer(i) => Value::Integer(i),
             ScriptValue::Float(f) => Value::Number(f),
             ScriptValue::String(s) => Value::String(lua.create_string(s.as_ref())?),
+            #[cfg(not(feature = "luau"))]
+            ScriptValue::Bytes(bytes) => Value::String(lua.create_string(bytes)?),
+            #[cfg(feature = "luau")]
+            ScriptValue::Bytes(bytes) => Value::Buffer(lua.create_buffer(bytes)?),
             ScriptValue::Reference(r) => LuaReflectReference::from(r).into_lua(lua)?,
             ScriptValue::Error(script_error) => return Err(mlua::Error::external(script_error)),
             ScriptValue::Function(function) => lua
@@ -202,7 +221,7 @@ impl IntoLua for LuaScriptValue {
                         })
                     };
                     let out = function
-                        .call(
+                        .call_from_script(
                             args.0,
                             FunctionCallContext::new_with_location(Language::Lua, loc),
                         )
@@ -224,7 +243,7 @@ impl IntoLua for LuaScriptValue {
                         })
                     };
                     let out = function
-                        .call(
+                        .call_from_script(
                             args.0,
                             FunctionCallContext::new_with_location(Language::Lua, loc),
                         )
@@ -0,0 +1,303 @@
+//! Lua integration for the [`ScriptDebugger`](bevy_mod_scripting_core::debugger::ScriptDebugger), driven by a line hook installed into each context, see [`crate::hooks`].
+//!
+//! Breakpoints are matched against the asset path of the script being called.
+//! Luau does not support debug hooks, so scripts cannot be debugged with the `luau` feature enabled.
+use bevy_mod_scripting_bindings::ScriptValue;
+use bevy_mod_scripting_core::debugger::{
+    DebugScope, DebugStackFrame, ScriptDebugger, ScriptInspector,
+};
+use std::ffi::{CStr, c_char, c_int};
+
+use mlua::{Debug, Lua, Table, Value, ffi};
+
+use crate::bindings::reference::LuaReflectReference;
+
+/// Standard library globals which are hidden from the variable view
+const HIDDEN_GLOBALS: &[&str] = &[
+    "_G",
+    "package",
+    "string",
+    "table",
+    "math",
+    "io",
+    "os",
+    "coroutine",
+    "utf8",
+    "debug",
+    "bit32",
+    "jit",
+];
+
+/// How many levels of nested tables are converted when inspecting variables
+const MAX_TABLE_DEPTH: usize = 3;
+
+/// Reports the line about to be executed to the debugger, if it is tracking the current call.
+pub(crate) fn on_line_hook(lua: &Lua, debug: &Debug) {
+    if ScriptDebugger::is_debugging_current_call() {
+        let line = debug.current_line().unwrap_or_default() as u32;
+        ScriptDebugger::on_line(None, line, stack_depth(lua), &LuaInspector(lua));
+    }
+}
+
+/// The number of lua functions on the call stack
+fn stack_depth(lua: &Lua) -> usize {
+    (0..)
+        .map_while(|level| lua.inspect_stack(level, |debug| debug.source().what != "C"))
+        .filter(|is_lua| *is_lua)
+        .count()
+}
+
+/// Exposes the state of a paused lua context to the debugger
+struct LuaInspector<'a>(&'a Lua);
+
+impl ScriptInspector for LuaInspector<'_> {
+    fn stack_frames(&self) -> Vec<DebugStackFrame> {
+        (0..)
+            .map_while(|level| {
+                self.0.inspect_stack(level, |debug| {
+                    (debug.source().what != "C").then(|| DebugStackFrame {
+                        name: debug
+                            .names()
+                            .name
+                            .map(|name| name.to_string())
+                            .unwrap_or_else(|| "main chunk".to_owned()),
+                        source: None,
+                        line: debug.current_line().unwrap_or_default() as u32,
+                    })
+                })
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn scopes(&self, frame: usize) -> Vec<DebugScope> {
+        let mut scopes = Vec::default();
+        // Safety: the closure only inspects the stack of the paused call and keeps the lua stack balanced
+        let frame_variables = unsafe {
+            self.0
+                .exec_raw::<(Table, Table)>((), |state| push_frame_variables(state, frame))
+        };
+        if let Ok((locals, upvalues)) = frame_variables {
+            scopes.push(DebugScope {
+                name: "Locals".to_owned(),
+                variables: inspect_variables(locals),
+            });
+            scopes.push(DebugScope {
+                name: "Upvalues".to_owned(),
+                variables: inspect_variables(upvalues),
+            });
+        }
+
+        let mut variables = self
+            .0
+            .globals()
+            .pairs::<String, Value>()
+            .filter_map(Result::ok)
+            .filter(|(name, _)| !HIDDEN_GLOBALS.contains(&name.as_str()))
+            .filter_map(|(name, value)| Some((name, inspect_value(value, MAX_TABLE_DEPTH)?)))
+            .collect::<Vec<_>>();
+        variables.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        scopes.push(DebugScope {
+            name: "Globals".to_owned(),
+            variables,
+        });
+        scopes
+    }
+}
+
+/// Pushes two tables onto the stack, containing the `{name, value}` pairs of the locals and upvalues of the given frame.
+///
+/// Frames are counted the same way as in [`LuaInspector::stack_frames`], i.e. skipping C functions.
+/// Both tables are empty if the frame does not exist.
+unsafe fn push_frame_variables(state: *mut ffi::lua_State, frame: usize) {
+    unsafe {
+        ffi::lua_createtable(state, 0, 0);
+        let locals = ffi::lua_gettop(state);
+        ffi::lua_createtable(state, 0, 0);
+        let upvalues = ffi::lua_gettop(state);
+
+        let mut debug = std::mem::MaybeUninit::<ffi::lua_Debug>::zeroed();
+        let debug = debug.as_mut_ptr();
+        let mut lua_frames = 0;
+        let mut level = 0;
+        loop {
+            if ffi::lua_getstack(state, level, debug) == 0 {
+                return;
+            }
+            ffi::lua_getinfo(state, c"S".as_ptr(), debug);
+            if CStr::from_ptr((*debug).what) != c"C" {
+                if lua_frames == frame {
+                    break;
+                }
+                lua_frames += 1;
+            }
+            level += 1;
+        }
+
+        let mut n = 1;
+        loop {
+            let name = ffi::lua_getlocal(state, debug, n);
+            if name.is_null() {
+                break;
+            }
+            store_variable(state, locals, name);
+            n += 1;
+        }
+
+        ffi::lua_getinfo(state, c"f".as_ptr(), debug);
+        let function = ffi::lua_gettop(state);
+        let mut n = 1;
+        loop {
+            let name = ffi::lua_getupvalue(state, function, n);
+            if name.is_null() {
+                break;
+            }
+            store_variable(state, upvalues, name);
+            n += 1;
+        }
+        ffi::lua_pop(state, 1);
+    }
+}
+
+/// Pops the value on top of the stack and appends it together with its name to the table at the given index.
+///
+/// Internal variables such as loop state and the `_ENV` upvalue are skipped.
+unsafe fn store_variable(state: *mut ffi::lua_State, table: c_int, name: *const c_char) {
+    unsafe {
+        let name = CStr::from_ptr(name);
+        if name.to_bytes().starts_with(b"(") || name == c"_ENV" {
+            ffi::lua_pop(state, 1);
+            return;
+        }
+        ffi::lua_createtable(state, 2, 0);
+        ffi::lua_pushstring(state, name.as_ptr());
+        ffi::lua_rawseti(state, -2, 1);
+        ffi::lua_insert(state, -2);
+        ffi::lua_rawseti(state, -2, 2);
+        let len = ffi::lua_rawlen(state, table);
+        ffi::lua_rawseti(state, table, (len + 1) as _);
+    }
+}
+
+/// Converts the `{name, value}` pairs collected by [`push_frame_variables`] for display in the debugger.
+///
+/// Later variables shadow earlier ones with the same name.
+fn inspect_variables(pairs: Table) -> Vec<(String, ScriptValue)> {
+    let mut variables: Vec<(String, ScriptValue)> = Vec::default();
+    for pair in pairs.sequence_values::<Table>().filter_map(Result::ok) {
+        let (Ok(name), Ok(value)) = (pair.raw_get::<String>(1), pair.raw_get::<Value>(2)) else {
+            continue;
+        };
+        let Some(value) = inspect_value(value, MAX_TABLE_DEPTH) else {
+            continue;
+        };
+        variables.retain(|(existing, _)| *existing != name);
+        variables.push((name, value));
+    }
+    variables
+}
+
+/// Converts a lua value for display in the debugger, functions and other opaque values are skipped.
+fn inspect_value(value: Value, depth: usize) -> Option<ScriptValue> {
+    Some(match value {
+        Value::Nil => ScriptValue::Unit,
+        Value::Boolean(b) => ScriptValue::Bool(b),
+        Value::Integer(i) => ScriptValue::Integer(i),
+        Value::Number(n) => ScriptValue::Float(n),
+        Value::String(s) => ScriptValue::String(s.to_string_lossy().into()),
+        Value::Table(_) if depth == 0 => ScriptValue::String("{...}".into()),
+        Value::Table(table) if table.raw_len() > 0 => ScriptValue::List(
+            table
+                .sequence_values::<Value>()
+                .filter_map(Result::ok)
+                .filter_map(|v| inspect_value(v, depth - 1))
+                .collect(),
+        ),
+        Value::Table(table) => ScriptValue::Map(
+            table
+                .pairs::<Value, Value>()
+                .filter_map(Result::ok)
+                .filter_map(|(key, value)| {
+                    let key = match key {
+                        Value::String(s) => s.to_string_lossy().to_string(),
+                        Value::Integer(i) => i.to_string(),
+                        _ => return None,
+                    };
+                    Some((key, inspect_value(value, depth - 1)?))
+                })
+                .collect(),
+        ),
+        Value::UserData(userdata) => match userdata.borrow::<LuaReflectReference>() {
+            Ok(reference) => ScriptValue::Reference(reference.0.clone()),
+            Err(_) => ScriptValue::String("userdata".into()),
+        },
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use mlua::{HookTriggers, VmState};
+
+    use super::*;
+
+    #[test]
+    fn scopes_contain_locals_and_upvalues_of_the_requested_frame() {
+        let lua = Lua::new();
+        let captured = Arc::new(Mutex::new(Vec::default()));
+        let hook_captured = captured.clone();
+        lua.set_hook(HookTriggers::new().every_line(), move |lua, debug| {
+            if debug.current_line() == Some(5) {
+                let inspector = LuaInspector(lua);
+                let scopes = (0..2).map(|frame| inspector.scopes(frame)).collect();
+                *hook_captured.lock().unwrap() = scopes;
+            }
+            Ok(VmState::Continue)
+        })
+        .unwrap();
+
+        lua.load(
+            "local outer = 1
+            global_value = true
+            local function f(a)
+                local b = a + outer
+                return b
+            end
+            f(41)",
+        )
+        .exec()
+        .unwrap();
+
+        let captured = captured.lock().unwrap();
+        let scope = |frame: usize, name: &str| -> Vec<(String, ScriptValue)> {
+            captured[frame]
+                .iter()
+                .find(|scope| scope.name == name)
+                .unwrap()
+                .variables
+                .clone()
+        };
+
+        assert_eq!(
+            scope(0, "Locals"),
+            vec![
+                ("a".to_owned(), ScriptValue::Integer(41)),
+                ("b".to_owned(), ScriptValue::Integer(42))
+            ]
+        );
+        assert_eq!(
+            scope(0, "Upvalues"),
+            vec![("outer".to_owned(), ScriptValue::Integer(1))]
+        );
+        assert_eq!(
+            scope(1, "Locals"),
+            vec![("outer".to_owned(), ScriptValue::Integer(1))]
+        );
+        assert!(
+            scope(1, "Globals").contains(&("global_value".to_owned(), ScriptValue::Bool(true)))
+        );
+    }
+}
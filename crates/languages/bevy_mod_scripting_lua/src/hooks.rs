@@ -0,0 +1,84 @@
+//! The debug hook installed into lua contexts, driving the script debugger and the sampling profiler.
+//!
+//! Lua only supports a single hook per state, so both are dispatched from the same hook.
+//! Luau does not support debug hooks, so neither is available with the `luau` feature enabled.
+use bevy_mod_scripting_bindings::InteropError;
+#[cfg(not(feature = "luau"))]
+use bevy_mod_scripting_bindings::WorldExtensions;
+#[cfg(not(feature = "luau"))]
+use bevy_mod_scripting_core::{debugger::ScriptDebugger, profiler::ScriptProfiler};
+use bevy_mod_scripting_script::ScriptAttachment;
+#[cfg(not(feature = "luau"))]
+use bevy_mod_scripting_world::ThreadWorldContainer;
+#[cfg(not(feature = "luau"))]
+use mlua::{Debug, DebugEvent, HookTriggers, VmState};
+
+use crate::LuaContext;
+#[cfg(not(feature = "luau"))]
+use crate::{IntoInteropError, debugger};
+
+/// A context initializer installing the debug hook into the context.
+///
+/// The hook steps through lines if the `ScriptDebugger` resource is present, and samples the executing function
+/// if the `ScriptProfiler` resource is present with sampling enabled. If neither is, no hook is installed.
+pub fn lua_hooks_initializer(
+    _attachment: &ScriptAttachment,
+    context: &mut LuaContext,
+) -> Result<(), InteropError> {
+    #[cfg(not(feature = "luau"))]
+    {
+        let world = ThreadWorldContainer.try_get_context()?.world;
+        let debugging = world.with_resource(|_: &ScriptDebugger| ()).is_ok();
+        let sampler = world
+            .with_resource(|profiler: &ScriptProfiler| profiler.clone())
+            .ok()
+            .and_then(|profiler| Some((profiler.sampling_interval()?, profiler)));
+
+        let mut triggers = HookTriggers::new();
+        if debugging {
+            triggers = triggers.every_line();
+        }
+        if let Some((interval, _)) = &sampler {
+            triggers = triggers.every_nth_instruction(*interval);
+        }
+        if !debugging && sampler.is_none() {
+            return Ok(());
+        }
+
+        let profiler = sampler.map(|(_, profiler)| profiler);
+        context
+            .set_hook(triggers, move |lua, debug| {
+                match debug.event() {
+                    DebugEvent::Line => debugger::on_line_hook(lua, debug),
+                    DebugEvent::Count => {
+                        if let Some(profiler) = &profiler {
+                            profiler.record_sample(&function_name(debug));
+                        }
+                    }
+                    _ => {}
+                }
+                Ok(VmState::Continue)
+            })
+            .map_err(IntoInteropError::to_bms_error)?;
+    }
+    #[cfg(feature = "luau")]
+    let _ = context;
+
+    Ok(())
+}
+
+/// A name identifying the function being executed, including where it was defined
+#[cfg(not(feature = "luau"))]
+fn function_name(debug: &Debug) -> String {
+    let name = debug.names().name;
+    let source = debug.source();
+    let name = name.as_deref().unwrap_or(match source.what {
+        "main" => "main chunk",
+        _ => "anonymous",
+    });
+    match (source.short_src, source.line_defined) {
+        (Some(src), Some(line)) if line > 0 => format!("{name} ({src}:{line})"),
+        (Some(src), _) => format!("{name} ({src})"),
+        _ => name.to_owned(),
+    }
+}
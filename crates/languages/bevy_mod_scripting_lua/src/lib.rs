@@ -1,5 +1,6 @@
 //! Lua integration for the bevy_mod_scripting system.
 use std::{
+    collections::VecDeque,
     ops::{Deref, DerefMut},
     sync::Arc,
 };
@@ -37,6 +38,9 @@ use mlua::{Function, IntoLua, Lua, MultiValue, Variadic};
 
 /// Bindings for lua.
 pub mod bindings;
+#[cfg(not(feature = "luau"))]
+pub mod debugger;
+pub mod hooks;
 
 make_plugin_config_static!(LuaScriptingPlugin);
 
@@ -76,6 +80,10 @@ impl IntoScriptPluginParams for LuaScriptingPlugin {
     fn context_reloader() -> bevy_mod_scripting_core::context::ContextReloadFn<Self> {
         lua_context_reload
     }
+
+    fn evaluator() -> bevy_mod_scripting_core::console::EvalFn<Self> {
+        lua_eval
+    }
 }
 
 // necessary for automatic config goodies
@@ -209,6 +217,7 @@ impl Default for LuaScriptingPlugin {
 
                         Ok(())
                     },
+                    hooks::lua_hooks_initializer,
                 ],
                 context_pre_handling_initializers: vec![|context_key, context| {
                     // TODO: convert these to functions
@@ -242,6 +251,7 @@ impl Default for LuaScriptingPlugin {
                 context_policy: ContextPolicy::default(),
                 emit_responses: false,
                 processing_pipeline_plugin: Default::default(),
+                error_return_mode: None,
             },
         }
     }
@@ -365,6 +375,47 @@ pub fn lua_handler(
     Ok(out.into())
 }
 
+#[profiling::function]
+/// Evaluates a snippet of lua code against an existing context.
+///
+/// The code is first evaluated as an expression, falling back to a statement block if it does not parse as one.
+pub fn lua_eval(
+    code: &str,
+    context_key: &ScriptAttachment,
+    context: &mut LuaContext,
+    world_id: WorldId,
+) -> Result<ScriptValue, InteropError> {
+    let config = LuaScriptingPlugin::readonly_configuration(world_id);
+
+    config
+        .pre_handling_callbacks
+        .iter()
+        .try_for_each(|init| init(context_key, context))?;
+
+    let out = match context
+        .load(format!("return {code}"))
+        .set_name("=console")
+        .eval::<Variadic<LuaScriptValue>>()
+    {
+        Err(mlua::Error::SyntaxError { .. }) => context
+            .load(code)
+            .set_name("=console")
+            .eval::<Variadic<LuaScriptValue>>(),
+        out => out,
+    }
+    .map_err(IntoInteropError::to_bms_error)?;
+
+    let mut values = out
+        .into_iter()
+        .map(ScriptValue::from)
+        .collect::<VecDeque<_>>();
+    Ok(match values.len() {
+        0 => ScriptValue::Unit,
+        1 => values.pop_front().unwrap_or_default(),
+        _ => ScriptValue::List(values),
+    })
+}
+
 /// A trait to convert between mlua::Error and InteropError
 pub trait IntoInteropError {
     /// Convert into InteropError
@@ -408,8 +459,11 @@ impl<T: Into<InteropError>> IntoMluaError for T {
 mod test {
     use ::bevy_asset::Handle;
     use bevy_mod_scripting_asset::LanguageExtensions;
+    use bevy_mod_scripting_bindings::ScriptCallable;
     use mlua::Value;
 
+    use crate::bindings::script_value::LUA_CALLER_CONTEXT;
+
     use super::*;
 
     #[test]
@@ -455,4 +509,31 @@ mod test {
         assert!(globals.get::<Value>("hello_world_from_first_load").is_ok());
         assert!(globals.get::<Value>("hello_world_from_second_load").is_ok());
     }
+
+    #[test]
+    fn test_callable_fails_after_context_is_dropped() {
+        let lua = Lua::new();
+        let value = lua
+            .load("return function(a) return a + 1 end")
+            .eval::<LuaScriptValue>()
+            .unwrap();
+        let ScriptValue::Function(function) = value.0 else {
+            panic!("Expected a function, got: {value:?}");
+        };
+        let callable = ScriptCallable::from(function);
+
+        let out = callable
+            .call([ScriptValue::Integer(1)], LUA_CALLER_CONTEXT)
+            .unwrap();
+        assert!(matches!(out, ScriptValue::Integer(2)));
+
+        drop(lua);
+        let err = callable
+            .call([ScriptValue::Integer(1)], LUA_CALLER_CONTEXT)
+            .unwrap_err();
+        let InteropError::FunctionInteropError { error, .. } = err else {
+            panic!("Expected a function interop error, got: {err}");
+        };
+        assert!(matches!(*error, InteropError::ScriptContextUnloaded { .. }));
+    }
 }
@@ -9,7 +9,7 @@ use bevy_mod_scripting_bindings::{
     function::script_function::DynamicScriptFunctionMut,
 };
 use bevy_mod_scripting_display::OrFakeId;
-use bevy_mod_scripting_world::ThreadWorldContainer;
+use bevy_mod_scripting_world::{ThreadWorldContainer, WorldGuard};
 use rhai::{CustomType, Dynamic, EvalAltResult};
 use strum::VariantNames;
 
@@ -213,6 +213,15 @@ pub enum RhaiOperator {
     Eq,
     Ne,
     Lt,
+    Le,
+    Gt,
+    Ge,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    Not,
 }
 
 impl RhaiOperator {
@@ -229,10 +238,35 @@ impl RhaiOperator {
             RhaiOperator::Eq => "==",
             RhaiOperator::Lt => "<",
             RhaiOperator::Ne => "!=",
+            RhaiOperator::Le => "<=",
+            RhaiOperator::Gt => ">",
+            RhaiOperator::Ge => ">=",
+            RhaiOperator::BitAnd => "&",
+            RhaiOperator::BitOr => "|",
+            RhaiOperator::BitXor => "^",
+            RhaiOperator::Shl => "<<",
+            RhaiOperator::Shr => ">>",
+            RhaiOperator::Not => "!",
         }
     }
 }
 
+/// Compares the variant of an enum reference against a variant name, i.e. `my_enum == "Some"`.
+///
+/// Returns `None` if the other value is not a string or the reference is not an enum.
+fn variant_name_eq(
+    reference: &ReflectReference,
+    other: &ScriptValue,
+    world: WorldGuard,
+) -> Result<Option<bool>, InteropError> {
+    let ScriptValue::String(name) = other else {
+        return Ok(None);
+    };
+    Ok(reference
+        .variant_name(world)?
+        .map(|variant| variant == name.as_ref()))
+}
+
 /// An iterator over a [`ReflectReference`] that implements [`IntoIterator`] for Rhai.
 pub struct RhaiReflectRefIter {
     next_func: DynamicScriptFunctionMut,
@@ -499,6 +533,11 @@ impl CustomType for RhaiReflectReference {
                         .world;
                     let self_: ReflectReference = self_.0.clone();
                     let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    if let Some(eq) = variant_name_eq(&self_, &other, world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                    {
+                        return ScriptValue::Bool(eq).into_dynamic();
+                    }
                     let target_type_id = self_
                         .tail_type_id(world.clone())
                         .map_err(IntoRhaiError::into_rhai_error)?
@@ -519,6 +558,11 @@ impl CustomType for RhaiReflectReference {
                         .world;
                     let self_: ReflectReference = self_.0.clone();
                     let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    if let Some(eq) = variant_name_eq(&self_, &other, world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                    {
+                        return ScriptValue::Bool(!eq).into_dynamic();
+                    }
                     let target_type_id = self_
                         .tail_type_id(world.clone())
                         .map_err(IntoRhaiError::into_rhai_error)?
@@ -556,6 +600,182 @@ impl CustomType for RhaiReflectReference {
                     out.into_dynamic()
                 },
             )
+            .with_fn(
+                RhaiOperator::Le.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "le", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(
+                RhaiOperator::Gt.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "gt", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(
+                RhaiOperator::Ge.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "ge", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(
+                RhaiOperator::BitAnd.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "bitand", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(
+                RhaiOperator::BitOr.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "bitor", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(
+                RhaiOperator::BitXor.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "bitxor", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(
+                RhaiOperator::Shl.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "shl", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(
+                RhaiOperator::Shr.function_name(),
+                |self_: Self, other: Dynamic| {
+                    let world = ThreadWorldContainer
+                        .try_get_context()
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .world;
+                    let self_: ReflectReference = self_.0.clone();
+                    let other: ScriptValue = ScriptValue::from_dynamic(other)?;
+                    let target_type_id = self_
+                        .tail_type_id(world.clone())
+                        .map_err(IntoRhaiError::into_rhai_error)?
+                        .or_fake_id();
+                    let args = vec![ScriptValue::Reference(self_), other];
+                    let out = world
+                        .try_call_overloads(target_type_id, "shr", args, RHAI_CALLER_CONTEXT)
+                        .map_err(IntoRhaiError::into_rhai_error)?;
+                    out.into_dynamic()
+                },
+            )
+            .with_fn(RhaiOperator::Not.function_name(), |self_: Self| {
+                let world = ThreadWorldContainer
+                    .try_get_context()
+                    .map_err(IntoRhaiError::into_rhai_error)?
+                    .world;
+                let self_: ReflectReference = self_.0.clone();
+                let target_type_id = self_
+                    .tail_type_id(world.clone())
+                    .map_err(IntoRhaiError::into_rhai_error)?
+                    .or_fake_id();
+                let args = vec![ScriptValue::Reference(self_)];
+                let out = world
+                    .try_call_overloads(target_type_id, "not", args, RHAI_CALLER_CONTEXT)
+                    .map_err(IntoRhaiError::into_rhai_error)?;
+                out.into_dynamic()
+            })
             .on_debug(|self_| {
                 let result: Result<_, InteropError> = (|| {
                     let world = ThreadWorldContainer.try_get_context()?.world;
@@ -638,7 +858,7 @@ impl CustomType for RhaiStaticReflectReference {
                 let key: ScriptValue = ScriptValue::from_dynamic(index)?;
 
                 let key = match key.as_string() {
-                    Ok(name) => match world.lookup_function([type_id], name) {
+                    Ok(name) => match world.lookup_static_function(type_id, name) {
                         Ok(func) => return ScriptValue::Function(func).into_dynamic(),
                         Err(key) => ScriptValue::String(key),
                     },
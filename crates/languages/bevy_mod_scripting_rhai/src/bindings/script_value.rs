@@ -1,4 +1,9 @@
-use std::{collections::VecDeque, str::FromStr};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    str::FromStr,
+    sync::{Arc, Weak},
+};
 
 use bevy_mod_scripting_asset::Language;
 use bevy_mod_scripting_bindings::{
@@ -7,9 +12,11 @@ use bevy_mod_scripting_bindings::{
     function::script_function::{DynamicScriptFunction, FunctionCallContext},
     script_value::ScriptValue,
 };
-use rhai::{Dynamic, EvalAltResult, FnPtr, Map, NativeCallContext};
+use bevy_mod_scripting_core::config::GetPluginThreadConfig;
+use bevy_mod_scripting_world::ThreadWorldContainer;
+use rhai::{AST, Dynamic, EvalAltResult, FnPtr, Map, NativeCallContext};
 
-use crate::IntoRhaiError;
+use crate::{IntoInteropError, IntoRhaiError, RhaiScriptingPlugin};
 
 use super::reference::RhaiReflectReference;
 
@@ -44,7 +51,7 @@ impl IntoDynamic for FunctionWithReceiver {
 
                 let out = self
                     .function
-                    .call(
+                    .call_from_script(
                         std::iter::once(self.receiver.clone()).chain(convert_args),
                         RHAI_CALLER_CONTEXT,
                     )
@@ -81,6 +88,7 @@ impl IntoDynamic for ScriptValue {
                     .into(),
                 )
             })?,
+            ScriptValue::Bytes(bytes) => Dynamic::from_blob(bytes),
             ScriptValue::List(vec) | ScriptValue::Tuple(VariadicTuple(vec)) => Dynamic::from_array(
                 vec.into_iter()
                     .map(|v| v.into_dynamic())
@@ -106,7 +114,7 @@ impl IntoDynamic for ScriptValue {
                         .collect::<Result<Vec<_>, _>>()?;
 
                     let out = func
-                        .call(convert_args, RHAI_CALLER_CONTEXT)
+                        .call_from_script(convert_args, RHAI_CALLER_CONTEXT)
                         .map_err(IntoRhaiError::into_rhai_error)?;
 
                     out.into_dynamic()
@@ -122,7 +130,7 @@ impl IntoDynamic for ScriptValue {
                         .collect::<Result<Vec<_>, _>>()?;
 
                     let out = func
-                        .call(convert_args, RHAI_CALLER_CONTEXT)
+                        .call_from_script(convert_args, RHAI_CALLER_CONTEXT)
                         .map_err(IntoRhaiError::into_rhai_error)?;
 
                     out.into_dynamic()
@@ -139,6 +147,54 @@ impl IntoDynamic for ScriptValue {
     }
 }
 
+thread_local! {
+    /// The function definitions of the rhai script currently running on this thread.
+    static CURRENT_SCRIPT_FUNCTIONS: RefCell<Weak<AST>> = const { RefCell::new(Weak::new()) };
+}
+
+/// Runs `f` with the given function definitions marked as belonging to the currently running script.
+///
+/// Function pointers passed into rust while `f` runs resolve named functions against these definitions.
+pub(crate) fn with_script_functions<O>(functions: &Arc<AST>, f: impl FnOnce() -> O) -> O {
+    let previous = CURRENT_SCRIPT_FUNCTIONS.replace(Arc::downgrade(functions));
+    let out = f();
+    CURRENT_SCRIPT_FUNCTIONS.set(previous);
+    out
+}
+
+/// Wraps a function pointer passed from a rhai script into a [`DynamicScriptFunction`].
+///
+/// The function stays callable only while the script it was passed from is loaded, and stops working once that script is unloaded or reloaded.
+fn fn_ptr_into_script_function(fn_ptr: FnPtr) -> Result<DynamicScriptFunction, InteropError> {
+    let world_id = ThreadWorldContainer.try_get_context()?.world.id();
+    let owner = CURRENT_SCRIPT_FUNCTIONS.with_borrow(Weak::clone);
+
+    Ok(DynamicScriptFunction::from(
+        move |_context: FunctionCallContext, args: VecDeque<ScriptValue>| {
+            let Some(functions) = owner.upgrade() else {
+                return ScriptValue::Error(InteropError::script_context_unloaded(Language::Rhai));
+            };
+
+            let args = match args
+                .into_iter()
+                .map(IntoDynamic::into_dynamic)
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(args) => args,
+                Err(e) => return ScriptValue::Error(e.into_bms_error()),
+            };
+
+            let config = RhaiScriptingPlugin::readonly_configuration(world_id);
+            let runtime = config.runtime.read();
+            with_script_functions(&functions, || {
+                fn_ptr.call::<Dynamic>(&runtime, &functions, args)
+            })
+            .and_then(ScriptValue::from_dynamic)
+            .unwrap_or_else(|e| ScriptValue::Error(e.into_bms_error()))
+        },
+    ))
+}
+
 /// A trait for converting a [`Dynamic`] value into a type
 pub trait FromDynamic: Sized {
     /// Convert a [`Dynamic`] value into a type
@@ -168,6 +224,19 @@ impl FromDynamic for ScriptValue {
                     .map(|(k, v)| Ok((k.to_string(), ScriptValue::from_dynamic(v.clone())?)))
                     .collect::<Result<_, Box<EvalAltResult>>>()?,
             )),
+            d if d.is_blob() => Ok(ScriptValue::Bytes(
+                d.into_blob()
+                    .map_err(|_| InteropError::invariant("d is proved to be a blob"))
+                    .map_err(IntoRhaiError::into_rhai_error)?,
+            )),
+            d if d.is_fnptr() => {
+                let fn_ptr = d
+                    .try_cast::<FnPtr>()
+                    .ok_or_else(|| InteropError::invariant("d is proved to be a function pointer"))
+                    .and_then(fn_ptr_into_script_function)
+                    .map_err(IntoRhaiError::into_rhai_error)?;
+                Ok(ScriptValue::Function(fn_ptr))
+            }
             d if d.is_array() => Ok(ScriptValue::List(
                 d.into_array()
                     .map_err(|_| InteropError::invariant("d is proved to be an array"))
@@ -0,0 +1,117 @@
+//! Rhai integration for the [`ScriptDebugger`], built on top of rhai's `debugging` feature.
+//!
+//! Breakpoints are matched against the asset path of the script being called.
+use std::cell::Cell;
+
+use bevy_mod_scripting_bindings::ScriptValue;
+use bevy_mod_scripting_core::debugger::{
+    DebugScope, DebugStackFrame, ScriptDebugger, ScriptInspector,
+};
+use rhai::{
+    Engine, EvalContext,
+    debugger::{DebuggerCommand, DebuggerEvent},
+};
+
+use crate::bindings::script_value::FromDynamic;
+
+thread_local! {
+    /// The last call level and line reported to the debugger, rhai steps through multiple nodes per line
+    static LAST_LINE: Cell<Option<(usize, u32)>> = const { Cell::new(None) };
+}
+
+/// Registers the debugger callback with the engine.
+///
+/// Scripts are only stepped through while a client is attached to the [`ScriptDebugger`], otherwise
+/// the callback is only invoked once at the start of each evaluation.
+#[allow(
+    deprecated,
+    reason = "the rhai debugging API is volatile, not deprecated"
+)]
+pub fn register_rhai_debugger(engine: &mut Engine) {
+    engine.register_debugger(
+        |_, debugger| debugger,
+        |context, event, _node, _source, position| {
+            if matches!(event, DebuggerEvent::Start | DebuggerEvent::End) {
+                LAST_LINE.set(None);
+            }
+            if !ScriptDebugger::is_debugging_current_call() {
+                return Ok(DebuggerCommand::Continue);
+            }
+            let Some(line) = position
+                .line()
+                .filter(|_| matches!(event, DebuggerEvent::Step | DebuggerEvent::BreakPoint(_)))
+            else {
+                return Ok(DebuggerCommand::StepInto);
+            };
+
+            let line = line as u32;
+            let depth = context.call_level() + 1;
+            if LAST_LINE.replace(Some((depth, line))) != Some((depth, line)) {
+                ScriptDebugger::on_line(
+                    None,
+                    line,
+                    depth,
+                    &RhaiInspector {
+                        context: &context,
+                        line,
+                    },
+                );
+            }
+            Ok(DebuggerCommand::StepInto)
+        },
+    );
+}
+
+/// Exposes the state of a paused rhai evaluation to the debugger
+struct RhaiInspector<'a, 'e, 's, 'ps, 'g, 'c, 't> {
+    context: &'a EvalContext<'e, 's, 'ps, 'g, 'c, 't>,
+    line: u32,
+}
+
+impl ScriptInspector for RhaiInspector<'_, '_, '_, '_, '_, '_, '_> {
+    fn stack_frames(&self) -> Vec<DebugStackFrame> {
+        let call_stack = self
+            .context
+            .debugger()
+            .map(|debugger| debugger.call_stack())
+            .unwrap_or_default();
+
+        // each call stack entry records the position of the call in its caller
+        let mut frames = Vec::with_capacity(call_stack.len() + 1);
+        let mut line = self.line;
+        for call in call_stack.iter().rev() {
+            frames.push(DebugStackFrame {
+                name: call.fn_name.to_string(),
+                source: None,
+                line,
+            });
+            line = call.pos.line().unwrap_or_default() as u32;
+        }
+        frames.push(DebugStackFrame {
+            name: "script".to_owned(),
+            source: None,
+            line,
+        });
+        frames
+    }
+
+    fn scopes(&self, _frame: usize) -> Vec<DebugScope> {
+        let variables = self
+            .context
+            .scope()
+            .iter()
+            .map(|(name, _, value)| {
+                let display = value.to_string();
+                (
+                    name.to_owned(),
+                    ScriptValue::from_dynamic(value).unwrap_or(ScriptValue::String(display.into())),
+                )
+            })
+            .collect();
+
+        vec![DebugScope {
+            name: "Scope".to_owned(),
+            variables,
+        }]
+    }
+}
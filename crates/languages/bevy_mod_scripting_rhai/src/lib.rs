@@ -2,7 +2,7 @@
 
 use std::{ops::Deref, str::Utf8Error, sync::Arc};
 
-use crate::bindings::script_value::{FromDynamic, IntoDynamic};
+use crate::bindings::script_value::{FromDynamic, IntoDynamic, with_script_functions};
 
 use ::{
     bevy_app::Plugin,
@@ -35,6 +35,8 @@ pub use rhai;
 use rhai::{AST, CallFnOptions, Dynamic, Engine, EvalAltResult, FnPtr, ParseError, Scope};
 /// Bindings for rhai.
 pub mod bindings;
+#[cfg(feature = "debugging")]
+pub mod debugger;
 
 /// The rhai runtime type.
 pub type RhaiRuntime = RwLock<Engine>;
@@ -45,6 +47,11 @@ pub struct RhaiScriptContext {
     pub ast: AST,
     /// The scope of the script
     pub scope: Scope<'static>,
+    /// The function definitions of the script, replaced every time the script is loaded or reloaded.
+    ///
+    /// Callables passed from the script into rust only hold a weak handle to these,
+    /// so they stop working once the script they came from is unloaded or reloaded.
+    pub(crate) functions: Arc<AST>,
 }
 
 make_plugin_config_static!(RhaiScriptingPlugin);
@@ -70,6 +77,10 @@ impl IntoScriptPluginParams for RhaiScriptingPlugin {
     fn context_reloader() -> bevy_mod_scripting_core::context::ContextReloadFn<Self> {
         rhai_context_reload
     }
+
+    fn evaluator() -> bevy_mod_scripting_core::console::EvalFn<Self> {
+        rhai_eval
+    }
 }
 
 /// A trait for converting types into an [`EvalAltResult`]
@@ -161,9 +172,10 @@ fn register_plugin_globals(ctxt: &mut Engine) {
                                 .iter()
                                 .try_for_each(|init| init(&attachment, rhai))?;
 
-                            let ret = func
-                                .call::<Dynamic>(&runtime_guard, &rhai.ast, args)
-                                .map_err(IntoInteropError::into_bms_error)?;
+                            let ret = with_script_functions(&rhai.functions, || {
+                                func.call::<Dynamic>(&runtime_guard, &rhai.ast, args)
+                            })
+                            .map_err(IntoInteropError::into_bms_error)?;
                             ScriptValue::from_dynamic(ret).map_err(IntoInteropError::into_bms_error)
                         },
                     ),
@@ -188,6 +200,8 @@ impl Default for RhaiScriptingPlugin {
                     engine.build_type::<RhaiStaticReflectReference>();
                     engine.register_iterator_result::<RhaiReflectReference, _>();
                     register_plugin_globals(&mut engine);
+                    #[cfg(feature = "debugging")]
+                    debugger::register_rhai_debugger(&mut engine);
                     Ok(())
                 }],
                 context_initializers: vec![
@@ -287,6 +301,7 @@ impl Default for RhaiScriptingPlugin {
                 context_policy: ContextPolicy::default(),
                 emit_responses: false,
                 processing_pipeline_plugin: Default::default(),
+                error_return_mode: None,
             },
         }
     }
@@ -324,6 +339,7 @@ fn load_rhai_content_into_context(
     context
         .ast
         .set_source(context_key.script().display().to_string());
+    context.functions = Arc::new(context.ast.clone_functions_only());
 
     initializers
         .iter()
@@ -331,9 +347,10 @@ fn load_rhai_content_into_context(
     pre_handling_initializers
         .iter()
         .try_for_each(|init| init(context_key, context))?;
-    runtime
-        .eval_ast_with_scope::<()>(&mut context.scope, &context.ast)
-        .map_err(IntoInteropError::into_bms_error)?;
+    with_script_functions(&context.functions, || {
+        runtime.eval_ast_with_scope::<()>(&mut context.scope, &context.ast)
+    })
+    .map_err(IntoInteropError::into_bms_error)?;
 
     context.ast.clear_statements();
     Ok(())
@@ -349,6 +366,7 @@ pub fn rhai_context_load(
         // Using an empty AST as a placeholder.
         ast: AST::empty(),
         scope: Scope::new(),
+        functions: Default::default(),
     };
     load_rhai_content_into_context(&mut context, context_key, content, world_id)?;
     Ok(context)
@@ -394,13 +412,15 @@ pub fn rhai_callback_handler(
     );
     let runtime = config.runtime.read();
 
-    match runtime.call_fn_with_options::<Dynamic>(
-        options,
-        &mut context.scope,
-        &context.ast,
-        callback.as_ref(),
-        args,
-    ) {
+    match with_script_functions(&context.functions, || {
+        runtime.call_fn_with_options::<Dynamic>(
+            options,
+            &mut context.scope,
+            &context.ast,
+            callback.as_ref(),
+            args,
+        )
+    }) {
         Ok(v) => Ok(ScriptValue::from_dynamic(v).map_err(IntoInteropError::into_bms_error)?),
         Err(e) => {
             if let EvalAltResult::ErrorFunctionNotFound(_, _) = e.unwrap_inner() {
@@ -415,3 +435,34 @@ pub fn rhai_callback_handler(
         }
     }
 }
+
+/// Evaluates a snippet of rhai code against an existing context.
+///
+/// The snippet can call functions defined by the script and read or modify variables in its scope.
+pub fn rhai_eval(
+    code: &str,
+    context_key: &ScriptAttachment,
+    context: &mut RhaiScriptContext,
+    world_id: WorldId,
+) -> Result<ScriptValue, InteropError> {
+    let config = RhaiScriptingPlugin::readonly_configuration(world_id);
+    let pre_handling_initializers = config.pre_handling_callbacks;
+
+    pre_handling_initializers
+        .iter()
+        .try_for_each(|init| init(context_key, context))?;
+
+    let runtime = config.runtime.read();
+    let snippet = runtime
+        .compile_with_scope(&context.scope, code)
+        .map_err(IntoInteropError::into_bms_error)?;
+
+    // the context AST only retains function definitions once loaded
+    let ast = context.ast.merge(&snippet);
+    let out = with_script_functions(&context.functions, || {
+        runtime.eval_ast_with_scope::<Dynamic>(&mut context.scope, &ast)
+    })
+    .map_err(IntoInteropError::into_bms_error)?;
+
+    ScriptValue::from_dynamic(out).map_err(IntoInteropError::into_bms_error)
+}
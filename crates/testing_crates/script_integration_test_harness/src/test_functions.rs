@@ -11,8 +11,9 @@ use ::{
 };
 use bevy_mod_scripting_asset::Language;
 use bevy_mod_scripting_bindings::{
-    DynamicScriptFunction, ReflectReference, ScriptComponentRegistration,
-    ScriptResourceRegistration, ScriptTypeRegistration, ScriptValue, WorldExtensions,
+    DynamicScriptFunction, ErrorReturnMode, ReflectReference, ScriptCallable,
+    ScriptComponentRegistration, ScriptResourceRegistration, ScriptTypeRegistration, ScriptValue,
+    WorldExtensions,
     error::InteropError,
     function::{
         namespace::{GlobalNamespace, NamespaceBuilder},
@@ -21,7 +22,7 @@ use bevy_mod_scripting_bindings::{
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha12Rng;
-use test_utils::test_data::{EnumerateTestComponents, TestAsset};
+use test_utils::test_data::{EnumerateTestComponents, SimpleStruct, TestAsset};
 
 // lazy lock rng state
 pub static RNG: std::sync::LazyLock<Mutex<ChaCha12Rng>> = std::sync::LazyLock::new(|| {
@@ -31,6 +32,11 @@ pub static RNG: std::sync::LazyLock<Mutex<ChaCha12Rng>> = std::sync::LazyLock::n
 
 pub use rand;
 
+thread_local! {
+    // callable stored by scripts to be called back later
+    static STORED_CALLABLE: std::cell::RefCell<Option<ScriptCallable>> = const { std::cell::RefCell::new(None) };
+}
+
 pub fn register_test_functions(world: &mut App) {
     let world = world.world_mut();
     NamespaceBuilder::<World>::new_unregistered(world)
@@ -187,5 +193,115 @@ pub fn register_test_functions(world: &mut App) {
                 let mut allocator = allocator.write();
                 Ok(ReflectReference::new_allocated(handle, &mut allocator))
             },
+        )
+        .register("_checked_div", checked_div)
+        .register("_checked_div_nil_and_message", checked_div)
+        .register("_checked_div_reference", checked_div)
+        .set_error_mode(
+            "_checked_div_nil_and_message",
+            ErrorReturnMode::NilAndMessage,
+        )
+        .set_error_mode("_checked_div_reference", ErrorReturnMode::Reference)
+        .register("_sort_with", sort_with)
+        .register("_store_callable", |callable: ScriptCallable| {
+            STORED_CALLABLE.set(Some(callable));
+        })
+        .register(
+            "_call_stored_callable",
+            |ctxt: FunctionCallContext, args: Vec<ScriptValue>| {
+                let callable = STORED_CALLABLE
+                    .with_borrow(Clone::clone)
+                    .ok_or_else(|| InteropError::str("no callable was stored"))?;
+                callable.call(args, ctxt)
+            },
+        )
+        .register("_reverse_bytes", |mut bytes: Vec<u8>| {
+            bytes.reverse();
+            bytes
+        });
+
+    // operators dispatched on reflected references, `lt` is left out on purpose so `>=` cannot fall back to it
+    NamespaceBuilder::<SimpleStruct>::new_unregistered(world)
+        .register("concat", simple_struct_concat)
+        .register(
+            "call",
+            |ctxt: FunctionCallContext, self_: ReflectReference, amount: usize| {
+                Ok::<_, InteropError>(simple_struct_foo(&ctxt, &self_)? + amount)
+            },
+        )
+        .register(
+            "le",
+            |ctxt: FunctionCallContext, a: ReflectReference, b: ReflectReference| {
+                Ok::<_, InteropError>(
+                    simple_struct_foo(&ctxt, &a)? <= simple_struct_foo(&ctxt, &b)?,
+                )
+            },
+        )
+        .register(
+            "ge",
+            |ctxt: FunctionCallContext, a: ReflectReference, b: ReflectReference| {
+                Ok::<_, InteropError>(
+                    simple_struct_foo(&ctxt, &a)? >= simple_struct_foo(&ctxt, &b)?,
+                )
+            },
         );
 }
+
+fn simple_struct_foo(
+    ctxt: &FunctionCallContext,
+    reference: &ReflectReference,
+) -> Result<usize, InteropError> {
+    reference
+        .with_reflect(ctxt.world()?, |r| {
+            r.try_downcast_ref::<SimpleStruct>().map(|s| s.foo)
+        })?
+        .ok_or_else(|| InteropError::str("expected a reference to a SimpleStruct"))
+}
+
+/// Concatenates a `SimpleStruct` with a string on either side.
+fn simple_struct_concat(
+    ctxt: FunctionCallContext,
+    left: ScriptValue,
+    right: ScriptValue,
+) -> Result<String, InteropError> {
+    let display = |value: ScriptValue| match value {
+        ScriptValue::Reference(reference) => Ok(format!(
+            "SimpleStruct({})",
+            simple_struct_foo(&ctxt, &reference)?
+        )),
+        ScriptValue::String(s) => Ok(s.into_owned()),
+        other => Err(InteropError::string(format!(
+            "cannot concatenate a SimpleStruct with {other:?}"
+        ))),
+    };
+    Ok(display(left)? + &display(right)?)
+}
+
+fn sort_with(
+    ctxt: FunctionCallContext,
+    values: Vec<i64>,
+    less_than: ScriptCallable,
+) -> Result<Vec<i64>, InteropError> {
+    let mut sorted: Vec<i64> = Vec::with_capacity(values.len());
+    for value in values {
+        let mut index = sorted.len();
+        while index > 0
+            && less_than.call_as::<bool, _>(
+                [
+                    ScriptValue::Integer(value),
+                    ScriptValue::Integer(sorted[index - 1]),
+                ],
+                ctxt.clone(),
+            )?
+        {
+            index -= 1;
+        }
+        sorted.insert(index, value);
+    }
+    Ok(sorted)
+}
+
+fn checked_div(a: i64, b: i64) -> Result<i64, InteropError> {
+    a.checked_div(b)
+        .ok_or_else(|| InteropError::str("division by zero"))
+}
@@ -1,6 +1,6 @@
 use std::{
     alloc::Layout,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
 };
 
 use bevy_app::{App, ScheduleRunnerPlugin, TaskPoolPlugin};
@@ -178,6 +178,7 @@ pub struct TestResourceWithVariousFields {
     pub string_map: HashMap<String, String>,
     pub string_set: HashSet<String>,
     pub simple_type_map: HashMap<SimpleType, String>,
+    pub ordered_map: BTreeMap<u32, String>,
 }
 
 impl TestResourceWithVariousFields {
@@ -213,6 +214,11 @@ impl TestResourceWithVariousFields {
                     String::from("zed"),
                 ),
             ]),
+            ordered_map: BTreeMap::from_iter(vec![
+                (3, String::from("three")),
+                (1, String::from("one")),
+                (2, String::from("two")),
+            ]),
         }
     }
 }
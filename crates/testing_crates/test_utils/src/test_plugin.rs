@@ -58,6 +58,12 @@ macro_rules! make_test_plugin {
                     Ok(())
                 })
             }
+
+            fn evaluator() -> $ident::EvalFn<Self> {
+                (|code, context_key, context, world_id| {
+                    Ok(ScriptValue::String(code.to_owned().into()))
+                }) as $ident::EvalFn<Self>
+            }
         }
 
         #[derive(Default, std::fmt::Debug)]
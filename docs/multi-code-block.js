@@ -1,3 +1,111 @@
-document.addEventListener("DOMContentLoaded", function() {
-    console.log("Hello, world!");
-});
\ No newline at end of file
+// Turns `<div class="multi-code-block">` elements containing code blocks in different languages into tabs.
+// The last selected language is remembered and applied to every multi code block in the book.
+(function () {
+    const STORAGE_KEY = "mdbook-multi-code-block-language";
+    const LANGUAGE_CLASS_PREFIX = "language-";
+
+    function codeBlockLanguage(pre) {
+        const code = pre.querySelector("code");
+        if (!code) {
+            return null;
+        }
+        for (const cls of code.classList) {
+            if (cls.startsWith(LANGUAGE_CLASS_PREFIX)) {
+                return cls.substring(LANGUAGE_CLASS_PREFIX.length);
+            }
+        }
+        return null;
+    }
+
+    function showLanguage(block, language) {
+        const tabs = block.querySelectorAll(".multi-code-block-tab");
+        if (!Array.from(tabs).some((tab) => tab.dataset.language === language)) {
+            return;
+        }
+        tabs.forEach((tab) => {
+            tab.classList.toggle("active", tab.dataset.language === language);
+        });
+        block.querySelectorAll("pre").forEach((pre) => {
+            pre.style.display = codeBlockLanguage(pre) === language ? "" : "none";
+        });
+    }
+
+    function selectLanguage(language) {
+        try {
+            localStorage.setItem(STORAGE_KEY, language);
+        } catch (e) {
+            // storage might be disabled, the selection will simply not persist
+        }
+        document.querySelectorAll(".multi-code-block").forEach((block) => showLanguage(block, language));
+    }
+
+    function addStyles() {
+        const style = document.createElement("style");
+        style.textContent = `
+            .multi-code-block-tabs {
+                display: flex;
+                gap: 2px;
+            }
+            .multi-code-block-tab {
+                padding: 4px 12px;
+                border: none;
+                border-radius: 4px 4px 0 0;
+                background: var(--sidebar-bg);
+                color: var(--sidebar-fg);
+                cursor: pointer;
+                opacity: 0.6;
+            }
+            .multi-code-block-tab.active {
+                opacity: 1;
+            }
+            .multi-code-block pre {
+                margin-top: 0;
+            }
+        `;
+        document.head.appendChild(style);
+    }
+
+    document.addEventListener("DOMContentLoaded", function () {
+        const blocks = document.querySelectorAll(".multi-code-block");
+        if (blocks.length === 0) {
+            return;
+        }
+        addStyles();
+
+        blocks.forEach((block) => {
+            const languages = [];
+            block.querySelectorAll("pre").forEach((pre) => {
+                const language = codeBlockLanguage(pre);
+                if (language && !languages.includes(language)) {
+                    languages.push(language);
+                }
+            });
+            if (languages.length === 0) {
+                return;
+            }
+
+            const tabs = document.createElement("div");
+            tabs.className = "multi-code-block-tabs";
+            languages.forEach((language) => {
+                const tab = document.createElement("button");
+                tab.className = "multi-code-block-tab";
+                tab.dataset.language = language;
+                tab.textContent = language;
+                tab.addEventListener("click", () => selectLanguage(language));
+                tabs.appendChild(tab);
+            });
+            block.insertBefore(tabs, block.firstChild);
+            showLanguage(block, languages[0]);
+        });
+
+        let stored = null;
+        try {
+            stored = localStorage.getItem(STORAGE_KEY);
+        } catch (e) {
+            // ignore, fall back to the first language of each block
+        }
+        if (stored) {
+            blocks.forEach((block) => showLanguage(block, stored));
+        }
+    });
+})();
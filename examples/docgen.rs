@@ -1,15 +1,11 @@
 use bevy::prelude::PluginGroup;
-use bevy::{DefaultPlugins, app::App, ecs::reflect::AppTypeRegistry};
+use bevy::{DefaultPlugins, app::App};
 use bevy_mod_scripting::BMSPlugin;
-use bevy_mod_scripting_bindings::{
-    DummyScriptFunctionRegistry, function::script_function::AppScriptFunctionRegistry,
-    globals::AppScriptGlobalsRegistry,
-};
 use ladfile_builder::plugin::{
-    LadFileSettingsArc, ScriptingFilesGenerationPlugin, generate_lad_file,
+    LadFileSettingsArc, ScriptingFilesGenerationPlugin, generate_lad_file_from_world,
 };
 use std::path::PathBuf;
-fn main() -> std::io::Result<()> {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut app = App::new();
     // headless bevy, kinda, I want to include as many plugins as I can which actually
     // provide reflected type definitions, but exclude anything that runs rendering stuff.
@@ -38,40 +34,13 @@ fn main() -> std::io::Result<()> {
     // or 2. manually trigger the system
     // this is what we do here as we're running this example in GHA
 
-    let type_registry = app
-        .world()
-        .get_resource::<AppTypeRegistry>()
-        .unwrap()
-        .clone();
-    let function_registry = app
-        .world()
-        .get_resource::<AppScriptFunctionRegistry>()
-        .unwrap()
-        .clone();
-    let dummy_function_registry = app
-        .world()
-        .get_resource::<DummyScriptFunctionRegistry>()
-        .unwrap()
-        .clone();
-    let global_registry = app
-        .world()
-        .get_resource::<AppScriptGlobalsRegistry>()
-        .unwrap()
-        .clone();
-
     let settings = app
         .world()
         .get_resource::<LadFileSettingsArc>()
         .unwrap()
         .clone();
 
-    generate_lad_file(
-        &type_registry,
-        &function_registry,
-        &dummy_function_registry,
-        &global_registry,
-        &settings.0,
-    );
+    generate_lad_file_from_world(app.world_mut(), &settings.0)?;
 
     // bah bye, the generated file will be found in assets/
     // this can then be passed to various backends to generate docs, and other declaration files
@@ -33,7 +33,13 @@ pub mod rhai {
     pub use bevy_mod_scripting_rhai::*;
 }
 
-#[cfg(feature = "lua_language_server_files")]
+#[cfg(any(
+    feature = "lua_language_server_files",
+    feature = "rhai_definition_files",
+    feature = "luau_definition_files",
+    feature = "typescript_definition_files",
+    feature = "json_schema_files"
+))]
 pub mod ladfile {
     pub use ladfile_builder::*;
 }
@@ -53,7 +59,13 @@ plugin_group! {
         bevy_mod_scripting_lua:::LuaScriptingPlugin,
         #[custom(cfg(feature = "rhai"))]
         bevy_mod_scripting_rhai:::RhaiScriptingPlugin,
-        #[custom(cfg(feature = "lua_language_server_files"))]
+        #[custom(cfg(any(
+            feature = "lua_language_server_files",
+            feature = "rhai_definition_files",
+            feature = "luau_definition_files",
+            feature = "typescript_definition_files",
+            feature = "json_schema_files"
+        )))]
         ladfile_builder::plugin:::ScriptingFilesGenerationPlugin
     }
 }